@@ -39,6 +39,7 @@
 - [🚀 Getting Started](#-getting-started)
 - [🌐 Running in the Browser with WASM](#-running-in-the-browser-with-wasm)
 - [🤖 Compatibility with Meilisearch](#-compatibility-with-meilisearch)
+- [⬆️ Migrating to v0.21.0](#️-migrating-to-v0210)
 - [⚙️ Development Workflow and Contributing](#️-development-workflow-and-contributing)
 
 ## 🎃 Hacktoberfest
@@ -59,7 +60,7 @@ To use `meilisearch-sdk`, add this to your `Cargo.toml`:
 
 ```toml
 [dependencies]
-meilisearch-sdk = "0.20.1"
+meilisearch-sdk = "0.21.0"
 ```
 
 The following optional dependencies may also be useful:
@@ -71,6 +72,10 @@ serde = { version = "1.0", features = ["derive"] }
 
 This crate is `async` but you can choose to use an async runtime like [tokio](https://crates.io/crates/tokio) or just [block on futures](https://docs.rs/futures/latest/futures/executor/fn.block_on.html).
 You can enable the `sync` feature to make most structs `Sync`. It may be a bit slower.
+When waiting for tasks, the SDK sleeps on a dedicated thread by default. Enable the `tokio`, `async-std` or `futures-timer` feature to use the timer of your runtime instead, or `gloo-timers` in WASM.
+With the `tokio` feature, a tokio runtime has to be built with [`enable_time`](https://docs.rs/tokio/latest/tokio/runtime/struct.Builder.html#method.enable_time) (or `enable_all`, like `#[tokio::main]` does), otherwise waiting for a task panics.
+The `metrics` feature lets you record metrics about the requests sent to Meilisearch, and `metrics-opentelemetry` exports them with OpenTelemetry.
+The `simd-json` feature serializes the request bodies, like the documents to index, with [simd-json](https://crates.io/crates/simd-json) for faster imports.
 
 Using this crate is possible without [serde](https://crates.io/crates/serde), but a lot of features require serde.
 
@@ -108,6 +113,15 @@ WARNING: `meilisearch-sdk` will panic if no Window is available (ex: Web extensi
 
 This package only guarantees the compatibility with the [version v0.29.0 of Meilisearch](https://github.com/meilisearch/meilisearch/releases/tag/v0.29.0).
 
+## ⬆️ Migrating to v0.21.0
+
+v0.21.0 contains breaking changes to the `Settings` and the search parameters:
+
+- The fields of `Settings` are `Setting<T>` instead of `Option<T>`. A `Setting::Set` is sent as its value, a `Setting::Reset` as `null` to reset the setting to its default value, and a `Setting::NotSet` is not sent, so a single `set_settings` can reset some settings while updating others.
+- When building `Settings` by hand, replace `Some(value)` with `Setting::Set(value)` and `None` with `Setting::NotSet`. The `with_*` builders are unchanged, and the new `reset_*` builders mark a setting as reset.
+- When reading the `Settings` returned by `get_settings`, use `Setting::as_option` or `Setting::into_option` to get an `Option` back.
+- The `query` and `sort` fields of `SearchParams` hold `Cow<str>` so that a search can own them, like the one built by `SearchQuery::from_query_string`. The `with_query` and `with_sort` builders are unchanged, and a `&str` is turned into a `Cow` with `.into()`.
+
 ## ⚙️ Development Workflow and Contributing
 
 Any new contribution is more than welcome in this project!
@@ -0,0 +1,124 @@
+use meilisearch_sdk::client::Client;
+use meilisearch_sdk::search::Selectors;
+use meilisearch_sdk::settings::Settings;
+use meilisearch_sdk::tasks::TasksQuery;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Movie {
+    id: usize,
+    title: String,
+    genre: String,
+    year: u32,
+}
+
+// we need an async runtime
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let client: Client = Client::new("http://localhost:7700", "masterKey");
+    let movies = client.index("search_example");
+
+    // Filtering, faceting and sorting only work on the attributes declared in the settings.
+    let settings = Settings::new()
+        .with_filterable_attributes(["genre", "year"])
+        .with_sortable_attributes(["year"]);
+    movies
+        .set_settings(&settings)
+        .await
+        .expect("Could not join the remote server.")
+        .wait_for_completion(&client, None, None)
+        .await
+        .expect("Could not join the remote server.");
+
+    // Adding documents is asynchronous too, so we wait for them to be indexed before searching.
+    let task = movies
+        .add_documents(
+            &[
+                Movie {
+                    id: 1,
+                    title: String::from("Carol"),
+                    genre: String::from("romance"),
+                    year: 2015,
+                },
+                Movie {
+                    id: 2,
+                    title: String::from("Wonder Woman"),
+                    genre: String::from("action"),
+                    year: 2017,
+                },
+                Movie {
+                    id: 3,
+                    title: String::from("Mad Max"),
+                    genre: String::from("action"),
+                    year: 2015,
+                },
+            ],
+            Some("id"),
+        )
+        .await
+        .expect("Could not join the remote server.")
+        .wait_for_completion(&client, None, None)
+        .await
+        .expect("Could not join the remote server.");
+    if task.is_failure() {
+        panic!(
+            "Could not add the documents. {}",
+            task.unwrap_failure().error_message
+        );
+    }
+
+    // A filtered and sorted search, one result at a time.
+    let mut query = movies.search();
+    query
+        .with_filter("genre = action")
+        .with_sort(&["year:desc"])
+        .with_limit(1);
+    for offset in 0..2 {
+        query.with_offset(offset);
+        let results = query
+            .execute::<Movie>()
+            .await
+            .expect("Could not join the remote server.");
+        println!("page {}: {:?}", offset, results.hits[0].result);
+    }
+
+    // The number of movies per genre, without fetching any movie.
+    let mut query = movies.search();
+    query.with_facets(Selectors::Some(&["genre"])).with_limit(0);
+    let results = query
+        .execute::<Movie>()
+        .await
+        .expect("Could not join the remote server.");
+    println!("{:?}", results.facet_distribution);
+
+    // The matches of the query highlighted in the titles.
+    let mut query = movies.search();
+    query
+        .with_query("max")
+        .with_attributes_to_highlight(Selectors::Some(&["title"]));
+    let results = query
+        .execute::<Movie>()
+        .await
+        .expect("Could not join the remote server.");
+    for hit in results.hits {
+        println!("{:?}", hit.formatted_result);
+    }
+
+    // Every task that ran on the index.
+    let mut query = TasksQuery::new(&client);
+    query.with_index_uid(["search_example"]);
+    let tasks = query
+        .execute()
+        .await
+        .expect("Could not join the remote server.");
+    println!("{} tasks ran on the index", tasks.results.len());
+
+    // And finally we delete the `Index`.
+    movies
+        .delete()
+        .await
+        .expect("Could not join the remote server.")
+        .wait_for_completion(&client, None, None)
+        .await
+        .expect("Could not join the remote server.");
+}
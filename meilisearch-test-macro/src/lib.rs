@@ -156,7 +156,7 @@ pub fn meilisearch_test(params: TokenStream, input: TokenStream) -> TokenStream
         // And right before the end, if an index was created and the tests successfully executed we delete it.
         if use_index {
             outer_block.push(parse_quote!(
-                index
+                let _ = index
                     .delete()
                     .await
                     .expect("Network issue while sending the last delete index task");
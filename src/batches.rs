@@ -0,0 +1,79 @@
+//! The `batches` module gives access to the batches in which Meilisearch processes the [tasks](crate::tasks::Task).
+//!
+//! Meilisearch groups the enqueued tasks into batches and processes a whole batch at once.
+//! A [Task](crate::tasks::Task) knows the [uid of its batch](crate::tasks::Task::batch_uid), which can be
+//! [fetched](crate::tasks::Task::fetch_batch) to see what was processed with it, and how far along the processing is.
+//!
+//! The batches are available since Meilisearch v1.13.
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{collections::HashMap, time::Duration};
+use time::OffsetDateTime;
+
+use crate::{client::Client, errors::Error, request::*, tasks::deserialize_optional_duration};
+
+/// A batch of tasks processed together by Meilisearch.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Batch {
+    pub uid: u32,
+    /// The steps of the processing, while the batch is processing.
+    pub progress: Option<Value>,
+    /// The details of the tasks of the batch, merged.
+    #[serde(default)]
+    pub details: Map<String, Value>,
+    pub stats: BatchStats,
+    /// `None` while the batch is processing.
+    #[serde(default, deserialize_with = "deserialize_optional_duration")]
+    pub duration: Option<Duration>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    /// `None` while the batch is processing.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+}
+
+/// What the tasks of a [Batch] are.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStats {
+    pub total_nb_tasks: u32,
+    /// The number of tasks per status.
+    pub status: HashMap<String, u32>,
+    /// The number of tasks per type.
+    pub types: HashMap<String, u32>,
+    /// The number of tasks per index.
+    pub index_uids: HashMap<String, u32>,
+}
+
+/// Batch related methods.\
+/// See the [batches](crate::batches) module.
+impl Client {
+    /// Get a [Batch] from its uid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let batch = client.get_batch(0).await.unwrap();
+    ///
+    /// println!("{} tasks processed in {:?}", batch.stats.total_nb_tasks, batch.duration);
+    /// # });
+    /// ```
+    pub async fn get_batch(&self, batch_uid: u32) -> Result<Batch, Error> {
+        request::<(), Batch>(
+            &format!("{}/batches/{}", self.host, batch_uid),
+            self,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+}
@@ -1,15 +1,31 @@
 use crate::{
+    clock::{Clock, SystemClock},
     errors::*,
     indexes::*,
     key::{Key, KeyBuilder, KeyUpdater, KeysQuery, KeysResults},
     request::*,
+    search::{FacetSearchParams, FacetSearchResponse, Filter, SearchParams, SearchResults},
+    settings::SettingsDiff,
     task_info::TaskInfo,
-    tasks::{Task, TasksQuery, TasksResults},
-    utils::async_sleep,
+    tasks::{Task, TaskStatus, TaskWith, TasksQuery, TasksResults},
 };
-use serde::Deserialize;
+use either::Either;
+use futures::{
+    future::{AbortHandle, Abortable},
+    stream::{self, Stream, StreamExt},
+};
+use log::warn;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::{collections::HashMap, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, VecDeque},
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
 use time::OffsetDateTime;
 
 /// The top-level struct of the SDK, representing a client containing [indexes](../indexes/struct.Index.html).
@@ -17,11 +33,350 @@ use time::OffsetDateTime;
 pub struct Client {
     pub(crate) host: String,
     pub(crate) api_key: String,
+    pub(crate) proxy: ProxyConfig,
+    pub(crate) max_response_bytes: Option<usize>,
+    pub(crate) stream_hits: bool,
+    pub(crate) search_limits: SearchLimits,
+    pub(crate) attributes_cache: AttributesCache,
+    pub(crate) embedders_cache: EmbeddersCache,
+    pub(crate) primary_keys_cache: PrimaryKeysCache,
+    pub(crate) key_provider: Option<Arc<KeyProvider>>,
+    pub(crate) search_postprocessor: Option<SearchPostprocessor>,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) wait_policy: WaitPolicy,
+    pub(crate) base_filters: Vec<String>,
+    pub(crate) in_flight: Option<InFlightRequests>,
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: crate::metrics::Metrics,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ProxyConfig {
+    pub(crate) url: Option<String>,
+    pub(crate) system: bool,
+    pub(crate) no_proxy: Vec<String>,
+}
+
+impl Default for ProxyConfig {
+    fn default() -> ProxyConfig {
+        ProxyConfig {
+            url: None,
+            system: true,
+            no_proxy: Vec::new(),
+        }
+    }
+}
+
+/// The future returned by a [key provider](ClientBuilder::with_key_provider).
+#[cfg(not(target_arch = "wasm32"))]
+pub type ApiKeyFuture = Pin<Box<dyn Future<Output = Result<String, Error>> + Send>>;
+/// The future returned by a [key provider](ClientBuilder::with_key_provider).
+#[cfg(target_arch = "wasm32")]
+pub type ApiKeyFuture = Pin<Box<dyn Future<Output = Result<String, Error>>>>;
+
+/// The hook applied to the search responses, see [ClientBuilder::with_search_postprocessor].
+#[derive(Clone)]
+pub(crate) struct SearchPostprocessor(Arc<dyn Fn(&mut Value) + Send + Sync>);
+
+impl SearchPostprocessor {
+    /// Apply the hook to a raw search response, then deserialize it.
+    pub(crate) fn apply<T: DeserializeOwned>(
+        &self,
+        mut response: Value,
+    ) -> Result<SearchResults<T>, Error> {
+        (self.0)(&mut response);
+        SearchResults::deserialize(&response)
+            .map_err(|e| Error::parse_error::<SearchResults<T>>(response.to_string(), e))
+    }
+}
+
+impl std::fmt::Debug for SearchPostprocessor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SearchPostprocessor")
+    }
+}
+
+/// Fetches the API key of a client and caches it until Meilisearch rejects it.
+pub(crate) struct KeyProvider {
+    fetch: Box<dyn Fn() -> ApiKeyFuture + Send + Sync>,
+    // held while the key is fetched, so concurrent requests wait for the same fetch
+    key: futures::lock::Mutex<Option<String>>,
+}
+
+impl std::fmt::Debug for KeyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyProvider").finish_non_exhaustive()
+    }
+}
+
+impl KeyProvider {
+    /// Get the cached key, fetching it if there is none.
+    pub(crate) async fn current(&self) -> Result<String, Error> {
+        let mut key = self.key.lock().await;
+        match &*key {
+            Some(key) => Ok(key.clone()),
+            None => {
+                let fetched = (self.fetch)().await?;
+                *key = Some(fetched.clone());
+                Ok(fetched)
+            }
+        }
+    }
+
+    /// Fetch a new key to replace `rejected`, unless another request already replaced it.
+    pub(crate) async fn refresh(&self, rejected: &str) -> Result<String, Error> {
+        let mut key = self.key.lock().await;
+        match &*key {
+            Some(key) if key != rejected => Ok(key.clone()),
+            _ => {
+                let fetched = (self.fetch)().await?;
+                *key = Some(fetched.clone());
+                Ok(fetched)
+            }
+        }
+    }
+}
+
+/// The filterable and sortable attributes of the indexes, as last fetched by
+/// [SearchQuery::execute_checked](crate::search::SearchQuery::execute_checked).
+/// It is shared by the clones of a [Client].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AttributesCache(Arc<Mutex<HashMap<String, CachedAttributes>>>);
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedAttributes {
+    pub(crate) filterable: Vec<String>,
+    pub(crate) sortable: Vec<String>,
+}
+
+impl AttributesCache {
+    pub(crate) fn get(&self, index_uid: &str) -> Option<CachedAttributes> {
+        let cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.get(index_uid).cloned()
+    }
+
+    pub(crate) fn insert(&self, index_uid: &str, attributes: CachedAttributes) {
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.insert(index_uid.to_string(), attributes);
+    }
+}
+
+/// The dimensions of the embedders of the indexes, as last fetched by [Index::nearest_neighbors].
+/// The embedders whose dimensions are not configured are kept with `None`.
+/// It is shared by the clones of a [Client].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmbeddersCache(Arc<Mutex<HashMap<String, EmbedderDimensions>>>);
+
+/// The dimensions of the embedders of an index, by name.
+pub(crate) type EmbedderDimensions = HashMap<String, Option<usize>>;
+
+impl EmbeddersCache {
+    /// The dimensions of `embedder`, `None` if the embedder isn't cached.
+    pub(crate) fn get(&self, index_uid: &str, embedder: &str) -> Option<Option<usize>> {
+        let cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.get(index_uid)?.get(embedder).copied()
+    }
+
+    pub(crate) fn insert(&self, index_uid: &str, dimensions: EmbedderDimensions) {
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.insert(index_uid.to_string(), dimensions);
+    }
+}
+
+/// The primary keys of the indexes, as last fetched by [Index::search_in_chunks].
+/// It is shared by the clones of a [Client].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PrimaryKeysCache(Arc<Mutex<HashMap<String, String>>>);
+
+impl PrimaryKeysCache {
+    pub(crate) fn get(&self, index_uid: &str) -> Option<String> {
+        let cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.get(index_uid).cloned()
+    }
+
+    pub(crate) fn insert(&self, index_uid: &str, primary_key: String) {
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.insert(index_uid.to_string(), primary_key);
+    }
+}
+
+/// The requests in flight of a [Client] built [with abort on drop](ClientBuilder::with_abort_on_drop).
+/// It is shared by the clones of the [Client] and by its [indexes](Index).
+#[derive(Debug, Clone)]
+pub(crate) struct InFlightRequests {
+    requests: Arc<Mutex<InFlightState>>,
+    // only held by the clones of the client, the requests are aborted once the last one is dropped
+    _owner: Option<Arc<AbortOnDrop>>,
+}
+
+#[derive(Debug, Default)]
+struct InFlightState {
+    aborted: bool,
+    next_id: u64,
+    handles: HashMap<u64, AbortHandle>,
+}
+
+#[derive(Debug)]
+struct AbortOnDrop(Arc<Mutex<InFlightState>>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        let mut state = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        state.aborted = true;
+        for (_, handle) in state.handles.drain() {
+            handle.abort();
+        }
+    }
+}
+
+/// Removes a request from the requests in flight once it's done, or dropped by the caller.
+struct InFlightGuard<'a> {
+    requests: &'a Mutex<InFlightState>,
+    id: u64,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+        state.handles.remove(&self.id);
+    }
+}
+
+impl InFlightRequests {
+    fn new() -> InFlightRequests {
+        let requests = Arc::new(Mutex::new(InFlightState::default()));
+        InFlightRequests {
+            _owner: Some(Arc::new(AbortOnDrop(requests.clone()))),
+            requests,
+        }
+    }
+
+    /// The same requests, without keeping them from being aborted.
+    fn without_owner(&self) -> InFlightRequests {
+        InFlightRequests {
+            requests: self.requests.clone(),
+            _owner: None,
+        }
+    }
+
+    /// Run `request`, or return [Error::Aborted] as soon as the client is dropped.
+    pub(crate) async fn run<T>(
+        &self,
+        request: impl Future<Output = Result<T, Error>>,
+    ) -> Result<T, Error> {
+        let (handle, registration) = AbortHandle::new_pair();
+        let id = {
+            let mut state = self.requests.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.aborted {
+                return Err(Error::Aborted);
+            }
+            let id = state.next_id;
+            state.next_id += 1;
+            state.handles.insert(id, handle);
+            id
+        };
+        let _guard = InFlightGuard {
+            requests: &self.requests,
+            id,
+        };
+
+        Abortable::new(request, registration)
+            .await
+            .unwrap_or(Err(Error::Aborted))
+    }
+}
+
+/// How the methods waiting for a [Task] poll Meilisearch when they're not given an interval or a timeout.
+///
+/// The policy of a [Client] is set with [ClientBuilder::with_wait_policy],
+/// and can be overridden for an index with [Index::with_wait_policy].
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// # use std::time::Duration;
+/// let client = ClientBuilder::new("http://localhost:7700", "masterKey")
+///     .with_wait_policy(WaitPolicy::new(Duration::from_millis(100), Duration::from_secs(10)))
+///     .build();
+/// let catalog = client
+///     .index("catalog")
+///     .with_wait_policy(WaitPolicy::new(Duration::from_secs(1), Duration::from_secs(600)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitPolicy {
+    /// The frequency at which the task is polled. Default: 50ms
+    pub interval: Duration,
+    /// The maximum time to wait for the task, before failing with [Error::Timeout]. Default: 5s
+    pub timeout: Duration,
+}
+
+impl WaitPolicy {
+    /// A policy polling the task every `interval`, until it's finished or `timeout` elapsed.
+    pub fn new(interval: Duration, timeout: Duration) -> WaitPolicy {
+        WaitPolicy { interval, timeout }
+    }
+}
+
+impl Default for WaitPolicy {
+    fn default() -> WaitPolicy {
+        WaitPolicy {
+            interval: Duration::from_millis(50),
+            timeout: Duration::from_millis(5000),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SearchLimits {
+    pub(crate) default: Option<usize>,
+    pub(crate) max: Option<usize>,
+    pub(crate) strict: bool,
+}
+
+/// The number of hits returned by Meilisearch when a search doesn't set a limit.
+const MEILISEARCH_DEFAULT_SEARCH_LIMIT: usize = 20;
+
+impl SearchLimits {
+    /// Apply the default limit and the maximum limit to the parameters of a search.
+    fn apply<'p, 'a>(
+        &self,
+        params: &'p SearchParams<'a>,
+    ) -> Result<Cow<'p, SearchParams<'a>>, Error> {
+        let limit = match (params.limit, self.max) {
+            (Some(limit), Some(max)) if limit > max => {
+                if self.strict {
+                    return Err(Error::SearchLimitExceeded { limit, max });
+                }
+                warn!(
+                    "The search limit of {} is larger than the maximum of {}, it has been lowered to {}",
+                    limit, max, max
+                );
+                max
+            }
+            (Some(_), _) => return Ok(Cow::Borrowed(params)),
+            // without a limit Meilisearch returns its own default number of hits, which can be over the maximum
+            (None, Some(max)) => self
+                .default
+                .unwrap_or(MEILISEARCH_DEFAULT_SEARCH_LIMIT)
+                .min(max),
+            (None, None) => match self.default {
+                Some(default) => default,
+                None => return Ok(Cow::Borrowed(params)),
+            },
+        };
+
+        Ok(Cow::Owned(SearchParams {
+            limit: Some(limit),
+            ..params.clone()
+        }))
+    }
 }
 
 impl Client {
     /// Create a client using the specified server.
-    /// Don't put a '/' at the end of the host.
+    /// The host can contain a base path, ie `https://example.com/search`, when Meilisearch is behind a reverse proxy.
+    /// A trailing '/' is ignored.
     /// In production mode, see [the documentation about authentication](https://docs.meilisearch.com/reference/features/authentication.html#authentication).
     /// # Example
     ///
@@ -36,8 +391,23 @@ impl Client {
     /// ```
     pub fn new(host: impl Into<String>, api_key: impl Into<String>) -> Client {
         Client {
-            host: host.into(),
+            host: normalize_host(host.into()),
             api_key: api_key.into(),
+            proxy: ProxyConfig::default(),
+            max_response_bytes: None,
+            stream_hits: false,
+            search_limits: SearchLimits::default(),
+            attributes_cache: AttributesCache::default(),
+            embedders_cache: EmbeddersCache::default(),
+            primary_keys_cache: PrimaryKeysCache::default(),
+            key_provider: None,
+            search_postprocessor: None,
+            clock: Arc::new(SystemClock::default()),
+            wait_policy: WaitPolicy::default(),
+            base_filters: Vec::new(),
+            in_flight: None,
+            #[cfg(feature = "metrics")]
+            metrics: crate::metrics::Metrics::default(),
         }
     }
 
@@ -50,14 +420,14 @@ impl Client {
             total: value["total"].as_u64().unwrap() as u32,
             results: raw_indexes
                 .iter()
-                .map(|raw_index| Index::from_value(raw_index.clone(), self.clone()))
+                .map(|raw_index| Index::from_value(raw_index.clone(), self.index_client()))
                 .collect::<Result<_, _>>()?,
         };
 
         Ok(indexes_results)
     }
 
-    /// List all [Index]es with query parameters and returns values as instances of [Index].
+    /// List all [Index]es and returns values as instances of [Index].
     ///
     /// # Example
     ///
@@ -71,17 +441,17 @@ impl Client {
     /// // create the client
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
     ///
-    /// let indexes: IndexesResults = client.list_all_indexes().await.unwrap();
+    /// let indexes: IndexesResults = client.get_indexes().await.unwrap();
     /// println!("{:?}", indexes);
     /// # });
     /// ```
-    pub async fn list_all_indexes(&self) -> Result<IndexesResults, Error> {
-        let value = self.list_all_indexes_raw().await?;
+    pub async fn get_indexes(&self) -> Result<IndexesResults, Error> {
+        let value = self.get_indexes_raw().await?;
         let indexes_results = self.parse_indexes_results_from_value(value)?;
         Ok(indexes_results)
     }
 
-    /// List all [Index]es and returns values as instances of [Index].
+    /// List all [Index]es with query parameters and returns values as instances of [Index].
     ///
     /// # Example
     ///
@@ -96,16 +466,16 @@ impl Client {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
     /// let mut query = IndexesQuery::new(&client);
     /// query.with_limit(1);
-    /// let indexes: IndexesResults = client.list_all_indexes_with(&query).await.unwrap();
+    /// let indexes: IndexesResults = client.get_indexes_with(&query).await.unwrap();
     ///
     /// assert_eq!(indexes.limit, 1);
     /// # });
     /// ```
-    pub async fn list_all_indexes_with(
+    pub async fn get_indexes_with(
         &self,
         indexes_query: &IndexesQuery<'_>,
     ) -> Result<IndexesResults, Error> {
-        let value = self.list_all_indexes_raw_with(indexes_query).await?;
+        let value = self.get_indexes_raw_with(indexes_query).await?;
         let indexes_results = self.parse_indexes_results_from_value(value)?;
 
         Ok(indexes_results)
@@ -125,14 +495,14 @@ impl Client {
     /// // create the client
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
     ///
-    /// let json_indexes = client.list_all_indexes_raw().await.unwrap();
+    /// let json_indexes = client.get_indexes_raw().await.unwrap();
     /// println!("{:?}", json_indexes);
     /// # });
     /// ```
-    pub async fn list_all_indexes_raw(&self) -> Result<Value, Error> {
+    pub async fn get_indexes_raw(&self) -> Result<Value, Error> {
         let json_indexes = request::<(), Value>(
             &format!("{}/indexes", self.host),
-            &self.api_key,
+            self,
             Method::Get(()),
             200,
         )
@@ -157,18 +527,18 @@ impl Client {
     ///
     /// let mut query = IndexesQuery::new(&client);
     /// query.with_limit(1);
-    /// let json_indexes = client.list_all_indexes_raw_with(&query).await.unwrap();
+    /// let json_indexes = client.get_indexes_raw_with(&query).await.unwrap();
     ///
     /// println!("{:?}", json_indexes);
     /// # });
     /// ```
-    pub async fn list_all_indexes_raw_with(
+    pub async fn get_indexes_raw_with(
         &self,
         indexes_query: &IndexesQuery<'_>,
     ) -> Result<Value, Error> {
         let json_indexes = request::<&IndexesQuery, Value>(
             &format!("{}/indexes", self.host),
-            &self.api_key,
+            self,
             Method::Get(indexes_query),
             200,
         )
@@ -229,7 +599,7 @@ impl Client {
     pub async fn get_raw_index(&self, uid: impl AsRef<str>) -> Result<Value, Error> {
         request::<(), Value>(
             &format!("{}/indexes/{}", self.host, uid.as_ref()),
-            &self.api_key,
+            self,
             Method::Get(()),
             200,
         )
@@ -237,8 +607,69 @@ impl Client {
     }
 
     /// Create a corresponding object of an [Index] without any check or doing an HTTP call.
+    /// See [Client::try_index] to validate the uid first.
     pub fn index(&self, uid: impl Into<String>) -> Index {
-        Index::new(uid, self.clone())
+        Index::new(uid, self.index_client())
+    }
+
+    /// A clone of the client for an [Index], which doesn't keep its requests from being
+    /// [aborted](ClientBuilder::with_abort_on_drop) when the client is dropped.
+    fn index_client(&self) -> Client {
+        Client {
+            in_flight: self.in_flight.as_ref().map(InFlightRequests::without_owner),
+            ..self.clone()
+        }
+    }
+
+    /// Create a [TypedIndex] whose document methods all use `T`, without any check or doing an HTTP call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Movie {
+    ///     id: String,
+    /// }
+    ///
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.typed_index::<Movie>("movies");
+    ///
+    /// assert_eq!(movies.uid, "movies");
+    /// ```
+    pub fn typed_index<T: 'static + Serialize + DeserializeOwned>(
+        &self,
+        uid: impl Into<String>,
+    ) -> TypedIndex<T> {
+        TypedIndex::new(self.index(uid))
+    }
+
+    /// Create a corresponding object of an [Index] without doing an HTTP call,
+    /// after checking its uid is valid with [Index::validate_uid].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    ///
+    /// assert!(client.try_index("movies").is_ok());
+    /// assert!(matches!(client.try_index("my movies"), Err(Error::InvalidIndexUid(_))));
+    /// ```
+    pub fn try_index(&self, uid: impl Into<String>) -> Result<Index, Error> {
+        let uid = uid.into();
+        Index::validate_uid(&uid)?;
+
+        Ok(Index::new(uid, self.index_client()))
     }
 
     /// Create an [Index].
@@ -274,9 +705,11 @@ impl Client {
         uid: impl AsRef<str>,
         primary_key: Option<&str>,
     ) -> Result<TaskInfo, Error> {
+        Index::validate_uid(uid.as_ref())?;
+
         request::<Value, TaskInfo>(
             &format!("{}/indexes", self.host),
-            &self.api_key,
+            self,
             Method::Post(json!({
                 "uid": uid.as_ref(),
                 "primaryKey": primary_key,
@@ -291,182 +724,444 @@ impl Client {
     pub async fn delete_index(&self, uid: impl AsRef<str>) -> Result<TaskInfo, Error> {
         request::<(), TaskInfo>(
             &format!("{}/indexes/{}", self.host, uid.as_ref()),
-            &self.api_key,
+            self,
             Method::Delete,
             202,
         )
         .await
     }
 
-    /// Alias for [Client::list_all_indexes].
-    pub async fn get_indexes(&self) -> Result<IndexesResults, Error> {
-        self.list_all_indexes().await
+    /// Deprecated alias of [Client::get_indexes].
+    #[deprecated(note = "use Client::get_indexes instead")]
+    pub async fn list_all_indexes(&self) -> Result<IndexesResults, Error> {
+        self.get_indexes().await
     }
 
-    /// Alias for [Client::list_all_indexes_with].
-    pub async fn get_indexes_with(
+    /// Deprecated alias of [Client::get_indexes_with].
+    #[deprecated(note = "use Client::get_indexes_with instead")]
+    pub async fn list_all_indexes_with(
         &self,
         indexes_query: &IndexesQuery<'_>,
     ) -> Result<IndexesResults, Error> {
-        self.list_all_indexes_with(indexes_query).await
+        self.get_indexes_with(indexes_query).await
     }
 
-    /// Alias for [Client::list_all_indexes_raw].
-    pub async fn get_indexes_raw(&self) -> Result<Value, Error> {
-        self.list_all_indexes_raw().await
+    /// Deprecated alias of [Client::get_indexes_raw].
+    #[deprecated(note = "use Client::get_indexes_raw instead")]
+    pub async fn list_all_indexes_raw(&self) -> Result<Value, Error> {
+        self.get_indexes_raw().await
     }
 
-    /// Alias for [Client::list_all_indexes_raw_with].
-    pub async fn get_indexes_raw_with(
+    /// Deprecated alias of [Client::get_indexes_raw_with].
+    #[deprecated(note = "use Client::get_indexes_raw_with instead")]
+    pub async fn list_all_indexes_raw_with(
         &self,
         indexes_query: &IndexesQuery<'_>,
     ) -> Result<Value, Error> {
-        self.list_all_indexes_raw_with(indexes_query).await
+        self.get_indexes_raw_with(indexes_query).await
     }
 
-    /// Get stats of all indexes.
+    /// Search for documents in the index with the given uid, without building an [Index] first.\
+    /// See also [Index::execute_query].
     ///
     /// # Example
     ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// # use serde::Deserialize;
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
+    /// # #[derive(Deserialize)]
+    /// # struct Movie {
+    /// #     name: String,
+    /// # }
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// let stats = client.get_stats().await.unwrap();
+    /// let results = client.search::<Movie>("movies", SearchParams::new().with_query("Interstellar")).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_stats(&self) -> Result<ClientStats, Error> {
-        request::<(), ClientStats>(
-            &format!("{}/stats", self.host),
-            &self.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn search<T: 'static + DeserializeOwned>(
+        &self,
+        index_uid: &str,
+        params: SearchParams<'_>,
+    ) -> Result<SearchResults<T>, Error> {
+        self.execute_search::<T>(index_uid, &params).await
     }
 
-    /// Get health of Meilisearch server.
+    pub(crate) async fn execute_search<T: 'static + DeserializeOwned>(
+        &self,
+        index_uid: &str,
+        params: &SearchParams<'_>,
+    ) -> Result<SearchResults<T>, Error> {
+        if let Some(vector) = &params.vector {
+            crate::vectors::validate(vector)?;
+        }
+        let params = self.search_limits.apply(params)?;
+        let filtered = self.with_base_filters(&params);
+        let url = format!("{}/indexes/{}/search", self.host, index_uid);
+        // the filters are joined into a single string in the URL, where a filter with unbalanced parentheses
+        // could escape the base filters, while each filter of the body is parsed on its own
+        if params.cacheable && filtered.is_none() {
+            request_search::<BTreeMap<String, String>, T>(
+                &url,
+                self,
+                Method::Get(params.to_query_parameters()),
+                200,
+            )
+            .await
+        } else {
+            let params = filtered.as_ref().unwrap_or(&params);
+            request_search::<&SearchParams, T>(&url, self, Method::Post(params), 200).await
+        }
+    }
+
+    /// The `params` whose filter is combined with `AND` to the [base filters](crate::indexes::Index::with_base_filter),
+    /// `None` when there is no base filter.
+    fn with_base_filters<'p>(&'p self, params: &SearchParams<'p>) -> Option<SearchParams<'p>> {
+        self.combine_base_filters(params.filter.as_ref())
+            .map(|filter| SearchParams {
+                filter: Some(filter),
+                ..params.clone()
+            })
+    }
+
+    /// The `filter` combined with `AND` to the [base filters](crate::indexes::Index::with_base_filter),
+    /// `None` when there is no base filter.
+    pub(crate) fn combine_base_filters<'p>(
+        &'p self,
+        filter: Option<&Filter<'p>>,
+    ) -> Option<Filter<'p>> {
+        if self.base_filters.is_empty() {
+            return None;
+        }
+        let mut filters: Vec<Cow<str>> = self
+            .base_filters
+            .iter()
+            .map(|filter| Cow::Borrowed(filter.as_str()))
+            .collect();
+        match filter.map(|filter| &filter.inner) {
+            Some(Either::Left(filter)) => filters.push(filter.clone()),
+            Some(Either::Right(query_filters)) => filters.extend(query_filters.iter().cloned()),
+            None => {}
+        }
+
+        Some(Filter {
+            inner: Either::Right(filters),
+        })
+    }
+
+    /// Search for the values of a facet in the index with the given uid, without building an [Index] first.\
+    /// See also [Index::facet_search].
     ///
     /// # Example
     ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, errors::{Error, ErrorCode}};
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, search::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// let health = client.health().await.unwrap();
-    /// assert_eq!(health.status, "available");
+    /// let genres = client.facet_search("movies", &FacetSearchParams::new("genres").with_facet_query("fic")).await.unwrap();
     /// # });
     /// ```
-    pub async fn health(&self) -> Result<Health, Error> {
-        request::<(), Health>(
-            &format!("{}/health", self.host),
-            &self.api_key,
-            Method::Get(()),
+    pub async fn facet_search(
+        &self,
+        index_uid: &str,
+        params: &FacetSearchParams<'_>,
+    ) -> Result<FacetSearchResponse, Error> {
+        let filtered = self
+            .combine_base_filters(params.filter.as_ref())
+            .map(|filter| FacetSearchParams {
+                filter: Some(filter),
+                ..params.clone()
+            });
+        request::<&FacetSearchParams, FacetSearchResponse>(
+            &format!("{}/indexes/{}/facet-search", self.host, index_uid),
+            self,
+            Method::Post(filtered.as_ref().unwrap_or(params)),
             200,
         )
         .await
     }
 
-    /// Get health of Meilisearch server, return true or false.
+    /// Get stats of all indexes.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::client::*;
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// let health = client.is_healthy().await;
-    /// assert_eq!(health, true);
+    /// let stats = client.get_stats().await.unwrap();
     /// # });
     /// ```
-    pub async fn is_healthy(&self) -> bool {
-        if let Ok(health) = self.health().await {
-            health.status.as_str() == "available"
-        } else {
-            false
-        }
+    pub async fn get_stats(&self) -> Result<ClientStats, Error> {
+        request::<(), ClientStats>(&format!("{}/stats", self.host), self, Method::Get(()), 200)
+            .await
     }
 
-    /// Get the API [Key]s from Meilisearch with parameters.
-    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-all-keys).
-    ///
-    /// See also [Client::create_key] and [Client::get_key].
+    /// Fetch the settings of two indexes and list the settings whose values differ, ie to check in CI
+    /// that the staging and production indexes are configured identically.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, errors::Error, key::KeysQuery};
+    /// # use meilisearch_sdk::{client::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// let mut query = KeysQuery::new();
-    /// query.with_limit(1);
-    /// let keys = client.get_keys_with(&query).await.unwrap();
-    ///
-    /// assert_eq!(keys.results.len(), 1);
+    /// # for uid in ["compare_settings_staging", "compare_settings_production"] {
+    /// #   client.create_index(uid, None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # }
+    /// let diff = client
+    ///     .compare_settings("compare_settings_staging", "compare_settings_production")
+    ///     .await
+    ///     .unwrap();
+    /// assert!(diff.is_empty(), "{}", diff);
+    /// # for uid in ["compare_settings_staging", "compare_settings_production"] {
+    /// #   client.index(uid).delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # }
     /// # });
     /// ```
-    pub async fn get_keys_with(&self, keys_query: &KeysQuery) -> Result<KeysResults, Error> {
-        let keys = request::<&KeysQuery, KeysResults>(
-            &format!("{}/keys", self.host),
-            &self.api_key,
-            Method::Get(keys_query),
-            200,
-        )
-        .await?;
-
-        Ok(keys)
+    pub async fn compare_settings(
+        &self,
+        uid_a: impl AsRef<str>,
+        uid_b: impl AsRef<str>,
+    ) -> Result<SettingsDiff, Error> {
+        let index_a = self.index(uid_a.as_ref());
+        let index_b = self.index(uid_b.as_ref());
+        let (a, b) =
+            futures::future::try_join(index_a.get_settings(), index_b.get_settings()).await?;
+
+        Ok(SettingsDiff::new(index_a.uid, &a, index_b.uid, &b))
     }
 
-    /// Get the API [Key]s from Meilisearch.
-    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-all-keys).
+    /// Get the stats of the indexes `uids`, with at most `concurrency` requests sent at the same time.
     ///
-    /// See also [Client::create_key] and [Client::get_key].
+    /// When the stats can't be fetched in a single round of requests, the stats of all the indexes
+    /// are fetched at once with [Client::get_stats] and filtered instead.
+    /// A missing index only fails its own entry, with an [ErrorCode::IndexNotFound] error.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, errors::Error, key::KeyBuilder};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// let keys = client.get_keys().await.unwrap();
+    /// # client.create_index("get_stats_for", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let stats = client.get_stats_for(&["get_stats_for", "get_stats_for_missing"], 4).await.unwrap();
     ///
-    /// assert_eq!(keys.limit, 20);
+    /// assert!(stats["get_stats_for"].is_ok());
+    /// assert!(stats["get_stats_for_missing"].is_err());
+    /// # client.index("get_stats_for").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_keys(&self) -> Result<KeysResults, Error> {
-        let keys = request::<(), KeysResults>(
-            &format!("{}/keys", self.host),
-            &self.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await?;
+    pub async fn get_stats_for(
+        &self,
+        uids: &[&str],
+        concurrency: usize,
+    ) -> Result<HashMap<String, Result<IndexStats, Error>>, Error> {
+        if !prefers_global_stats(uids.len(), concurrency) {
+            return Ok(stream::iter(uids)
+                .map(|uid| async move { (uid.to_string(), self.index(*uid).get_stats().await) })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await);
+        }
 
-        Ok(keys)
+        let mut indexes = self.get_stats().await?.indexes;
+        let mut stats = HashMap::with_capacity(uids.len());
+        for uid in uids {
+            if stats.contains_key(*uid) {
+                continue;
+            }
+            let index_stats = indexes.remove(*uid).ok_or_else(|| {
+                Error::Meilisearch(MeilisearchError {
+                    error_message: format!("Index `{}` not found.", uid),
+                    error_code: ErrorCode::IndexNotFound,
+                    error_type: ErrorType::InvalidRequest,
+                    error_link: "https://docs.meilisearch.com/errors#index_not_found".to_string(),
+                })
+            });
+            stats.insert(uid.to_string(), index_stats);
+        }
+        Ok(stats)
     }
 
-    /// Get one API [Key] from Meilisearch.
-    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-one-key).
+    /// Get health of Meilisearch server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::{Error, ErrorCode}};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let health = client.health().await.unwrap();
+    /// assert_eq!(health.status, "available");
+    /// # });
+    /// ```
+    pub async fn health(&self) -> Result<Health, Error> {
+        request::<(), Health>(&format!("{}/health", self.host), self, Method::Get(()), 200).await
+    }
+
+    /// Get health of Meilisearch server, return true or false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let health = client.is_healthy().await;
+    /// assert_eq!(health, true);
+    /// # });
+    /// ```
+    pub async fn is_healthy(&self) -> bool {
+        if let Ok(health) = self.health().await {
+            health.status.as_str() == "available"
+        } else {
+            false
+        }
+    }
+
+    /// Check that the Meilisearch server is ready to serve requests, ie for a readiness probe.
+    ///
+    /// The health and the version of the server are fetched concurrently.
+    /// See [Client::readiness_with_stats] to also know which indexes are indexing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let readiness = client.readiness().await.unwrap();
+    /// assert!(readiness.is_ready());
+    /// # });
+    /// ```
+    pub async fn readiness(&self) -> Result<Readiness, Error> {
+        let (health, version) =
+            futures::future::try_join(self.health(), self.get_version()).await?;
+
+        Ok(Readiness {
+            status: health.status,
+            version,
+            indexing: None,
+        })
+    }
+
+    /// Same as [Client::readiness], but also fetch the [stats](Client::get_stats) of the indexes
+    /// to fill [Readiness::indexing].
+    ///
+    /// It is slower on an instance with many indexes.
+    pub async fn readiness_with_stats(&self) -> Result<Readiness, Error> {
+        let (readiness, stats) =
+            futures::future::try_join(self.readiness(), self.get_stats()).await?;
+        let mut indexing: Vec<String> = stats
+            .indexes
+            .into_iter()
+            .filter(|(_, stats)| stats.is_indexing)
+            .map(|(uid, _)| uid)
+            .collect();
+        indexing.sort();
+
+        Ok(Readiness {
+            indexing: Some(indexing),
+            ..readiness
+        })
+    }
+
+    /// Get the API [Key]s from Meilisearch with parameters.
+    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-all-keys).
+    ///
+    /// See also [Client::create_key] and [Client::get_key].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error, key::KeysQuery};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let mut query = KeysQuery::new();
+    /// query.with_limit(1);
+    /// let keys = client.get_keys_with(&query).await.unwrap();
+    ///
+    /// assert_eq!(keys.results.len(), 1);
+    /// # });
+    /// ```
+    pub async fn get_keys_with(&self, keys_query: &KeysQuery) -> Result<KeysResults, Error> {
+        let keys = request::<&KeysQuery, KeysResults>(
+            &format!("{}/keys", self.host),
+            self,
+            Method::Get(keys_query),
+            200,
+        )
+        .await?;
+
+        Ok(keys)
+    }
+
+    /// Get the API [Key]s from Meilisearch.
+    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-all-keys).
+    ///
+    /// See also [Client::create_key] and [Client::get_key].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error, key::KeyBuilder};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let keys = client.get_keys().await.unwrap();
+    ///
+    /// assert_eq!(keys.limit, 20);
+    /// # });
+    /// ```
+    pub async fn get_keys(&self) -> Result<KeysResults, Error> {
+        let keys =
+            request::<(), KeysResults>(&format!("{}/keys", self.host), self, Method::Get(()), 200)
+                .await?;
+
+        Ok(keys)
+    }
+
+    /// Get one API [Key] from Meilisearch.
+    /// See the [meilisearch documentation](https://docs.meilisearch.com/reference/api/keys.html#get-one-key).
     ///
     /// See also [Client::create_key] and [Client::get_keys].
     ///
@@ -492,7 +1187,7 @@ impl Client {
     pub async fn get_key(&self, key: impl AsRef<str>) -> Result<Key, Error> {
         request::<(), Key>(
             &format!("{}/keys/{}", self.host, key.as_ref()),
-            &self.api_key,
+            self,
             Method::Get(()),
             200,
         )
@@ -527,7 +1222,7 @@ impl Client {
     pub async fn delete_key(&self, key: impl AsRef<str>) -> Result<(), Error> {
         request::<(), ()>(
             &format!("{}/keys/{}", self.host, key.as_ref()),
-            &self.api_key,
+            self,
             Method::Delete,
             204,
         )
@@ -561,7 +1256,7 @@ impl Client {
     pub async fn create_key(&self, key: impl AsRef<KeyBuilder>) -> Result<Key, Error> {
         request::<&KeyBuilder, Key>(
             &format!("{}/keys", self.host),
-            &self.api_key,
+            self,
             Method::Post(key.as_ref()),
             201,
         )
@@ -597,7 +1292,7 @@ impl Client {
     pub async fn update_key(&self, key: impl AsRef<KeyUpdater>) -> Result<Key, Error> {
         request::<&KeyUpdater, Key>(
             &format!("{}/keys/{}", self.host, key.as_ref().key),
-            &self.api_key,
+            self,
             Method::Patch(key.as_ref()),
             200,
         )
@@ -622,7 +1317,7 @@ impl Client {
     pub async fn get_version(&self) -> Result<Version, Error> {
         request::<(), Version>(
             &format!("{}/version", self.host),
-            &self.api_key,
+            self,
             Method::Get(()),
             200,
         )
@@ -631,8 +1326,8 @@ impl Client {
 
     /// Wait until Meilisearch processes a [Task], and get its status.
     ///
-    /// `interval` = The frequency at which the server should be polled. Default = 50ms
-    /// `timeout` = The maximum time to wait for processing to complete. Default = 5000ms
+    /// `interval` = The frequency at which the server should be polled. Default = the [WaitPolicy] of the client, 50ms
+    /// `timeout` = The maximum time to wait for processing to complete. Default = the [WaitPolicy] of the client, 5000ms
     ///
     /// If the waited time exceeds `timeout` then an [Error::Timeout] will be returned.
     ///
@@ -677,29 +1372,108 @@ impl Client {
         interval: Option<Duration>,
         timeout: Option<Duration>,
     ) -> Result<Task, Error> {
-        let interval = interval.unwrap_or_else(|| Duration::from_millis(50));
-        let timeout = timeout.unwrap_or_else(|| Duration::from_millis(5000));
-
-        let mut elapsed_time = Duration::new(0, 0);
-        let mut task_result: Result<Task, Error>;
-
-        while timeout > elapsed_time {
-            task_result = self.get_task(&task_id).await;
-            match task_result {
-                Ok(status) => match status {
-                    Task::Failed { .. } | Task::Succeeded { .. } => {
-                        return self.get_task(task_id).await;
+        self.wait_for_task_with_status_change(task_id, interval, timeout, |_| {})
+            .await
+    }
+
+    /// Wait until Meilisearch processes a [Task] like [Client::wait_for_task], calling `on_status_change` each time a new status is observed.
+    ///
+    /// The callback is called once per status, even if the same status is observed by several polls,
+    /// and the terminal status ([TaskStatus::Succeeded], [TaskStatus::Failed] or [TaskStatus::Canceled]) is always the last one reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, tasks::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let task = client.create_index("wait_for_task_with_status_change", None).await.unwrap();
+    ///
+    /// let mut statuses = Vec::new();
+    /// let task = client
+    ///     .wait_for_task_with_status_change(task, None, None, |status| statuses.push(status))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(statuses.last(), Some(&TaskStatus::Succeeded));
+    /// # task.try_make_index(&client).unwrap().delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn wait_for_task_with_status_change(
+        &self,
+        task_id: impl AsRef<u32>,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+        mut on_status_change: impl FnMut(TaskStatus),
+    ) -> Result<Task, Error> {
+        let interval = interval.unwrap_or(self.wait_policy.interval);
+        let timeout = timeout.unwrap_or(self.wait_policy.timeout);
+
+        #[cfg(feature = "metrics")]
+        let stopwatch = crate::metrics::Stopwatch::start();
+        let result = async {
+            let start = self.clock.now();
+            let mut last_status = None;
+
+            while timeout > self.clock.now().saturating_sub(start) {
+                let task = match self.get_task_raw(&task_id).await {
+                    // the task was deleted, ie along with its index, it will never be processed
+                    Err(Error::Meilisearch(error))
+                        if error.error_code == ErrorCode::TaskNotFound =>
+                    {
+                        return Err(Error::TaskVanished {
+                            uid: *task_id.as_ref(),
+                        })
                     }
+                    result => result?,
+                };
+                let status = match TaskStatus::deserialize(&task["status"]) {
+                    Ok(status) => status,
+                    Err(error) => return Err(Error::parse_error::<Task>(task.to_string(), error)),
+                };
+                if last_status != Some(status) {
+                    last_status = Some(status);
+                    on_status_change(status);
+                }
+                if status == TaskStatus::Canceled {
+                    return Err(Error::TaskCanceled {
+                        uid: *task_id.as_ref(),
+                        by: Option::<u32>::deserialize(&task["canceledBy"]).unwrap_or_default(),
+                    });
+                }
+                let task = Task::deserialize(&task)
+                    .map_err(|error| Error::parse_error::<Task>(task.to_string(), error))?;
+
+                match task {
+                    Task::Failed { .. } | Task::Succeeded { .. } => return Ok(task),
                     Task::Enqueued { .. } | Task::Processing { .. } => {
-                        elapsed_time += interval;
-                        async_sleep(interval).await;
+                        self.clock.sleep(interval).await;
                     }
-                },
-                Err(error) => return Err(error),
-            };
+                }
+            }
+
+            Err(Error::Timeout)
         }
+        .await;
+
+        #[cfg(feature = "metrics")]
+        self.metrics.record_task_wait(
+            match &result {
+                Ok(Task::Succeeded { .. }) => "succeeded",
+                Ok(_) => "failed",
+                Err(Error::Timeout) => "timeout",
+                Err(Error::TaskCanceled { .. }) => "canceled",
+                Err(Error::TaskVanished { .. }) => "vanished",
+                Err(_) => "error",
+            },
+            stopwatch,
+        );
 
-        Err(Error::Timeout)
+        result
     }
 
     /// Get a task from the server given a task id.
@@ -723,7 +1497,64 @@ impl Client {
     pub async fn get_task(&self, task_id: impl AsRef<u32>) -> Result<Task, Error> {
         request::<(), Task>(
             &format!("{}/tasks/{}", self.host, task_id.as_ref()),
-            &self.api_key,
+            self,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get a task from the server given a task id, as the JSON returned by Meilisearch.
+    pub async fn get_task_raw(&self, task_id: impl AsRef<u32>) -> Result<Value, Error> {
+        request::<(), Value>(
+            &format!("{}/tasks/{}", self.host, task_id.as_ref()),
+            self,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get a task from the server given a task id, with its `details` deserialized into `D`.
+    ///
+    /// Use it when the details of the task aren't known by [TaskType](crate::tasks::TaskType),
+    /// ie when they're extended by a fork of Meilisearch. The other fields are typed as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Deserialize)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct AdditionDetails {
+    ///     received_documents: usize,
+    ///     indexed_documents: Option<usize>,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("get_task_with_details");
+    /// let task = movies.add_documents(&[json!({ "id": 1 })], Some("id")).await.unwrap();
+    /// let task = task.wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let task = client.get_task_with_details::<AdditionDetails>(task).await.unwrap();
+    /// assert_eq!(task.details.unwrap().received_documents, 1);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_task_with_details<D: DeserializeOwned + 'static>(
+        &self,
+        task_id: impl AsRef<u32>,
+    ) -> Result<TaskWith<D>, Error> {
+        request::<(), TaskWith<D>>(
+            &format!("{}/tasks/{}", self.host, task_id.as_ref()),
+            self,
             Method::Get(()),
             200,
         )
@@ -754,7 +1585,7 @@ impl Client {
     ) -> Result<TasksResults, Error> {
         let tasks = request::<&TasksQuery, TasksResults>(
             &format!("{}/tasks", self.host),
-            &self.api_key,
+            self,
             Method::Get(tasks_query),
             200,
         )
@@ -763,6 +1594,67 @@ impl Client {
         Ok(tasks)
     }
 
+    /// Poll the tasks matching `tasks_query` every `interval` and stream each task whose status changed since the previous poll.
+    ///
+    /// The first poll emits all the matching tasks, the oldest first. A task is emitted once per status it goes through,
+    /// as long as the poll happens while it has this status. Polling stops when the stream is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let mut query = TasksQuery::new(&client);
+    /// query.with_index_uid(["movies"]);
+    ///
+    /// let mut tasks = Box::pin(client.watch_tasks(&query, Duration::from_secs(1)));
+    /// while let Some(task) = tasks.next().await {
+    ///     println!("{:?}", task.unwrap());
+    /// }
+    /// # });
+    /// ```
+    pub fn watch_tasks<'a>(
+        &'a self,
+        tasks_query: &'a TasksQuery<'a>,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Task, Error>> + 'a {
+        // the last status of every task seen, and the changes not emitted yet
+        let state = (HashMap::new(), VecDeque::new(), true);
+
+        stream::unfold(
+            state,
+            move |(mut statuses, mut changes, mut first_poll)| async move {
+                loop {
+                    if let Some(task) = changes.pop_front() {
+                        return Some((Ok(task), (statuses, changes, first_poll)));
+                    }
+                    if !first_poll {
+                        self.clock.sleep(interval).await;
+                    }
+                    first_poll = false;
+
+                    match self.get_tasks_with(tasks_query).await {
+                        // the most recent tasks come first
+                        Ok(tasks) => {
+                            changes.extend(tasks.results.into_iter().rev().filter(|task| {
+                                let status = mem::discriminant(task);
+                                statuses.insert(task.get_uid(), status) != Some(status)
+                            }))
+                        }
+                        Err(error) => return Some((Err(error), (statuses, changes, first_poll))),
+                    }
+                }
+            },
+        )
+    }
+
     /// Get all tasks from the server.
     ///
     /// # Example
@@ -784,7 +1676,7 @@ impl Client {
     pub async fn get_tasks(&self) -> Result<TasksResults, Error> {
         let tasks = request::<(), TasksResults>(
             &format!("{}/tasks", self.host),
-            &self.api_key,
+            self,
             Method::Get(()),
             200,
         )
@@ -793,6 +1685,57 @@ impl Client {
         Ok(tasks)
     }
 
+    /// Delete the finished tasks matching the given statuses that finished before `date`.\
+    /// If `statuses` is empty, the tasks are deleted whatever their status.
+    ///
+    /// The deletion is itself a task, whose details contain the number of matched tasks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, tasks::*};
+    /// # use time::{Duration, OffsetDateTime};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// // delete the succeeded tasks older than a week
+    /// let task = client
+    ///     .purge_tasks_before(OffsetDateTime::now_utc() - Duration::WEEK, &[TaskStatus::Succeeded])
+    ///     .await
+    ///     .unwrap()
+    ///     .wait_for_completion(&client, None, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert!(task.is_success());
+    /// # });
+    /// ```
+    pub async fn purge_tasks_before(
+        &self,
+        date: OffsetDateTime,
+        statuses: &[TaskStatus],
+    ) -> Result<TaskInfo, Error> {
+        let query = yaup::to_string(&DeleteTasksQuery {
+            before_finished_at: date,
+            statuses: if statuses.is_empty() {
+                None
+            } else {
+                Some(statuses)
+            },
+        })?;
+
+        request::<(), TaskInfo>(
+            &format!("{}/tasks?{}", self.host, query),
+            self,
+            Method::Delete,
+            200,
+        )
+        .await
+    }
+
     /// Generates a new tenant token.
     ///
     /// # Example
@@ -820,10 +1763,343 @@ impl Client {
     ) -> Result<String, Error> {
         let api_key = api_key.unwrap_or(&self.api_key);
 
-        crate::tenant_tokens::generate_tenant_token(api_key_uid, search_rules, api_key, expires_at)
+        crate::tenant_tokens::generate_tenant_token(api_key_uid, search_rules, api_key, expires_at)
+    }
+}
+
+/// A builder to configure how a [Client] reaches the Meilisearch server.
+///
+/// By default the proxy defined by the `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and `NO_PROXY` environment variables is used.
+/// The proxy settings are ignored when compiling to wasm, where the browser handles them.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::client::*;
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// let client = ClientBuilder::new(MEILISEARCH_URL, MEILISEARCH_API_KEY)
+///     .with_proxy("http://proxy.example.com:3128")
+///     .with_no_proxy(["localhost", "127.0.0.1"])
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    host: String,
+    base_path: Option<String>,
+    api_key: String,
+    proxy: ProxyConfig,
+    max_response_bytes: Option<usize>,
+    stream_hits: bool,
+    search_limits: SearchLimits,
+    key_provider: Option<Arc<KeyProvider>>,
+    search_postprocessor: Option<SearchPostprocessor>,
+    clock: Arc<dyn Clock>,
+    wait_policy: WaitPolicy,
+    abort_on_drop: bool,
+    #[cfg(feature = "metrics")]
+    metrics: crate::metrics::Metrics,
+}
+
+impl ClientBuilder {
+    /// Create a [ClientBuilder] for the specified server.
+    /// The host can contain a base path, a trailing '/' is ignored.
+    pub fn new(host: impl Into<String>, api_key: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            host: normalize_host(host.into()),
+            base_path: None,
+            api_key: api_key.into(),
+            proxy: ProxyConfig::default(),
+            max_response_bytes: None,
+            stream_hits: false,
+            search_limits: SearchLimits::default(),
+            key_provider: None,
+            search_postprocessor: None,
+            clock: Arc::new(SystemClock::default()),
+            wait_policy: WaitPolicy::default(),
+            abort_on_drop: false,
+            #[cfg(feature = "metrics")]
+            metrics: crate::metrics::Metrics::default(),
+        }
+    }
+
+    /// Prefix all the routes with `path`, ie `/search` when Meilisearch is served from `https://example.com/search`
+    /// by a reverse proxy.
+    ///
+    /// The leading and trailing '/' of `path` are ignored, so `search`, `/search` and `/search/` are equivalent.
+    /// Calling it again replaces the previous base path, so the routes are prefixed once.
+    pub fn with_base_path(&mut self, path: impl AsRef<str>) -> &mut ClientBuilder {
+        let path = path.as_ref().trim_matches('/');
+        self.base_path = (!path.is_empty()).then(|| path.to_string());
+        self
+    }
+
+    /// Send all the requests through the given proxy, ie `http://proxy.example.com:3128`.
+    ///
+    /// An invalid proxy url makes every request fail with [Error::InvalidRequest].
+    pub fn with_proxy(&mut self, url: impl Into<String>) -> &mut ClientBuilder {
+        self.proxy.url = Some(url.into());
+        self
+    }
+
+    /// Use the proxy defined in the environment variables when no proxy was specified with [ClientBuilder::with_proxy].
+    ///
+    /// Default: `true`
+    pub fn with_system_proxy(&mut self, system_proxy: bool) -> &mut ClientBuilder {
+        self.proxy.system = system_proxy;
+        self
+    }
+
+    /// Hosts that should be reached directly, without going through the proxy.
+    pub fn with_no_proxy(
+        &mut self,
+        hosts: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut ClientBuilder {
+        self.proxy.no_proxy = hosts
+            .into_iter()
+            .map(|host| host.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Stop reading a response of Meilisearch larger than `max_bytes` and fail with [Error::ResponseTooLarge] instead.
+    ///
+    /// It protects the memory of the application against a query returning far more data than expected.
+    /// When compiling to wasm the browser always reads the whole response, the limit is only checked afterward.
+    ///
+    /// Default: no limit
+    pub fn with_max_response_bytes(&mut self, max_bytes: usize) -> &mut ClientBuilder {
+        self.max_response_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Abort the requests in flight when the last clone of the built [Client] is dropped, so they fail with
+    /// [Error::Aborted] right away instead of keeping the caller waiting, ie while the application shuts down.
+    ///
+    /// The [indexes](Index) created by the client don't keep it alive: the requests sent through them are aborted
+    /// as well, and the ones sent afterward fail immediately.
+    /// A request borrowing the client itself, ie [Client::get_task], can't outlive it and is never aborted.
+    ///
+    /// Default: `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error};
+    /// # futures::executor::block_on(async move {
+    /// let client = ClientBuilder::new("http://localhost:7700", "masterKey")
+    ///     .with_abort_on_drop(true)
+    ///     .build();
+    /// let movies = client.index("movies");
+    ///
+    /// drop(client);
+    /// assert!(matches!(movies.get_stats().await, Err(Error::Aborted)));
+    /// # });
+    /// ```
+    pub fn with_abort_on_drop(&mut self, abort: bool) -> &mut ClientBuilder {
+        self.abort_on_drop = abort;
+        self
+    }
+
+    /// Deserialize the hits of the search responses one by one while the response is read, instead of reading
+    /// the whole response before deserializing it.
+    ///
+    /// Only one hit is kept in its JSON form at a time, which lowers the memory used by the searches returning
+    /// many or large documents. The hits are never streamed when compiling to wasm.
+    ///
+    /// Default: `false`
+    pub fn with_streamed_hits(&mut self, streamed: bool) -> &mut ClientBuilder {
+        self.stream_hits = streamed;
+        self
+    }
+
+    /// The number of hits returned by the searches that don't [set a limit](SearchParams::with_limit).
+    ///
+    /// Default: the default of Meilisearch, `20`
+    pub fn with_default_search_limit(&mut self, limit: usize) -> &mut ClientBuilder {
+        self.search_limits.default = Some(limit);
+        self
+    }
+
+    /// The maximum number of hits a search can ask for, to protect the server against expensive queries.
+    ///
+    /// A larger limit is lowered to `max` and a warning is logged, unless [ClientBuilder::with_strict_max_search_limit]
+    /// is enabled. The default limit, or the default of Meilisearch when there is none, is lowered as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = ClientBuilder::new(MEILISEARCH_URL, MEILISEARCH_API_KEY)
+    ///     .with_default_search_limit(10)
+    ///     .with_max_search_limit(50)
+    ///     .build();
+    /// ```
+    pub fn with_max_search_limit(&mut self, max: usize) -> &mut ClientBuilder {
+        self.search_limits.max = Some(max);
+        self
+    }
+
+    /// Fail with [Error::SearchLimitExceeded] instead of lowering the limit of the searches going beyond
+    /// the [maximum](ClientBuilder::with_max_search_limit).
+    ///
+    /// Default: `false`
+    pub fn with_strict_max_search_limit(&mut self, strict: bool) -> &mut ClientBuilder {
+        self.search_limits.strict = strict;
+        self
+    }
+
+    /// Get the API key from `provider` instead of using the one given to [ClientBuilder::new], ie to use short-lived keys.
+    ///
+    /// The key is fetched on the first request and cached. When Meilisearch rejects it with an `invalid_api_key` error,
+    /// a new key is fetched and the request is sent again, once. Concurrent requests share the same fetch.
+    /// The key given to [ClientBuilder::new] is still used to [generate tenant tokens](Client::generate_tenant_token).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// async fn fetch_key_from_vault() -> String {
+    ///     // ...
+    /// #   String::from("masterKey")
+    /// }
+    ///
+    /// let client = ClientBuilder::new(MEILISEARCH_URL, "")
+    ///     .with_key_provider(|| Box::pin(async { Ok(fetch_key_from_vault().await) }))
+    ///     .build();
+    /// ```
+    pub fn with_key_provider(
+        &mut self,
+        provider: impl Fn() -> ApiKeyFuture + Send + Sync + 'static,
+    ) -> &mut ClientBuilder {
+        self.key_provider = Some(Arc::new(KeyProvider {
+            fetch: Box::new(provider),
+            key: futures::lock::Mutex::new(None),
+        }));
+        self
+    }
+
+    /// Call `postprocessor` on the raw JSON response of every search, ie to redact some fields of the hits
+    /// or normalize their scores, without changing every call site.
+    ///
+    /// The hook runs before the response is deserialized into the [SearchResults] of the requested type,
+    /// so it sees and can change every field returned by Meilisearch. The hits are then not
+    /// [streamed](ClientBuilder::with_streamed_hits), since the whole response is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use serde_json::Value;
+    /// # use std::sync::Arc;
+    /// let client = ClientBuilder::new("http://localhost:7700", "masterKey")
+    ///     .with_search_postprocessor(Arc::new(|response: &mut Value| {
+    ///         for hit in response["hits"].as_array_mut().into_iter().flatten() {
+    ///             if let Some(hit) = hit.as_object_mut() {
+    ///                 hit.remove("email");
+    ///             }
+    ///         }
+    ///     }))
+    ///     .build();
+    /// ```
+    pub fn with_search_postprocessor(
+        &mut self,
+        postprocessor: Arc<dyn Fn(&mut Value) + Send + Sync>,
+    ) -> &mut ClientBuilder {
+        self.search_postprocessor = Some(SearchPostprocessor(postprocessor));
+        self
+    }
+
+    /// Send the [metrics](crate::metrics) of the client to `recorder`.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics_recorder(
+        &mut self,
+        recorder: impl crate::metrics::MetricsRecorder + 'static,
+    ) -> &mut ClientBuilder {
+        self.metrics = crate::metrics::Metrics(Some(Arc::new(recorder)));
+        self
+    }
+
+    /// Poll the tasks with `wait_policy` when waiting for them without an interval or a timeout.
+    ///
+    /// Default: [WaitPolicy::default], polling every 50ms for at most 5s
+    pub fn with_wait_policy(&mut self, wait_policy: WaitPolicy) -> &mut ClientBuilder {
+        self.wait_policy = wait_policy;
+        self
+    }
+
+    /// Measure and wait for the time with `clock` instead of the system clock.
+    ///
+    /// Used by the tests, with a [MockClock](crate::clock::MockClock), to make the waits instant and deterministic.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn with_clock(&mut self, clock: impl Clock + 'static) -> &mut ClientBuilder {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Create the [Client].
+    pub fn build(&self) -> Client {
+        let host = match &self.base_path {
+            Some(base_path) => format!("{}/{}", self.host, base_path),
+            None => self.host.clone(),
+        };
+        Client {
+            host,
+            api_key: self.api_key.clone(),
+            proxy: self.proxy.clone(),
+            max_response_bytes: self.max_response_bytes,
+            stream_hits: self.stream_hits,
+            search_limits: self.search_limits,
+            attributes_cache: AttributesCache::default(),
+            embedders_cache: EmbeddersCache::default(),
+            primary_keys_cache: PrimaryKeysCache::default(),
+            key_provider: self.key_provider.clone(),
+            search_postprocessor: self.search_postprocessor.clone(),
+            clock: self.clock.clone(),
+            wait_policy: self.wait_policy,
+            base_filters: Vec::new(),
+            in_flight: self.abort_on_drop.then(InFlightRequests::new),
+            #[cfg(feature = "metrics")]
+            metrics: self.metrics.clone(),
+        }
     }
 }
 
+/// Above this number of indexes, [Client::get_stats_for] always filters the global stats.
+const MAX_STATS_FAN_OUT: usize = 16;
+
+/// Whether fetching the stats of all the indexes at once is cheaper than a request per index,
+/// ie when the requests per index would take more than one round trip.
+fn prefers_global_stats(uids: usize, concurrency: usize) -> bool {
+    uids > concurrency.clamp(1, MAX_STATS_FAN_OUT)
+}
+
+/// Remove the trailing '/' of the host, the routes are appended to it with a leading '/'.
+fn normalize_host(mut host: String) -> String {
+    let len = host.trim_end_matches('/').len();
+    host.truncate(len);
+    host
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteTasksQuery<'a> {
+    #[serde(with = "time::serde::rfc3339")]
+    before_finished_at: OffsetDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statuses: Option<&'a [TaskStatus]>,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientStats {
@@ -843,11 +2119,34 @@ pub struct ClientStats {
 ///    status: "available".to_string(),
 /// };
 /// ```
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Health {
     pub status: String,
 }
 
+/// Availability of the Meilisearch server, built by [Client::readiness].
+#[derive(Debug, Clone)]
+pub struct Readiness {
+    /// The status of the [Health] of the server.
+    pub status: String,
+    pub version: Version,
+    /// The uids of the indexes being indexed, sorted.
+    /// `None` when the stats were not fetched, see [Client::readiness_with_stats].
+    pub indexing: Option<Vec<String>>,
+}
+
+impl Readiness {
+    /// Whether the server is available.
+    pub fn is_ready(&self) -> bool {
+        self.status == "available"
+    }
+
+    /// Whether an index is being indexed, `None` when the stats were not fetched.
+    pub fn is_indexing(&self) -> Option<bool> {
+        self.indexing.as_ref().map(|indexing| !indexing.is_empty())
+    }
+}
+
 /// Version of a Meilisearch server.
 ///
 /// Example:
@@ -860,7 +2159,7 @@ pub struct Health {
 ///    pkg_version: "0.1.1".to_string(),
 /// };
 /// ```
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Version {
     pub commit_sha: String,
@@ -868,11 +2167,83 @@ pub struct Version {
     pub pkg_version: String,
 }
 
+impl Version {
+    /// Parse the `pkg_version` into its `(major, minor, patch)` numbers.
+    ///
+    /// A pre-release or build suffix, ie `-rc.1`, is ignored and a missing patch number is read as `0`.
+    /// Returns `None` if the version can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let version = Version {
+    ///     commit_sha: "b46889b5f0f2f8b91438a08a358ba8f05fc09fc1".to_string(),
+    ///     commit_date: "2019-11-15T09:51:54.278247+00:00".to_string(),
+    ///     pkg_version: "1.6.0-rc.2".to_string(),
+    /// };
+    ///
+    /// assert_eq!(version.semver(), Some((1, 6, 0)));
+    /// ```
+    pub fn semver(&self) -> Option<(u32, u32, u32)> {
+        let version = self.pkg_version.trim();
+        let version = version.strip_prefix('v').unwrap_or(version);
+        let core = version.split(['-', '+']).next()?;
+
+        let mut numbers = core.split('.').map(|number| number.parse::<u32>());
+        let major = numbers.next()?.ok()?;
+        let minor = numbers.next()?.ok()?;
+        let patch = numbers.next().unwrap_or(Ok(0)).ok()?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        Some((major, minor, patch))
+    }
+
+    /// Check that the version is at least `major.minor.patch`.
+    ///
+    /// A pre-release comes before the version it precedes, like in semver, so `1.6.0-rc.1` is at least `1.5.2`
+    /// but not `1.6.0`. A build suffix, ie `+build.5`, is ignored. Returns `false` if the version can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let version = client.get_version().await.unwrap();
+    ///
+    /// if version.at_least(1, 3, 0) {
+    ///     // use the vector search
+    /// }
+    /// # });
+    /// ```
+    pub fn at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        // the pre-release starts at the first `-` before the build suffix
+        let is_pre_release = self
+            .pkg_version
+            .split('+')
+            .next()
+            .unwrap_or_default()
+            .contains('-');
+        match self.semver() {
+            Some(version) if is_pre_release => version > (major, minor, patch),
+            Some(version) => version >= (major, minor, patch),
+            None => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         client::*,
+        clock::MockClock,
         key::{Action, KeyBuilder},
+        tasks::*,
     };
     use meilisearch_test_macro::meilisearch_test;
     use mockito::mock;
@@ -885,37 +2256,38 @@ mod tests {
         let path = "/hello";
         let address = &format!("{}{}", mock_server_url, path);
         let user_agent = &*qualified_version();
+        let client = Client::new(mock_server_url, "");
 
         let assertions = vec![
             (
                 mock("GET", path)
                     .match_header("User-Agent", user_agent)
                     .create(),
-                request::<(), ()>(address, "", Method::Get(()), 200),
+                request::<(), ()>(address, &client, Method::Get(()), 200),
             ),
             (
                 mock("POST", path)
                     .match_header("User-Agent", user_agent)
                     .create(),
-                request::<(), ()>(address, "", Method::Post(()), 200),
+                request::<(), ()>(address, &client, Method::Post(()), 200),
             ),
             (
                 mock("DELETE", path)
                     .match_header("User-Agent", user_agent)
                     .create(),
-                request::<(), ()>(address, "", Method::Delete, 200),
+                request::<(), ()>(address, &client, Method::Delete, 200),
             ),
             (
                 mock("PUT", path)
                     .match_header("User-Agent", user_agent)
                     .create(),
-                request::<(), ()>(address, "", Method::Put(()), 200),
+                request::<(), ()>(address, &client, Method::Put(()), 200),
             ),
             (
                 mock("PATCH", path)
                     .match_header("User-Agent", user_agent)
                     .create(),
-                request::<(), ()>(address, "", Method::Patch(()), 200),
+                request::<(), ()>(address, &client, Method::Patch(()), 200),
             ),
         ];
 
@@ -927,6 +2299,1004 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_client_builder() {
+        let client = ClientBuilder::new("http://localhost:7700", "masterKey").build();
+        assert_eq!(client.proxy.url, None);
+        assert!(client.proxy.system);
+        assert!(client.proxy.no_proxy.is_empty());
+
+        let client = ClientBuilder::new("http://localhost:7700", "masterKey")
+            .with_proxy("http://proxy:3128")
+            .with_system_proxy(false)
+            .with_no_proxy(["localhost", "internal.example.com"])
+            .build();
+        assert_eq!(client.host, "http://localhost:7700");
+        assert_eq!(client.api_key, "masterKey");
+        assert_eq!(client.proxy.url.as_deref(), Some("http://proxy:3128"));
+        assert!(!client.proxy.system);
+        assert_eq!(
+            client.proxy.no_proxy,
+            vec!["localhost".to_string(), "internal.example.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_abort_on_drop() {
+        use std::net::TcpListener;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        // accepts the connections but never answers, like a server stuck on a large upload
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = ClientBuilder::new(
+            format!("http://{}", listener.local_addr().unwrap()),
+            "masterKey",
+        )
+        .with_abort_on_drop(true)
+        .build();
+        let index = client.index("movies");
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            futures::executor::block_on(async move {
+                // the request is polled first, so it's in flight when the client is dropped
+                let (result, ()) =
+                    futures::join!(index.get_stats(), async move { mem::drop(client) });
+                sender.send(result.map(|_| ())).unwrap();
+
+                // the requests sent afterward are aborted right away
+                sender.send(index.get_stats().await.map(|_| ())).unwrap();
+            })
+        });
+
+        for _ in 0..2 {
+            let result = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+            assert!(matches!(result, Err(Error::Aborted)));
+        }
+
+        // the requests of a client built without it aren't aborted
+        let client = ClientBuilder::new(
+            format!("{}/not_aborted", mockito::server_url()),
+            "masterKey",
+        )
+        .build();
+        let index = client.index("movies");
+        let _stats = mock("GET", "/not_aborted/indexes/movies/stats")
+            .with_status(200)
+            .with_body(
+                r#"{ "numberOfDocuments": 1, "isIndexing": false, "fieldDistribution": {} }"#,
+            )
+            .create();
+        mem::drop(client);
+        assert!(futures::executor::block_on(index.get_stats()).is_ok());
+    }
+
+    #[meilisearch_test]
+    async fn test_request_through_proxy() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        // A proxy answering a single request with the task of a document addition.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+        let proxy = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = r#"{"taskUid":1,"indexUid":"movies","status":"enqueued","type":"documentAdditionOrUpdate","enqueuedAt":"2022-02-03T13:02:38.369634Z"}"#;
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 202 Accepted\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+
+            (request_line, String::from_utf8(body).unwrap())
+        });
+
+        // this host doesn't exist, the request can only succeed through the proxy
+        let client = ClientBuilder::new("http://meilisearch.invalid", "masterKey")
+            .with_proxy(proxy_url)
+            .build();
+        let task = client
+            .index("movies")
+            .add_documents(&[json!({ "id": 1, "title": "Interstellar" })], None)
+            .await
+            .unwrap();
+
+        let (request_line, body) = proxy.join().unwrap();
+        assert_eq!(task.task_uid, 1);
+        assert!(
+            request_line.starts_with("POST http://meilisearch.invalid/indexes/movies/documents")
+        );
+        assert_eq!(body, r#"[{"id":1,"title":"Interstellar"}]"#);
+    }
+
+    #[meilisearch_test]
+    async fn test_create_index_with_invalid_uid() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let mock_res = mock("POST", "/indexes").expect(0).create();
+
+        let error = client.create_index("my movies", None).await.unwrap_err();
+
+        mock_res.assert();
+        assert!(matches!(error, Error::InvalidIndexUid(uid) if uid == "my movies"));
+    }
+
+    #[meilisearch_test]
+    async fn test_watch_tasks() {
+        use futures::StreamExt;
+
+        let task = |uid: u32, status: &str| {
+            json!({
+                "uid": uid,
+                "indexUid": "watched",
+                "status": status,
+                "type": "documentAdditionOrUpdate",
+                "details": { "receivedDocuments": 1, "indexedDocuments": 1 },
+                "duration": "PT0.01S",
+                "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+                "startedAt": "2022-02-03T15:17:02.812338Z",
+                "finishedAt": "2022-02-03T15:17:02.821295Z"
+            })
+        };
+        let poll = |tasks: Vec<Value>| {
+            mock("GET", "/tasks")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "indexUid".into(),
+                    "watched".into(),
+                ))
+                .with_status(200)
+                .with_body(
+                    json!({ "results": tasks, "limit": 20, "from": null, "next": null })
+                        .to_string(),
+                )
+                .expect(1)
+                .create()
+        };
+        let polls = [
+            poll(vec![task(1, "enqueued")]),
+            poll(vec![task(2, "enqueued"), task(1, "processing")]),
+            poll(vec![task(2, "enqueued"), task(1, "processing")]),
+            poll(vec![task(2, "succeeded"), task(1, "succeeded")]),
+        ];
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_clock(clock.clone())
+            .build();
+        let mut query = TasksQuery::new(&client);
+        query.with_index_uid(["watched"]);
+
+        let changes: Vec<(u32, &str)> = client
+            .watch_tasks(&query, Duration::from_secs(1))
+            .take(5)
+            .map(|task| match task.unwrap() {
+                Task::Enqueued { content } => (content.uid, "enqueued"),
+                Task::Processing { content } => (content.uid, "processing"),
+                Task::Succeeded { content } => (content.uid, "succeeded"),
+                Task::Failed { content } => (content.task.uid, "failed"),
+            })
+            .collect()
+            .await;
+
+        // the unchanged tasks of the third poll are not emitted again
+        assert_eq!(
+            changes,
+            [
+                (1, "enqueued"),
+                (1, "processing"),
+                (2, "enqueued"),
+                (1, "succeeded"),
+                (2, "succeeded"),
+            ]
+        );
+        for poll in polls {
+            poll.assert();
+        }
+        // one wait between each poll
+        assert_eq!(clock.now(), Duration::from_secs(3));
+    }
+
+    #[meilisearch_test]
+    async fn test_key_provider_refreshes_an_expired_key() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let client = ClientBuilder::new(mockito::server_url(), "")
+            .with_key_provider({
+                let fetches = Arc::clone(&fetches);
+                move || {
+                    let key = match fetches.fetch_add(1, Ordering::SeqCst) {
+                        0 => "expired",
+                        _ => "fresh",
+                    };
+                    Box::pin(async move { Ok(key.to_string()) })
+                }
+            })
+            .build();
+        let rejected = mock("GET", "/indexes/reauth")
+            .match_header("Authorization", "Bearer expired")
+            .with_status(403)
+            .with_body(r#"{ "message": "The provided API key is invalid.", "code": "invalid_api_key", "type": "auth", "link": "https://docs.meilisearch.com/errors#invalid_api_key" }"#)
+            .expect(3)
+            .create();
+        let accepted = mock("GET", "/indexes/reauth")
+            .match_header("Authorization", "Bearer fresh")
+            .with_status(200)
+            .with_body(r#"{ "uid": "reauth", "createdAt": "2022-02-03T15:17:02.801341Z", "updatedAt": "2022-02-03T15:17:02.801341Z", "primaryKey": null }"#)
+            .expect(4)
+            .create();
+
+        // the concurrent requests rejected with the expired key share a single refresh
+        let results = futures::future::join3(
+            client.get_index("reauth"),
+            client.get_index("reauth"),
+            client.get_index("reauth"),
+        )
+        .await;
+        assert!(results.0.is_ok() && results.1.is_ok() && results.2.is_ok());
+        // the refreshed key is cached
+        client.get_index("reauth").await.unwrap();
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+        rejected.assert();
+        accepted.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_service_unavailable() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+
+        let _mock = mock("GET", "/indexes/maintenance")
+            .with_status(503)
+            .with_body(r#"{ "message": "Meilisearch is taking a snapshot, retry later.", "code": "maintenance", "type": "system", "link": "" }"#)
+            .create();
+        let error = client.get_index("maintenance").await.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::ServiceUnavailable { message } if message == "Meilisearch is taking a snapshot, retry later."
+        ));
+        assert!(error.is_retriable());
+
+        // a proxy in front of Meilisearch doesn't answer with JSON
+        let _mock = mock("GET", "/indexes/maintenance-proxy")
+            .with_status(503)
+            .with_body("Service Temporarily Unavailable\n")
+            .create();
+        let error = client.get_index("maintenance-proxy").await.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::ServiceUnavailable { message } if message == "Service Temporarily Unavailable"
+        ));
+
+        // the other server errors are not temporary
+        let _mock = mock("GET", "/indexes/internal")
+            .with_status(500)
+            .with_body(r#"{ "message": "Something went wrong.", "code": "internal", "type": "internal", "link": "" }"#)
+            .create();
+        let error = client.get_index("internal").await.unwrap_err();
+        assert!(matches!(error, Error::Meilisearch(_)));
+        assert!(!error.is_retriable());
+    }
+
+    #[meilisearch_test]
+    async fn test_base_path() {
+        let base_path = "/search-engine";
+        for client in [
+            Client::new(
+                format!("{}{}/", mockito::server_url(), base_path),
+                "masterKey",
+            ),
+            ClientBuilder::new(format!("{}/", mockito::server_url()), "masterKey")
+                .with_base_path("search-engine/")
+                .build(),
+            ClientBuilder::new(mockito::server_url(), "masterKey")
+                .with_base_path("/search")
+                .with_base_path("/search-engine")
+                .build(),
+        ] {
+            assert_eq!(
+                client.host,
+                format!("{}{}", mockito::server_url(), base_path)
+            );
+            let index = client.index("base_path");
+
+            let settings = mock(
+                "GET",
+                "/search-engine/indexes/base_path/settings/ranking-rules",
+            )
+            .with_status(200)
+            .with_body(r#"["words", "typo"]"#)
+            .create();
+            let document = mock("GET", "/search-engine/indexes/base_path/documents/42")
+                .with_status(200)
+                .with_body(r#"{ "id": 42 }"#)
+                .create();
+            let tasks = mock("GET", "/search-engine/tasks")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("indexUid".into(), "base_path".into()),
+                    mockito::Matcher::UrlEncoded("limit".into(), "2".into()),
+                ]))
+                .with_status(200)
+                .with_body(r#"{ "results": [], "limit": 2, "from": null, "next": null }"#)
+                .create();
+
+            index.get_ranking_rules().await.unwrap();
+            index.get_document::<Value>("42").await.unwrap();
+            client
+                .get_tasks_with(
+                    TasksQuery::new(&client)
+                        .with_index_uid(["base_path"])
+                        .with_limit(2),
+                )
+                .await
+                .unwrap();
+
+            settings.assert();
+            document.assert();
+            tasks.assert();
+        }
+
+        // the base path is added once, even when it's given several times
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_base_path("/search-engine")
+            .with_base_path("search-engine")
+            .build();
+        assert_eq!(client.host.matches(base_path).count(), 1);
+        assert!(client.host.ends_with(base_path));
+    }
+
+    #[meilisearch_test]
+    async fn test_readiness() {
+        // the base path keeps the routes apart from the other tests
+        let client = Client::new(format!("{}/readiness", mockito::server_url()), "masterKey");
+
+        let health = mock("GET", "/readiness/health")
+            .with_status(200)
+            .with_body(r#"{ "status": "available" }"#)
+            .expect(2)
+            .create();
+        let version = mock("GET", "/readiness/version")
+            .with_status(200)
+            .with_body(r#"{ "commitSha": "b46889b", "commitDate": "2023-01-01T00:00:00Z", "pkgVersion": "1.6.0" }"#)
+            .expect(2)
+            .create();
+        let stats = mock("GET", "/readiness/stats")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "databaseSize": 4096,
+                    "lastUpdate": null,
+                    "indexes": {
+                        "movies": { "numberOfDocuments": 10, "isIndexing": true, "fieldDistribution": {} },
+                        "books": { "numberOfDocuments": 3, "isIndexing": false, "fieldDistribution": {} }
+                    }
+                }"#,
+            )
+            .expect(1)
+            .create();
+
+        let readiness = client.readiness().await.unwrap();
+        assert!(readiness.is_ready());
+        assert_eq!(readiness.version.pkg_version, "1.6.0");
+        assert_eq!(readiness.indexing, None);
+        assert_eq!(readiness.is_indexing(), None);
+
+        let readiness = client.readiness_with_stats().await.unwrap();
+        assert!(readiness.is_ready());
+        assert_eq!(readiness.version.commit_sha, "b46889b");
+        assert_eq!(readiness.indexing, Some(vec!["movies".to_string()]));
+        assert_eq!(readiness.is_indexing(), Some(true));
+
+        health.assert();
+        version.assert();
+        stats.assert();
+    }
+
+    #[test]
+    fn test_prefers_global_stats() {
+        // a single round of requests
+        assert!(!prefers_global_stats(3, 4));
+        assert!(!prefers_global_stats(4, 4));
+        assert!(!prefers_global_stats(1, 0));
+        // several rounds, or too many requests at once
+        assert!(prefers_global_stats(5, 4));
+        assert!(prefers_global_stats(2, 0));
+        assert!(prefers_global_stats(MAX_STATS_FAN_OUT + 1, 500));
+    }
+
+    #[meilisearch_test]
+    async fn test_get_stats_for_each_index() {
+        let client = Client::new(
+            format!("{}/stats_for_each", mockito::server_url()),
+            "masterKey",
+        );
+        let movies = mock("GET", "/stats_for_each/indexes/movies/stats")
+            .with_status(200)
+            .with_body(
+                r#"{ "numberOfDocuments": 10, "isIndexing": true, "fieldDistribution": {} }"#,
+            )
+            .expect(1)
+            .create();
+        let missing = mock("GET", "/stats_for_each/indexes/missing/stats")
+            .with_status(404)
+            .with_body(
+                r#"{
+                    "message": "Index `missing` not found.",
+                    "code": "index_not_found",
+                    "type": "invalid_request",
+                    "link": "https://docs.meilisearch.com/errors#index_not_found"
+                }"#,
+            )
+            .expect(1)
+            .create();
+        let global = mock("GET", "/stats_for_each/stats").expect(0).create();
+
+        let stats = client
+            .get_stats_for(&["movies", "missing"], 2)
+            .await
+            .unwrap();
+
+        movies.assert();
+        missing.assert();
+        global.assert();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats["movies"].as_ref().unwrap().number_of_documents, 10);
+        assert!(matches!(
+            &stats["missing"],
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            }))
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_get_stats_for_many_indexes() {
+        let client = Client::new(
+            format!("{}/stats_for_many", mockito::server_url()),
+            "masterKey",
+        );
+        let global = mock("GET", "/stats_for_many/stats")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "databaseSize": 4096,
+                    "lastUpdate": null,
+                    "indexes": {
+                        "movies": { "numberOfDocuments": 10, "isIndexing": true, "fieldDistribution": {} },
+                        "books": { "numberOfDocuments": 3, "isIndexing": false, "fieldDistribution": {} },
+                        "songs": { "numberOfDocuments": 7, "isIndexing": false, "fieldDistribution": {} }
+                    }
+                }"#,
+            )
+            .expect(1)
+            .create();
+        let each = mock(
+            "GET",
+            mockito::Matcher::Regex("^/stats_for_many/indexes/".to_string()),
+        )
+        .expect(0)
+        .create();
+
+        let stats = client
+            .get_stats_for(&["movies", "books", "missing", "movies"], 2)
+            .await
+            .unwrap();
+
+        global.assert();
+        each.assert();
+        // the indexes not asked for are filtered out
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats["movies"].as_ref().unwrap().number_of_documents, 10);
+        assert_eq!(stats["books"].as_ref().unwrap().number_of_documents, 3);
+        assert!(matches!(
+            &stats["missing"],
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexNotFound,
+                ..
+            }))
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_large_search_response() {
+        let hits: Vec<Value> = (0..2000)
+            .map(|id| json!({ "id": id, "text": "a very long text ".repeat(20) }))
+            .collect();
+        let body = json!({
+            "hits": hits,
+            "offset": 0,
+            "limit": 2000,
+            "estimatedTotalHits": 2000,
+            "processingTimeMs": 12,
+            "query": "text",
+        })
+        .to_string();
+        let _mock = mock("POST", "/indexes/large_responses/search")
+            .with_status(200)
+            .with_body(&body)
+            .create();
+        let search = |client: Client| async move {
+            client
+                .search::<Value>("large_responses", SearchParams::new().with_limit(2000))
+                .await
+        };
+
+        for streamed in [false, true] {
+            let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+                .with_streamed_hits(streamed)
+                .with_max_response_bytes(64 * 1024)
+                .build();
+            let error = search(client).await.unwrap_err();
+            assert!(matches!(error, Error::ResponseTooLarge { limit: 65536 }));
+
+            let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+                .with_streamed_hits(streamed)
+                .with_max_response_bytes(body.len())
+                .build();
+            let results = search(client).await.unwrap();
+            assert_eq!(results.hits.len(), 2000);
+            assert_eq!(results.hits[1999].result["id"], 1999);
+            assert_eq!(results.estimated_total_hits, 2000);
+            assert_eq!(results.query, "text");
+        }
+    }
+
+    #[meilisearch_test]
+    async fn test_search_postprocessor() {
+        #[derive(Deserialize)]
+        struct User {
+            name: String,
+            email: Option<String>,
+        }
+
+        let _mock = mock("POST", "/search_postprocessor/indexes/users/search")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "hits": [
+                        { "name": "alice", "email": "alice@example.com" },
+                        { "name": "bob", "email": "bob@example.com" }
+                    ],
+                    "offset": 0,
+                    "limit": 20,
+                    "estimatedTotalHits": 2,
+                    "processingTimeMs": 1,
+                    "query": ""
+                })
+                .to_string(),
+            )
+            .create();
+
+        for streamed in [false, true] {
+            let client = ClientBuilder::new(
+                format!("{}/search_postprocessor", mockito::server_url()),
+                "masterKey",
+            )
+            .with_streamed_hits(streamed)
+            .with_search_postprocessor(Arc::new(|response: &mut Value| {
+                for hit in response["hits"].as_array_mut().into_iter().flatten() {
+                    hit.as_object_mut().unwrap().remove("email");
+                }
+                response["query"] = json!("redacted");
+            }))
+            .build();
+
+            let results = client
+                .search::<User>("users", SearchParams::new())
+                .await
+                .unwrap();
+            assert_eq!(results.hits[0].result.name, "alice");
+            assert!(results.hits.iter().all(|hit| hit.result.email.is_none()));
+            assert_eq!(results.query, "redacted");
+
+            let results = client
+                .index("users")
+                .search()
+                .execute::<Value>()
+                .await
+                .unwrap();
+            assert_eq!(results.hits[1].result, json!({ "name": "bob" }));
+        }
+    }
+
+    #[meilisearch_test]
+    async fn test_search_limits() {
+        let response = r#"{ "hits": [], "offset": 0, "limit": 0, "estimatedTotalHits": 0, "processingTimeMs": 1, "query": "" }"#;
+        let mock_limit = |limit: usize| {
+            mock("POST", "/indexes/search_limits/search")
+                .match_body(mockito::Matcher::Json(json!({ "limit": limit })))
+                .with_status(200)
+                .with_body(response)
+                .expect(1)
+                .create()
+        };
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_default_search_limit(10)
+            .with_max_search_limit(50)
+            .build();
+
+        // the default applies when no limit is set
+        let default = mock_limit(10);
+        client
+            .search::<Value>("search_limits", SearchParams::new())
+            .await
+            .unwrap();
+        default.assert();
+
+        // a limit under the maximum is left untouched
+        let under = mock_limit(30);
+        client
+            .search::<Value>("search_limits", SearchParams::new().with_limit(30))
+            .await
+            .unwrap();
+        under.assert();
+
+        // a limit over the maximum is clamped
+        let clamped = mock_limit(50);
+        client
+            .search::<Value>("search_limits", SearchParams::new().with_limit(1000))
+            .await
+            .unwrap();
+        clamped.assert();
+
+        // the default limit of Meilisearch is clamped too
+        let small = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_max_search_limit(5)
+            .build();
+        let implicit = mock_limit(5);
+        small
+            .search::<Value>("search_limits", SearchParams::new())
+            .await
+            .unwrap();
+        implicit.assert();
+
+        let strict = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_max_search_limit(50)
+            .with_strict_max_search_limit(true)
+            .build();
+        let error = strict
+            .search::<Value>("search_limits", SearchParams::new().with_limit(1000))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::SearchLimitExceeded {
+                limit: 1000,
+                max: 50
+            }
+        ));
+    }
+
+    #[test]
+    fn test_wait_for_task_on_tokio_and_futures_executor() {
+        let task = |status: &str| {
+            format!(
+                r#"{{
+                    "uid": 4242,
+                    "indexUid": "movies",
+                    "status": "{}",
+                    "type": "indexCreation",
+                    "duration": "PT0.01S",
+                    "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+                    "startedAt": "2022-02-03T15:17:02.812338Z",
+                    "finishedAt": "2022-02-03T15:17:02.821295Z"
+                }}"#,
+                status
+            )
+        };
+        let wait = || async {
+            let client = Client::new(mockito::server_url(), "masterKey");
+            let processing = mock("GET", "/tasks/4242")
+                .with_status(200)
+                .with_body(task("processing"))
+                .expect(2)
+                .create();
+            let succeeded = mock("GET", "/tasks/4242")
+                .with_status(200)
+                .with_body(task("succeeded"))
+                .expect(1)
+                .create();
+
+            let enqueued_task: Task = serde_json::from_str(&task("enqueued")).unwrap();
+            let task = client
+                .wait_for_task(enqueued_task, Some(Duration::from_millis(5)), None)
+                .await
+                .unwrap();
+
+            processing.assert();
+            succeeded.assert();
+            assert!(task.is_success());
+        };
+
+        futures::executor::block_on(wait());
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(wait());
+    }
+
+    #[meilisearch_test]
+    async fn test_get_task_with_details() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "camelCase")]
+        struct AdditionDetails {
+            received_documents: usize,
+            indexed_documents: Option<usize>,
+            // added by a fork of Meilisearch
+            skipped_documents: usize,
+        }
+
+        let client = Client::new(
+            format!("{}/task_with_details", mockito::server_url()),
+            "masterKey",
+        );
+        let task = json!({
+            "uid": 12,
+            "batchUid": 3,
+            "indexUid": "movies",
+            "status": "succeeded",
+            "type": "documentAdditionOrUpdate",
+            "canceledBy": null,
+            "details": { "receivedDocuments": 3, "indexedDocuments": 2, "skippedDocuments": 1 },
+            "error": null,
+            "duration": "PT0.25S",
+            "enqueuedAt": "2023-01-01T00:00:00Z",
+            "startedAt": "2023-01-01T00:00:01Z",
+            "finishedAt": "2023-01-01T00:00:02Z"
+        });
+        let get = mock("GET", "/task_with_details/tasks/12")
+            .with_status(200)
+            .with_body(task.to_string())
+            .expect(2)
+            .create();
+
+        let enqueued: TaskInfo = serde_json::from_value(json!({
+            "taskUid": 12,
+            "indexUid": "movies",
+            "status": "enqueued",
+            "type": "documentAdditionOrUpdate",
+            "enqueuedAt": "2023-01-01T00:00:00Z"
+        }))
+        .unwrap();
+
+        let typed = client
+            .get_task_with_details::<AdditionDetails>(&enqueued)
+            .await
+            .unwrap();
+        assert_eq!(
+            typed.details,
+            Some(AdditionDetails {
+                received_documents: 3,
+                indexed_documents: Some(2),
+                skipped_documents: 1,
+            })
+        );
+        assert_eq!(typed.status, TaskStatus::Succeeded);
+        assert_eq!(typed.task_type, "documentAdditionOrUpdate");
+        assert_eq!(typed.duration, Some(Duration::from_millis(250)));
+        assert_eq!(typed.batch_uid, Some(3));
+        assert!(typed.error.is_none());
+
+        assert_eq!(client.get_task_raw(&typed).await.unwrap(), task);
+        get.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_task_with_status_change() {
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_clock(clock.clone())
+            .build();
+        let task = |status: &str| {
+            format!(
+                r#"{{
+                    "uid": 42,
+                    "indexUid": "movies",
+                    "status": "{}",
+                    "type": "indexCreation",
+                    "duration": "PT0.01S",
+                    "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+                    "startedAt": "2022-02-03T15:17:02.812338Z",
+                    "finishedAt": "2022-02-03T15:17:02.821295Z"
+                }}"#,
+                status
+            )
+        };
+
+        let enqueued = mock("GET", "/tasks/42")
+            .with_status(200)
+            .with_body(task("enqueued"))
+            .expect(2)
+            .create();
+        let processing = mock("GET", "/tasks/42")
+            .with_status(200)
+            .with_body(task("processing"))
+            .expect(3)
+            .create();
+        let succeeded = mock("GET", "/tasks/42")
+            .with_status(200)
+            .with_body(task("succeeded"))
+            .expect(1)
+            .create();
+
+        let enqueued_task: Task = serde_json::from_str(&task("enqueued")).unwrap();
+        let mut statuses = Vec::new();
+        let task = client
+            .wait_for_task_with_status_change(
+                enqueued_task,
+                Some(Duration::from_millis(100)),
+                None,
+                |status| statuses.push(status),
+            )
+            .await
+            .unwrap();
+
+        enqueued.assert();
+        processing.assert();
+        succeeded.assert();
+        assert!(task.is_success());
+        assert_eq!(
+            statuses,
+            vec![
+                TaskStatus::Enqueued,
+                TaskStatus::Processing,
+                TaskStatus::Succeeded
+            ]
+        );
+        assert_eq!(clock.now(), Duration::from_millis(500));
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_task_times_out() {
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_clock(clock.clone())
+            .build();
+        let task = json!({
+            "uid": 43,
+            "indexUid": "movies",
+            "status": "processing",
+            "type": "indexCreation",
+            "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+            "startedAt": "2022-02-03T15:17:02.812338Z"
+        });
+        let processing = mock("GET", "/tasks/43")
+            .with_status(200)
+            .with_body(task.to_string())
+            .expect(5)
+            .create();
+
+        // the default timeout of 5s is reached without waiting for real
+        let task: Task = serde_json::from_value(task).unwrap();
+        let error = client
+            .wait_for_task_with_status_change(task, Some(Duration::from_secs(1)), None, |_| {})
+            .await
+            .unwrap_err();
+
+        processing.assert();
+        assert!(matches!(error, Error::Timeout));
+        assert_eq!(clock.now(), Duration::from_secs(5));
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_vanished_task() {
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(
+            format!("{}/wait_for_vanished_task", mockito::server_url()),
+            "masterKey",
+        )
+        .with_clock(clock.clone())
+        .build();
+        let task = json!({
+            "uid": 44,
+            "indexUid": "movies",
+            "status": "processing",
+            "type": "settingsUpdate",
+            "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+            "startedAt": "2022-02-03T15:17:02.812338Z"
+        });
+        let processing = mock("GET", "/wait_for_vanished_task/tasks/44")
+            .with_status(200)
+            .with_body(task.to_string())
+            .expect(1)
+            .create();
+        let not_found = mock("GET", "/wait_for_vanished_task/tasks/44")
+            .with_status(404)
+            .with_body(r#"{ "message": "Task `44` not found.", "code": "task_not_found", "type": "invalid_request", "link": "https://docs.meilisearch.com/errors#task_not_found" }"#)
+            .expect(1)
+            .create();
+
+        // the index of the task is deleted while it is processing, so the task is deleted too
+        let task: Task = serde_json::from_value(task).unwrap();
+        let error = client
+            .wait_for_task(task, Some(Duration::from_secs(1)), None)
+            .await
+            .unwrap_err();
+
+        processing.assert();
+        not_found.assert();
+        assert!(matches!(error, Error::TaskVanished { uid: 44 }));
+        assert_eq!(clock.now(), Duration::from_secs(1));
+    }
+
+    #[meilisearch_test]
+    async fn test_wait_for_canceled_task() {
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(
+            format!("{}/wait_for_canceled_task", mockito::server_url()),
+            "masterKey",
+        )
+        .with_clock(clock.clone())
+        .build();
+        let task = |status: &str| {
+            json!({
+                "uid": 45,
+                "indexUid": "movies",
+                "status": status,
+                "type": "settingsUpdate",
+                "canceledBy": if status == "canceled" { json!(46) } else { Value::Null },
+                "enqueuedAt": "2022-02-03T15:17:02.801341Z"
+            })
+        };
+        let enqueued = mock("GET", "/wait_for_canceled_task/tasks/45")
+            .with_status(200)
+            .with_body(task("enqueued").to_string())
+            .expect(1)
+            .create();
+        let canceled = mock("GET", "/wait_for_canceled_task/tasks/45")
+            .with_status(200)
+            .with_body(task("canceled").to_string())
+            .expect(1)
+            .create();
+
+        let mut statuses = Vec::new();
+        let enqueued_task: Task = serde_json::from_value(task("enqueued")).unwrap();
+        let error = client
+            .wait_for_task_with_status_change(
+                enqueued_task,
+                Some(Duration::from_secs(1)),
+                None,
+                |status| statuses.push(status),
+            )
+            .await
+            .unwrap_err();
+
+        enqueued.assert();
+        canceled.assert();
+        assert!(matches!(
+            error,
+            Error::TaskCanceled {
+                uid: 45,
+                by: Some(46)
+            }
+        ));
+        assert_eq!(statuses, [TaskStatus::Enqueued, TaskStatus::Canceled]);
+        assert_eq!(clock.now(), Duration::from_secs(1));
+    }
+
     #[meilisearch_test]
     async fn test_get_tasks(client: Client) {
         let tasks = client.get_tasks().await.unwrap();
@@ -941,6 +3311,82 @@ mod tests {
         assert!(tasks.results.len() >= 2);
     }
 
+    #[meilisearch_test]
+    async fn test_purge_tasks_before_query() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let path = "/tasks?beforeFinishedAt=2022-01-01T00%3A00%3A00Z&statuses=succeeded,failed";
+
+        let mock_res = mock("DELETE", path).with_status(200).create();
+
+        let date = OffsetDateTime::from_unix_timestamp(1640995200).unwrap();
+        let _ = client
+            .purge_tasks_before(date, &[TaskStatus::Succeeded, TaskStatus::Failed])
+            .await;
+
+        mock_res.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_purge_tasks_before(client: Client, index: Index) -> Result<(), Error> {
+        let task = index
+            .delete_all_documents()
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let deletion = client
+            .purge_tasks_before(OffsetDateTime::now_utc(), &[TaskStatus::Succeeded])
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        match deletion {
+            Task::Succeeded {
+                content:
+                    SucceededTask {
+                        update_type:
+                            TaskType::TaskDeletion {
+                                details: Some(details),
+                            },
+                        ..
+                    },
+            } => assert!(details.matched_tasks.unwrap() >= 1),
+            task => panic!("The task deletion should have succeeded {:?}", task),
+        }
+        assert!(client.get_task(&task).await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_semver() {
+        let version = |pkg_version: &str| Version {
+            commit_sha: String::new(),
+            commit_date: String::new(),
+            pkg_version: pkg_version.to_string(),
+        };
+
+        assert_eq!(version("1.6.0").semver(), Some((1, 6, 0)));
+        assert_eq!(version("0.30.5").semver(), Some((0, 30, 5)));
+        assert_eq!(version("v1.2.3").semver(), Some((1, 2, 3)));
+        assert_eq!(version("1.7.0-rc.1").semver(), Some((1, 7, 0)));
+        assert_eq!(version("1.7.0-rc.1+build.5").semver(), Some((1, 7, 0)));
+        assert_eq!(version("1.4").semver(), Some((1, 4, 0)));
+        assert_eq!(version("1").semver(), None);
+        assert_eq!(version("1.2.3.4").semver(), None);
+        assert_eq!(version("one.two").semver(), None);
+        assert_eq!(version("").semver(), None);
+
+        assert!(version("1.6.0").at_least(1, 6, 0));
+        assert!(version("1.6.0").at_least(1, 5, 9));
+        assert!(version("1.10.0").at_least(1, 9, 0));
+        assert!(!version("1.6.0").at_least(1, 6, 1));
+        assert!(!version("0.30.5").at_least(1, 0, 0));
+        assert!(!version("1.7.0-rc.1").at_least(1, 7, 0));
+        assert!(version("1.7.0-rc.1").at_least(1, 6, 3));
+        assert!(version("1.7.0+build.5").at_least(1, 7, 0));
+        assert!(!version("unknown").at_least(0, 0, 0));
+    }
+
     #[meilisearch_test]
     async fn test_get_keys(client: Client) {
         let keys = client.get_keys().await.unwrap();
@@ -1151,41 +3597,62 @@ mod tests {
     }
 
     #[meilisearch_test]
-    async fn test_list_all_indexes(client: Client) {
-        let all_indexes = client.list_all_indexes().await.unwrap();
+    async fn test_get_indexes(client: Client) {
+        let all_indexes = client.get_indexes().await.unwrap();
 
         assert_eq!(all_indexes.limit, 20);
         assert_eq!(all_indexes.offset, 0);
     }
 
     #[meilisearch_test]
-    async fn test_list_all_indexes_with_params(client: Client) {
+    async fn test_get_indexes_with_params(client: Client) {
         let mut query = IndexesQuery::new(&client);
         query.with_limit(1);
-        let all_indexes = client.list_all_indexes_with(&query).await.unwrap();
+        let all_indexes = client.get_indexes_with(&query).await.unwrap();
 
         assert_eq!(all_indexes.limit, 1);
         assert_eq!(all_indexes.offset, 0);
     }
 
     #[meilisearch_test]
-    async fn test_list_all_indexes_raw(client: Client) {
-        let all_indexes_raw = client.list_all_indexes_raw().await.unwrap();
+    async fn test_get_indexes_raw(client: Client) {
+        let all_indexes_raw = client.get_indexes_raw().await.unwrap();
 
         assert_eq!(all_indexes_raw["limit"], json!(20));
         assert_eq!(all_indexes_raw["offset"], json!(0));
     }
 
     #[meilisearch_test]
-    async fn test_list_all_indexes_raw_with_params(client: Client) {
+    async fn test_get_indexes_raw_with_params(client: Client) {
         let mut query = IndexesQuery::new(&client);
         query.with_limit(1);
-        let all_indexes_raw = client.list_all_indexes_raw_with(&query).await.unwrap();
+        let all_indexes_raw = client.get_indexes_raw_with(&query).await.unwrap();
 
         assert_eq!(all_indexes_raw["limit"], json!(1));
         assert_eq!(all_indexes_raw["offset"], json!(0));
     }
 
+    #[meilisearch_test]
+    async fn test_deprecated_list_all_indexes() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let body = r#"{ "results": [], "offset": 0, "limit": 20, "total": 0 }"#;
+
+        let mock_res = mock("GET", "/indexes")
+            .with_status(200)
+            .with_body(body)
+            .expect(2)
+            .create();
+
+        #[allow(deprecated)]
+        let indexes = client.list_all_indexes().await.unwrap();
+        #[allow(deprecated)]
+        let raw = client.list_all_indexes_raw().await.unwrap();
+
+        mock_res.assert();
+        assert_eq!(indexes.limit, 20);
+        assert_eq!(raw["total"], json!(0));
+    }
+
     #[meilisearch_test]
     async fn test_get_primary_key_is_none(mut index: Index) {
         let primary_key = index.get_primary_key().await;
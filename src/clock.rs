@@ -0,0 +1,127 @@
+use crate::utils::async_sleep;
+use async_trait::async_trait;
+use std::{fmt::Debug, time::Duration};
+
+/// The source of time of a [Client](crate::client::Client), used by every method waiting or polling Meilisearch.
+///
+/// The default one reads the system clock and sleeps on the timer of the async runtime.
+/// The tests replace it with a [MockClock] so the waits are instant and their outcome doesn't depend on the machine.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Clock: Debug + Send + Sync {
+    /// The time elapsed since an arbitrary origin, fixed for the lifetime of the clock.
+    fn now(&self) -> Duration;
+
+    /// Wait for `interval`.
+    async fn sleep(&self, interval: Duration);
+}
+
+/// The system clock, sleeping with the timer selected by the cargo features.
+#[derive(Debug)]
+pub(crate) struct SystemClock {
+    #[cfg(not(target_arch = "wasm32"))]
+    origin: std::time::Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock {
+            #[cfg(not(target_arch = "wasm32"))]
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Clock for SystemClock {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn now(&self) -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.)
+    }
+
+    async fn sleep(&self, interval: Duration) {
+        async_sleep(interval).await;
+    }
+}
+
+/// A virtual clock that only moves forward when it sleeps or is [advanced](MockClock::advance).
+///
+/// The clones share the same time, keep one to check how long the client waited.
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, clock::*};
+/// # use std::time::Duration;
+/// let clock = MockClock::new();
+/// let client = ClientBuilder::new("http://localhost:7700", "masterKey")
+///     .with_clock(clock.clone())
+///     .build();
+///
+/// clock.advance(Duration::from_secs(2));
+/// assert_eq!(clock.now(), Duration::from_secs(2));
+/// ```
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: std::sync::Arc<std::sync::Mutex<Duration>>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl MockClock {
+    /// Create a clock starting at zero.
+    pub fn new() -> MockClock {
+        MockClock::default()
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+
+    async fn sleep(&self, interval: Duration) {
+        self.advance(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock() {
+        let clock = MockClock::new();
+        let shared = clock.clone();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(30));
+        futures::executor::block_on(shared.sleep(Duration::from_millis(20)));
+
+        assert_eq!(clock.now(), Duration::from_millis(50));
+        assert_eq!(shared.now(), Duration::from_millis(50));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_system_clock() {
+        let clock = SystemClock::default();
+        let before = clock.now();
+
+        futures::executor::block_on(clock.sleep(Duration::from_millis(10)));
+
+        assert!(clock.now() - before >= Duration::from_millis(10));
+    }
+}
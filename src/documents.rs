@@ -1,5 +1,83 @@
-use crate::{errors::Error, indexes::Index};
+use crate::{errors::Error, indexes::Index, tasks::Task};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+/// The identifier of a document, which Meilisearch accepts either as an integer or as a string.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::documents::DocumentId;
+/// assert_eq!(DocumentId::from(42).to_string(), "42");
+/// assert_eq!(DocumentId::from("movie-42").to_string(), "movie-42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DocumentId {
+    Number(u64),
+    String(String),
+}
+
+impl Display for DocumentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentId::Number(id) => write!(f, "{}", id),
+            DocumentId::String(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl From<u64> for DocumentId {
+    fn from(id: u64) -> DocumentId {
+        DocumentId::Number(id)
+    }
+}
+
+impl From<u32> for DocumentId {
+    fn from(id: u32) -> DocumentId {
+        DocumentId::Number(id.into())
+    }
+}
+
+impl From<usize> for DocumentId {
+    fn from(id: usize) -> DocumentId {
+        DocumentId::Number(id as u64)
+    }
+}
+
+impl From<i32> for DocumentId {
+    fn from(id: i32) -> DocumentId {
+        match u64::try_from(id) {
+            Ok(id) => DocumentId::Number(id),
+            Err(_) => DocumentId::String(id.to_string()),
+        }
+    }
+}
+
+impl From<&str> for DocumentId {
+    fn from(id: &str) -> DocumentId {
+        DocumentId::String(id.to_string())
+    }
+}
+
+impl From<String> for DocumentId {
+    fn from(id: String) -> DocumentId {
+        DocumentId::String(id)
+    }
+}
+
+/// The result of [Index::delete_document_and_wait].
+#[derive(Debug, Clone)]
+pub struct DeletionOutcome {
+    /// Whether the document existed before being deleted.
+    pub existed: bool,
+    /// The processed deletion task.
+    pub task: Task,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct DocumentsResults<T> {
@@ -236,12 +314,119 @@ impl<'a> DocumentsQuery<'a> {
     }
 }
 
+/// Check that the fields reserved by Meilisearch have the shape it expects in each of the `documents`,
+/// before sending them with [Index::add_documents_validated].
+///
+/// - `_geo` is `null` or an object with a `lat` and a `lng`, each a number or a string holding one.
+/// - `_vectors` is `null` or an object mapping each embedder to `null`, an array of numbers, an array of arrays of numbers,
+///   or an object with a boolean `regenerate` and these `embeddings`.
+///
+/// The other fields, including the ones flattened into a document, are not checked. Otherwise Meilisearch would only
+/// reject the documents when processing the task, so [Error::InvalidReservedField] names the first document and field
+/// at fault instead.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{documents::validate_reserved_fields, errors::Error};
+/// # use serde_json::json;
+/// let documents = [
+///     json!({ "id": 1, "_geo": { "lat": 45.47, "lng": 9.18 } }),
+///     json!({ "id": 2, "_vectors": { "default": [0.1, "0.2"] } }),
+/// ];
+///
+/// let error = validate_reserved_fields(&documents).unwrap_err();
+/// assert!(matches!(error, Error::InvalidReservedField { document: 1, field, .. } if field == "_vectors.default"));
+/// ```
+pub fn validate_reserved_fields<T: Serialize>(documents: &[T]) -> Result<(), Error> {
+    for (i, document) in documents.iter().enumerate() {
+        let document = serde_json::to_value(document)
+            .map_err(|error| Error::parse_error::<T>(String::new(), error))?;
+        let invalid = |field: String, reason: &'static str| Error::InvalidReservedField {
+            document: i,
+            field,
+            reason,
+        };
+
+        if let Some(geo) = document.get("_geo") {
+            check_geo(geo).map_err(|reason| invalid("_geo".to_string(), reason))?;
+        }
+        match document.get("_vectors") {
+            None | Some(Value::Null) => {}
+            Some(Value::Object(vectors)) => {
+                for (embedder, value) in vectors {
+                    check_embedder_vectors(value)
+                        .map_err(|reason| invalid(format!("_vectors.{}", embedder), reason))?;
+                }
+            }
+            Some(_) => {
+                return Err(invalid(
+                    "_vectors".to_string(),
+                    "expected an object mapping the embedders to their vectors",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_geo(geo: &Value) -> Result<(), &'static str> {
+    let is_coordinate = |value: Option<&Value>| match value {
+        Some(Value::Number(_)) => true,
+        Some(Value::String(number)) => number.trim().parse::<f64>().is_ok(),
+        _ => false,
+    };
+    match geo {
+        Value::Null => Ok(()),
+        Value::Object(geo)
+            if geo.len() == 2 && is_coordinate(geo.get("lat")) && is_coordinate(geo.get("lng")) =>
+        {
+            Ok(())
+        }
+        _ => Err("expected an object with a numeric `lat` and `lng`"),
+    }
+}
+
+fn check_embedder_vectors(value: &Value) -> Result<(), &'static str> {
+    fn is_embeddings(value: &Value) -> bool {
+        let is_vector = |values: &[Value]| values.iter().all(Value::is_number);
+        match value {
+            Value::Null => true,
+            Value::Array(values) => {
+                is_vector(values)
+                    || values.iter().all(|value| match value {
+                        Value::Array(vector) => !vector.is_empty() && is_vector(vector),
+                        _ => false,
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    let valid = match value {
+        Value::Object(explicit) => {
+            matches!(explicit.get("regenerate"), Some(Value::Bool(_)))
+                && explicit.get("embeddings").into_iter().all(is_embeddings)
+                && explicit
+                    .keys()
+                    .all(|key| key == "regenerate" || key == "embeddings")
+        }
+        value => is_embeddings(value),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err("expected an array of numbers, an array of arrays of numbers, or an object with `regenerate` and `embeddings`")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{client::*, indexes::*};
     use meilisearch_test_macro::meilisearch_test;
     use serde::{Deserialize, Serialize};
+    use serde_json::{json, Map};
 
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     struct MyObject {
@@ -249,6 +434,87 @@ mod tests {
         kind: String,
     }
 
+    #[derive(Debug, Serialize)]
+    struct Restaurant {
+        id: usize,
+        name: String,
+        #[serde(flatten)]
+        extra: Map<String, Value>,
+    }
+
+    fn restaurant(id: usize, extra: Value) -> Restaurant {
+        Restaurant {
+            id,
+            name: format!("restaurant {}", id),
+            extra: serde_json::from_value(extra).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_validate_reserved_fields() {
+        let valid = [
+            restaurant(0, json!({ "cuisine": "italian" })),
+            restaurant(
+                1,
+                json!({ "_geo": { "lat": 45.47, "lng": "9.18" }, "_private": [1] }),
+            ),
+            restaurant(2, json!({ "_geo": null, "_vectors": null })),
+            restaurant(
+                3,
+                json!({ "_vectors": {
+                    "default": [0.1, 0.2],
+                    "multiple": [[0.1, 0.2], [0.3, 0.4]],
+                    "none": null,
+                    "explicit": { "embeddings": [0.1, 0.2], "regenerate": false },
+                    "generated": { "regenerate": true }
+                } }),
+            ),
+        ];
+        validate_reserved_fields(&valid).unwrap();
+
+        let invalid = |extra: Value| match validate_reserved_fields(&[
+            restaurant(0, json!({})),
+            restaurant(1, extra),
+        ]) {
+            Err(Error::InvalidReservedField {
+                document, field, ..
+            }) => (document, field),
+            other => panic!("expected an invalid reserved field, got {:?}", other),
+        };
+        assert_eq!(
+            invalid(json!({ "_geo": { "lat": 45.47, "long": 9.18 } })),
+            (1, "_geo".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_geo": { "lat": 45.47, "lng": "east" } })),
+            (1, "_geo".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_geo": { "lat": 45.47, "lng": 9.18, "alt": 120 } })),
+            (1, "_geo".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_geo": [45.47, 9.18] })),
+            (1, "_geo".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_vectors": [0.1, 0.2] })),
+            (1, "_vectors".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_vectors": { "default": [0.1, "garbage"] } })),
+            (1, "_vectors.default".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_vectors": { "default": [[0.1], []] } })),
+            (1, "_vectors.default".to_string())
+        );
+        assert_eq!(
+            invalid(json!({ "_vectors": { "default": { "embeddings": [0.1] } } })),
+            (1, "_vectors.default".to_string())
+        );
+    }
+
     async fn setup_test_index(client: &Client, index: &Index) -> Result<(), Error> {
         let t0 = index
             .add_documents(
@@ -297,6 +563,21 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn test_document_id() {
+        assert_eq!(DocumentId::from(3_u32), DocumentId::Number(3));
+        assert_eq!(DocumentId::from(3), DocumentId::Number(3));
+        assert_eq!(DocumentId::from(-3), DocumentId::String("-3".to_string()));
+        assert_eq!(
+            DocumentId::from("abc"),
+            DocumentId::String("abc".to_string())
+        );
+        assert_eq!(
+            serde_json::to_string(&[DocumentId::from(1_u64), DocumentId::from("a")]).unwrap(),
+            r#"[1,"a"]"#
+        );
+    }
+
     #[meilisearch_test]
     async fn test_get_documents_with_only_one_param(
         client: Client,
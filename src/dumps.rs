@@ -70,13 +70,7 @@ impl Client {
     /// # });
     /// ```
     pub async fn create_dump(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!("{}/dumps", self.host),
-            &self.api_key,
-            Method::Post(()),
-            202,
-        )
-        .await
+        request::<(), TaskInfo>(&format!("{}/dumps", self.host), self, Method::Post(()), 202).await
     }
 }
 
@@ -0,0 +1,356 @@
+use std::{fmt, time::Duration};
+
+/// The reason why a string isn't a valid [task duration](parse_iso8601_duration).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string doesn't start with the `P` designator.
+    MissingDesignator,
+    /// The duration has a date component other than days, ie `1Y` in `P1Y2D`.
+    ///
+    /// A year, a month or a week isn't used by Meilisearch, and a year or a month doesn't have a fixed length,
+    /// so they can't be converted to a [Duration].
+    DateComponent(String),
+    /// The duration doesn't have any component, ie `PT`.
+    Empty,
+    /// A component isn't a number followed by `H`, `M` or `S`, or is out of order, ie `2H` in `PT1M2H`.
+    InvalidComponent(String),
+    /// The duration doesn't fit in a [Duration].
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingDesignator => {
+                write!(fmt, "An ISO 8601 duration must start with `P`.")
+            }
+            ParseError::DateComponent(component) => write!(
+                fmt,
+                "The date component `{}` isn't supported, only days, hours, minutes and seconds are.",
+                component
+            ),
+            ParseError::Empty => write!(fmt, "The duration doesn't have any component."),
+            ParseError::InvalidComponent(component) => write!(
+                fmt,
+                "`{}` isn't a valid component, expected an integer followed by `H` or `M`, or a decimal number followed by `S`, in this order.",
+                component
+            ),
+            ParseError::Overflow => write!(fmt, "The duration is too long."),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse an ISO 8601 duration, as returned by Meilisearch in the `duration` of the [tasks](crate::tasks::Task) and [batches](crate::batches::Batch).
+///
+/// Only the days, which Meilisearch writes for the durations of 24 hours or more, and the time components are supported:
+/// the days, the hours and the minutes as integers and the seconds as a decimal number, with up to nine digits after the `.` or `,` being kept.
+/// A day is read as 24 hours.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::duration::*;
+/// # use std::time::Duration;
+/// assert_eq!(parse_iso8601_duration("PT2.34S"), Ok(Duration::from_millis(2340)));
+/// assert_eq!(parse_iso8601_duration("PT1M3.5S"), Ok(Duration::from_millis(63500)));
+/// assert_eq!(parse_iso8601_duration("PT1H"), Ok(Duration::from_secs(3600)));
+/// assert_eq!(parse_iso8601_duration("P1DT2H"), Ok(Duration::from_secs(26 * 3600)));
+///
+/// assert_eq!(
+///     parse_iso8601_duration("P1Y2D"),
+///     Err(ParseError::DateComponent("1Y2D".to_string()))
+/// );
+/// ```
+pub fn parse_iso8601_duration(duration: &str) -> Result<Duration, ParseError> {
+    let rest = duration
+        .strip_prefix('P')
+        .ok_or(ParseError::MissingDesignator)?;
+    let (date, time) = match rest.find('T') {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+    };
+
+    let mut total = Duration::ZERO;
+    if !date.is_empty() {
+        let days = date
+            .strip_suffix('D')
+            .filter(|days| !days.is_empty() && days.bytes().all(|b| b.is_ascii_digit()))
+            .ok_or_else(|| ParseError::DateComponent(date.to_string()))?;
+        let secs = days
+            .parse::<u64>()
+            .ok()
+            .and_then(|days| days.checked_mul(86_400))
+            .ok_or(ParseError::Overflow)?;
+        total = Duration::from_secs(secs);
+    }
+    let time = match time {
+        Some("") => return Err(ParseError::Empty),
+        None if date.is_empty() => return Err(ParseError::Empty),
+        Some(time) => time,
+        None => return Ok(total),
+    };
+
+    // each unit can only appear once, in this order
+    let mut units = [('H', 3600), ('M', 60), ('S', 1)].iter();
+    let mut rest = time;
+    while !rest.is_empty() {
+        let invalid = || ParseError::InvalidComponent(rest.to_string());
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .ok_or_else(invalid)?;
+        let (number, tail) = rest.split_at(end);
+        let unit = tail.chars().next().ok_or_else(invalid)?;
+        let component = &rest[..end + unit.len_utf8()];
+        let invalid = || ParseError::InvalidComponent(component.to_string());
+
+        let factor = units
+            .find(|(expected, _)| *expected == unit)
+            .map(|(_, factor)| *factor)
+            .ok_or_else(invalid)?;
+        let (whole, fraction) = match number.split_once(['.', ',']) {
+            Some((_, fraction)) if unit != 'S' || fraction.is_empty() => return Err(invalid()),
+            Some((whole, fraction)) => (whole, fraction),
+            None => (number, ""),
+        };
+        if whole.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let secs = whole
+            .parse::<u64>()
+            .ok()
+            .and_then(|whole| whole.checked_mul(factor))
+            .ok_or(ParseError::Overflow)?;
+        let nanos = fraction
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(9)
+            .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'));
+        total = total
+            .checked_add(Duration::new(secs, nanos))
+            .ok_or(ParseError::Overflow)?;
+
+        rest = &tail[unit.len_utf8()..];
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A xorshift generator, to get the same random cases on every run.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, max: u64) -> u64 {
+            self.next() % max
+        }
+    }
+
+    /// The reference formatter, writing the components in every way Meilisearch could.
+    ///
+    /// Returns the formatted duration, and the duration it represents as the nanoseconds may be dropped.
+    fn format(duration: Duration, rng: &mut Rng) -> (String, Duration) {
+        let secs = duration.as_secs();
+        let (mut hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        let mut formatted = String::from("P");
+        // the durations of a day or more can be written with days
+        if hours >= 24 && rng.below(2) == 0 {
+            formatted += &format!("{}D", hours / 24);
+            hours %= 24;
+        }
+        formatted += "T";
+        let time_start = formatted.len();
+        if hours > 0 || rng.below(4) == 0 {
+            formatted += &format!("{}H", hours);
+        }
+        if minutes > 0 || rng.below(4) == 0 {
+            formatted += &format!("{}M", minutes);
+        }
+        let nanos = format!("{:09}", duration.subsec_nanos());
+        match rng.below(3) {
+            0 if duration.subsec_nanos() > 0 => {
+                formatted += &format!("{}.{}S", seconds, nanos.trim_end_matches('0'));
+                (formatted, duration)
+            }
+            1 => {
+                formatted += &format!("{}.{}S", seconds, nanos);
+                (formatted, duration)
+            }
+            _ => {
+                if seconds > 0 || formatted.len() == time_start {
+                    formatted += &format!("{}S", seconds);
+                }
+                (formatted, Duration::from_secs(secs))
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_durations() {
+        assert_eq!(
+            parse_iso8601_duration("PT2.34S"),
+            Ok(Duration::from_millis(2340))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT10.848957S"),
+            Ok(Duration::new(10, 848_957_000))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1M3.5S"),
+            Ok(Duration::from_millis(63_500))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT2H0M1,25S"),
+            Ok(Duration::from_millis(7_201_250))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT0.0000000019S"),
+            Ok(Duration::from_nanos(1))
+        );
+        assert_eq!(parse_iso8601_duration("PT0S"), Ok(Duration::ZERO));
+        assert_eq!(
+            parse_iso8601_duration("P2D"),
+            Ok(Duration::from_secs(2 * 86_400))
+        );
+    }
+
+    #[test]
+    fn test_parse_durations_with_days() {
+        let duration = Duration::from_millis(((24 + 2) * 3600 + 3) * 1000 + 500);
+        assert_eq!(parse_iso8601_duration("P1DT2H3.5S"), Ok(duration));
+        assert_eq!(parse_iso8601_duration("PT26H0M3.5S"), Ok(duration));
+
+        // the reference formatter writes it back with or without the days
+        let mut rng = Rng(0xda75);
+        for _ in 0..16 {
+            let (formatted, expected) = format(duration, &mut rng);
+            assert_eq!(
+                parse_iso8601_duration(&formatted),
+                Ok(expected),
+                "{}",
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_durations() {
+        assert_eq!(
+            parse_iso8601_duration("T1S"),
+            Err(ParseError::MissingDesignator)
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1Y2M"),
+            Err(ParseError::DateComponent("1Y2M".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P2W"),
+            Err(ParseError::DateComponent("2W".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1M3DT1H"),
+            Err(ParseError::DateComponent("1M3D".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1.5D"),
+            Err(ParseError::DateComponent("1.5D".to_string()))
+        );
+        assert_eq!(parse_iso8601_duration("P1DT"), Err(ParseError::Empty));
+        assert_eq!(
+            parse_iso8601_duration(&format!("P{}D", u64::MAX / 86_400 + 1)),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(parse_iso8601_duration("P"), Err(ParseError::Empty));
+        assert_eq!(parse_iso8601_duration("PT"), Err(ParseError::Empty));
+        assert_eq!(
+            parse_iso8601_duration("PT1M2H"),
+            Err(ParseError::InvalidComponent("2H".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1S1S"),
+            Err(ParseError::InvalidComponent("1S".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1.5M"),
+            Err(ParseError::InvalidComponent("1.5M".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT.5S"),
+            Err(ParseError::InvalidComponent(".5S".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1.S"),
+            Err(ParseError::InvalidComponent("1.S".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1.2.3S"),
+            Err(ParseError::InvalidComponent("1.2.3S".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT12"),
+            Err(ParseError::InvalidComponent("12".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT-1S"),
+            Err(ParseError::InvalidComponent("-".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT99999999999999999999S"),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse_iso8601_duration(&format!("PT{}H", u64::MAX / 3600 + 1)),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_formatted_durations() {
+        let mut rng = Rng(0x5eed);
+        for _ in 0..10_000 {
+            let duration = Duration::new(rng.below(1 << 40), rng.below(1_000_000_000) as u32);
+            let (formatted, expected) = format(duration, &mut rng);
+
+            assert_eq!(
+                parse_iso8601_duration(&formatted),
+                Ok(expected),
+                "{}",
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_random_strings() {
+        const ALPHABET: &[char] = &[
+            'P', 'T', 'H', 'M', 'S', 'D', 'Y', 'W', '0', '1', '9', '.', ',', '-', ' ', 'é', '秒',
+        ];
+        let mut rng = Rng(0xf022);
+        for _ in 0..100_000 {
+            let len = rng.below(16);
+            let mut candidate: String = (0..len)
+                .map(|_| ALPHABET[rng.below(ALPHABET.len() as u64) as usize])
+                .collect();
+            if rng.below(2) == 0 {
+                candidate.insert_str(0, "PT");
+            }
+
+            // must not panic, and only accept strings starting with the designator
+            if parse_iso8601_duration(&candidate).is_ok() {
+                assert!(candidate.starts_with('P'), "{}", candidate);
+            }
+        }
+    }
+}
@@ -10,13 +10,37 @@ pub enum Error {
     Meilisearch(MeilisearchError),
     /// There is no Meilisearch server listening on the [specified host]
     /// (../client/struct.Client.html#method.new).
+    ///
+    /// It's only returned on wasm, where the browser doesn't tell why the request failed.
+    /// Elsewhere, the cause is known and [Error::Connect] is returned.
     UnreachableServer,
-    /// The Meilisearch server returned an invalid JSON for a request.
-    ParseError(serde_json::Error),
+    /// The connection to the Meilisearch server failed, or broke, before a response was received.
+    Connect {
+        kind: ConnectErrorKind,
+    },
+    /// The Meilisearch server returned an invalid JSON for a request,
+    /// or a JSON that doesn't match the type it is deserialized into, ie with a field unknown to this version of the SDK.
+    ParseError {
+        /// The JSON that couldn't be parsed.
+        body: String,
+        /// The type the JSON was deserialized into.
+        type_name: &'static str,
+        source: serde_json::Error,
+    },
     /// A timeout happened while waiting for an update to complete.
     Timeout,
+    /// The task waited for doesn't exist anymore, ie because it was deleted along with its index, so it will never complete.
+    TaskVanished {
+        uid: u32,
+    },
+    /// The task waited for was canceled, by the task whose uid is `by`, so it will never complete.
+    TaskCanceled {
+        uid: u32,
+        by: Option<u32>,
+    },
     /// This Meilisearch SDK generated an invalid request (which was not sent).
-    /// It probably comes from an invalid API key resulting in an invalid HTTP header.
+    /// It probably comes from an invalid API key resulting in an invalid HTTP header,
+    /// or from [faceting settings](crate::settings::FacetingSettings::validate) that can't be right.
     InvalidRequest,
 
     /// It is not possible to generate a tenant token with a invalid api key.
@@ -41,6 +65,158 @@ pub enum Error {
     Uuid(uuid::Error),
     // Error thrown in case the version of the Uuid is not v4.
     InvalidUuid4Version,
+    /// A vector contains a `NaN` or an infinite value.
+    InvalidVector,
+    /// Two vectors that should have the same dimension don't.
+    VectorDimensionMismatch {
+        expected: usize,
+        found: usize,
+    },
+    /// The request was aborted because the [Client](crate::client::Client) was dropped,
+    /// see [ClientBuilder::with_abort_on_drop](crate::client::ClientBuilder::with_abort_on_drop).
+    Aborted,
+    /// The index uid can't be used by Meilisearch.
+    /// It must only contain alphanumeric characters, `-` and `_`, and be at most 400 characters long.
+    InvalidIndexUid(String),
+    /// The response of Meilisearch is larger than the [maximum size](crate::client::ClientBuilder::with_max_response_bytes)
+    /// allowed by the client, so it was not read entirely.
+    ResponseTooLarge {
+        limit: usize,
+    },
+    /// The limit of a search is larger than the [maximum](crate::client::ClientBuilder::with_max_search_limit)
+    /// allowed by the client.
+    SearchLimitExceeded {
+        limit: usize,
+        max: usize,
+    },
+    /// A field used by the filter of a [checked search](crate::search::SearchQuery::execute_checked)
+    /// is not part of the filterable attributes of the index.
+    AttributeNotFilterable {
+        field: String,
+        configured: Vec<String>,
+    },
+    /// A field used by the sort of a [checked search](crate::search::SearchQuery::execute_checked)
+    /// is not part of the sortable attributes of the index.
+    AttributeNotSortable {
+        field: String,
+        configured: Vec<String>,
+    },
+    /// Meilisearch answered with a `503 Service Unavailable`, e.g. while it is in maintenance
+    /// during a snapshot or an upgrade. The request can be sent again later.
+    ServiceUnavailable {
+        message: String,
+    },
+    /// A URL query string contains a parameter that is not a [search parameter](crate::search::SearchParams::from_query_string).
+    UnknownQueryParameter(String),
+    /// A parameter of a URL query string has an invalid value or is repeated.
+    InvalidQueryParameter {
+        name: String,
+        value: String,
+    },
+    /// A field reserved by Meilisearch, like `_geo` or `_vectors`, doesn't have the expected shape in a document,
+    /// see [validate_reserved_fields](crate::documents::validate_reserved_fields).
+    InvalidReservedField {
+        /// The position of the document in the documents validated.
+        document: usize,
+        /// The invalid field, ie `_geo` or `_vectors.default` for the vectors of the `default` embedder.
+        field: String,
+        reason: &'static str,
+    },
+    /// A line of the NDJSON given to [Index::import_ndjson](crate::indexes::Index::import_ndjson) isn't a JSON object.
+    InvalidNdjsonLine {
+        /// The number of the line, counting from `1`.
+        line: usize,
+        source: serde_json::Error,
+    },
+    /// The index with this uid doesn't have a primary key yet.
+    MissingPrimaryKey(String),
+    /// The index was modified after the time given to [Index::set_settings_if_unchanged_since](crate::indexes::Index::set_settings_if_unchanged_since),
+    /// so its settings were not updated.
+    ConcurrentModification {
+        server_updated_at: time::OffsetDateTime,
+    },
+    /// The string isn't a built-in [ranking rule](crate::settings::RankingRule), nor an attribute followed by `:asc` or `:desc`.
+    InvalidRankingRule(String),
+    /// The words [allowed one typo](crate::settings::MinWordSizeForTypos::one_typo) are required to be longer
+    /// than the ones allowed two typos.
+    InvalidMinWordSizeForTypos {
+        one_typo: u8,
+        two_typos: u8,
+    },
+    /// Reading or writing a [settings file](crate::settings::Settings::from_reader) failed.
+    Io(std::io::Error),
+    /// A [settings file](crate::settings::Settings::from_reader) contains a key that isn't a setting,
+    /// given with its path, ie `typoTolerance.enabeld`.
+    UnknownSettingsKey(String),
+    /// The size of the chunks given to [Index::search_in_chunks](crate::indexes::Index::search_in_chunks) is `0`.
+    InvalidChunkSize,
+}
+
+impl Error {
+    /// A [Error::ParseError] for a `body` that couldn't be deserialized into a `T`.
+    pub(crate) fn parse_error<T>(body: impl Into<String>, source: serde_json::Error) -> Error {
+        Error::ParseError {
+            body: body.into(),
+            type_name: std::any::type_name::<T>(),
+            source,
+        }
+    }
+
+    /// Whether the request failed because of a temporary condition of the server,
+    /// so sending it again later can succeed.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::errors::Error;
+    /// let error = Error::ServiceUnavailable { message: String::from("Meilisearch is in maintenance.") };
+    /// assert!(error.is_retriable());
+    /// assert!(!Error::InvalidVector.is_retriable());
+    /// ```
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            Error::ServiceUnavailable { .. } | Error::UnreachableServer => true,
+            Error::Connect { kind } => kind.is_retriable(),
+            _ => false,
+        }
+    }
+}
+
+/// Why the connection to the Meilisearch server failed, see [Error::Connect].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectErrorKind {
+    /// The host couldn't be resolved.
+    Dns,
+    /// Nothing is listening on the host, or the connection was refused.
+    Refused,
+    /// The server didn't answer in time.
+    TimedOut,
+    /// The TLS handshake failed, ie because of an invalid certificate.
+    Tls,
+    /// Another network failure, described by the message.
+    Other(String),
+}
+
+impl ConnectErrorKind {
+    /// Whether the failure can be temporary, ie while Meilisearch or its DNS record is being deployed.
+    /// A TLS failure comes from the configuration and won't go away by itself.
+    pub fn is_retriable(&self) -> bool {
+        matches!(
+            self,
+            ConnectErrorKind::Dns | ConnectErrorKind::Refused | ConnectErrorKind::TimedOut
+        )
+    }
+}
+
+impl std::fmt::Display for ConnectErrorKind {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectErrorKind::Dns => write!(fmt, "the host couldn't be resolved"),
+            ConnectErrorKind::Refused => write!(fmt, "the connection was refused"),
+            ConnectErrorKind::TimedOut => write!(fmt, "the connection timed out"),
+            ConnectErrorKind::Tls => write!(fmt, "the TLS handshake failed"),
+            ConnectErrorKind::Other(message) => write!(fmt, "{}", message),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -182,6 +358,21 @@ impl std::fmt::Display for ErrorCode {
     }
 }
 
+/// The number of bytes of the body of a [Error::ParseError] shown in its message.
+const MAX_DISPLAYED_BODY: usize = 1024;
+
+/// Cut `text` to at most `max` bytes, at a char boundary.
+fn truncate(text: &str, max: usize) -> std::borrow::Cow<'_, str> {
+    if text.len() <= max {
+        return text.into();
+    }
+    let end = (0..=max)
+        .rev()
+        .find(|i| text.is_char_boundary(*i))
+        .unwrap_or(0);
+    format!("{}…", &text[..end]).into()
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         match self {
@@ -199,17 +390,41 @@ impl std::fmt::Display for Error {
                 error_link,
             ),
             Error::UnreachableServer => write!(fmt, "The Meilisearch server can't be reached."),
+            Error::Connect { kind } => write!(fmt, "The Meilisearch server can't be reached: {}.", kind),
             Error::InvalidRequest => write!(fmt, "Unable to generate a valid HTTP request. It probably comes from an invalid API key."),
-            Error::ParseError(e) => write!(fmt, "Error parsing response JSON: {}", e),
+            Error::ParseError { body, type_name, source } => write!(fmt, "Error parsing response JSON into `{}`: {}. The response was: {}", type_name, source, truncate(body, MAX_DISPLAYED_BODY)),
             Error::HttpError(e) => write!(fmt, "HTTP request failed: {}", e),
             Error::Timeout => write!(fmt, "A task did not succeed in time."),
+            Error::TaskVanished { uid } => write!(fmt, "The task {} doesn't exist anymore.", uid),
+            Error::TaskCanceled { uid, by: Some(by) } => write!(fmt, "The task {} was canceled by the task {}.", uid, by),
+            Error::TaskCanceled { uid, by: None } => write!(fmt, "The task {} was canceled.", uid),
             Error::TenantTokensInvalidApiKey => write!(fmt, "The provided api_key is invalid."),
             Error::TenantTokensExpiredSignature => write!(fmt, "The provided expires_at is already expired."),
             Error::InvalidTenantToken(e) => write!(fmt, "Impossible to generate the token, jsonwebtoken encountered an error: {}", e),
             Error::Yaup(e) => write!(fmt, "Internal Error: could not parse the query parameters: {}", e),
             #[cfg(not(target_arch = "wasm32"))]
             Error::Uuid(e) => write!(fmt, "The uid of the token has bit an uuid4 format: {}", e),
-            Error::InvalidUuid4Version => write!(fmt, "The uid provided to the token is not of version uuidv4")
+            Error::InvalidUuid4Version => write!(fmt, "The uid provided to the token is not of version uuidv4"),
+            Error::InvalidVector => write!(fmt, "The vector contains a NaN or an infinite value."),
+            Error::VectorDimensionMismatch { expected, found } => write!(fmt, "Expected a vector of dimension {} but found one of dimension {}.", expected, found),
+            Error::InvalidIndexUid(uid) => write!(fmt, "The index uid `{}` is invalid. It must only contain alphanumeric characters, `-` and `_`, and be at most 400 characters long.", uid),
+            Error::ResponseTooLarge { limit } => write!(fmt, "The response is larger than the limit of {} bytes.", limit),
+            Error::SearchLimitExceeded { limit, max } => write!(fmt, "The search limit of {} is larger than the maximum of {}.", limit, max),
+            Error::AttributeNotFilterable { field, configured } => write!(fmt, "The attribute `{}` is not filterable. The filterable attributes are {:?}.", field, configured),
+            Error::AttributeNotSortable { field, configured } => write!(fmt, "The attribute `{}` is not sortable. The sortable attributes are {:?}.", field, configured),
+            Error::ServiceUnavailable { message } => write!(fmt, "Meilisearch is temporarily unavailable: {}", message),
+            Error::UnknownQueryParameter(name) => write!(fmt, "The query parameter `{}` is unknown.", name),
+            Error::InvalidQueryParameter { name, value } => write!(fmt, "The value `{}` of the query parameter `{}` is invalid.", value, name),
+            Error::InvalidReservedField { document, field, reason } => write!(fmt, "The field `{}` of the document {} is invalid: {}.", field, document, reason),
+            Error::InvalidNdjsonLine { line, source } => write!(fmt, "The line {} of the NDJSON isn't a JSON object: {}.", line, source),
+            Error::MissingPrimaryKey(uid) => write!(fmt, "The index `{}` doesn't have a primary key yet.", uid),
+            Error::ConcurrentModification { server_updated_at } => write!(fmt, "The index was modified at {} since it was read.", server_updated_at),
+            Error::Aborted => write!(fmt, "The request was aborted because the client was dropped."),
+            Error::InvalidRankingRule(rule) => write!(fmt, "`{}` isn't a valid ranking rule, expected one of `words`, `typo`, `proximity`, `attribute`, `sort`, `exactness`, or an attribute followed by `:asc` or `:desc`.", rule),
+            Error::InvalidMinWordSizeForTypos { one_typo, two_typos } => write!(fmt, "The minimum word size for one typo ({}) can't be larger than the one for two typos ({}).", one_typo, two_typos),
+            Error::Io(e) => write!(fmt, "I/O error: {}", e),
+            Error::UnknownSettingsKey(key) => write!(fmt, "The key `{}` isn't a setting known to this version of the SDK.", key),
+            Error::InvalidChunkSize => write!(fmt, "The size of the chunks must be greater than 0."),
         }
     }
 }
@@ -219,11 +434,21 @@ impl std::error::Error for Error {}
 #[cfg(not(target_arch = "wasm32"))]
 impl From<isahc::Error> for Error {
     fn from(error: isahc::Error) -> Error {
-        if error.kind() == isahc::error::ErrorKind::ConnectionFailed {
-            Error::UnreachableServer
-        } else {
-            Error::HttpError(error)
-        }
+        use isahc::error::ErrorKind;
+
+        let kind = match error.kind() {
+            ErrorKind::NameResolution => ConnectErrorKind::Dns,
+            ErrorKind::ConnectionFailed => ConnectErrorKind::Refused,
+            ErrorKind::Timeout => ConnectErrorKind::TimedOut,
+            _ if error.is_tls() => ConnectErrorKind::Tls,
+            // the message of the kind alone, `I/O error`, is too vague
+            ErrorKind::Io => ConnectErrorKind::Other(match std::error::Error::source(&error) {
+                Some(source) => format!("{}: {}", error, source),
+                None => error.to_string(),
+            }),
+            _ => return Error::HttpError(error),
+        };
+        Error::Connect { kind }
     }
 }
 
@@ -263,4 +488,74 @@ mod test {
         assert_eq!(error.error_code, ErrorCode::Unknown);
         assert_eq!(error.error_type, ErrorType::Unknown);
     }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_connect_error_kind() {
+        use isahc::error::ErrorKind;
+        use std::io;
+
+        fn kind(error: impl Into<isahc::Error>) -> Option<ConnectErrorKind> {
+            match Error::from(error.into()) {
+                Error::Connect { kind } => Some(kind),
+                _ => None,
+            }
+        }
+
+        assert_eq!(kind(ErrorKind::NameResolution), Some(ConnectErrorKind::Dns));
+        assert_eq!(
+            kind(ErrorKind::ConnectionFailed),
+            Some(ConnectErrorKind::Refused)
+        );
+        assert_eq!(
+            kind(io::Error::from(io::ErrorKind::ConnectionRefused)),
+            Some(ConnectErrorKind::Refused)
+        );
+        assert_eq!(kind(ErrorKind::Timeout), Some(ConnectErrorKind::TimedOut));
+        assert_eq!(
+            kind(io::Error::from(io::ErrorKind::TimedOut)),
+            Some(ConnectErrorKind::TimedOut)
+        );
+        assert_eq!(
+            kind(ErrorKind::BadServerCertificate),
+            Some(ConnectErrorKind::Tls)
+        );
+        assert_eq!(kind(ErrorKind::TlsEngine), Some(ConnectErrorKind::Tls));
+        assert!(matches!(
+            kind(io::Error::new(io::ErrorKind::ConnectionReset, "connection reset by peer")),
+            Some(ConnectErrorKind::Other(message)) if message.contains("connection reset by peer")
+        ));
+        // the other failures don't come from the connection
+        assert_eq!(kind(ErrorKind::TooManyRedirects), None);
+        assert_eq!(kind(ErrorKind::InvalidRequest), None);
+
+        let retriable = |kind| Error::Connect { kind }.is_retriable();
+        assert!(retriable(ConnectErrorKind::Dns));
+        assert!(retriable(ConnectErrorKind::Refused));
+        assert!(retriable(ConnectErrorKind::TimedOut));
+        assert!(!retriable(ConnectErrorKind::Tls));
+        assert!(!retriable(ConnectErrorKind::Other(String::from(
+            "broken pipe"
+        ))));
+    }
+
+    #[test]
+    fn test_parse_error_message() {
+        let body = r#"{ "uid": 1 }"#;
+        let source = serde_json::from_str::<String>(body).unwrap_err();
+        let error = Error::parse_error::<String>(body, source);
+        assert!(error.to_string().contains("String"));
+        assert!(error.to_string().ends_with(body));
+
+        // only the beginning of a long body is shown, cut at a char boundary
+        let body = format!("[{}]", "\"é\",".repeat(1000));
+        let source = serde_json::from_str::<String>(&body).unwrap_err();
+        let message = Error::parse_error::<String>(body.as_str(), source).to_string();
+        assert!(message.ends_with('…'));
+        assert!(message.len() < 1200);
+        assert!(matches!(
+            Error::parse_error::<String>(body.as_str(), serde_json::from_str::<String>("").unwrap_err()),
+            Error::ParseError { body: full, .. } if full == body
+        ));
+    }
 }
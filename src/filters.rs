@@ -0,0 +1,580 @@
+//! A typed representation of the [filter expressions](https://docs.meilisearch.com/learn/advanced/filtering.html) understood by Meilisearch.
+//!
+//! A [FilterExpr] can be rendered to the string expected by [SearchQuery::with_filter](crate::search::SearchQuery::with_filter)
+//! with [ToString], and checked against the settings of an index with [Settings::validate_filter_compatibility].
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::filters::*;
+//! let filter = FilterExpr::and([
+//!     FilterExpr::condition("genres", FilterOperator::Equal("horror".to_string())),
+//!     FilterExpr::not(FilterExpr::condition("release_date", FilterOperator::LowerThan("2000".to_string()))),
+//! ]);
+//!
+//! assert_eq!(filter.to_string(), r#"(genres = "horror" AND NOT release_date < "2000")"#);
+//! ```
+
+use crate::settings::Settings;
+use std::fmt::{self, Display};
+
+/// A filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+    /// A condition on the value of a field.
+    Condition {
+        field: String,
+        operator: FilterOperator,
+    },
+    /// Matches the documents whose `_geo` is within `distance_in_meters` of a point.
+    GeoRadius {
+        lat: f64,
+        lng: f64,
+        distance_in_meters: f64,
+    },
+    /// Matches the documents whose `_geo` is inside a rectangle, given by its top right and bottom left corners.
+    GeoBoundingBox {
+        top_right: (f64, f64),
+        bottom_left: (f64, f64),
+    },
+    /// Matches the documents matching all the expressions.
+    And(Vec<FilterExpr>),
+    /// Matches the documents matching at least one of the expressions.
+    Or(Vec<FilterExpr>),
+    /// Matches the documents not matching the expression.
+    Not(Box<FilterExpr>),
+}
+
+/// The operator of a [FilterExpr::Condition] and its operands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterOperator {
+    Equal(String),
+    NotEqual(String),
+    GreaterThan(String),
+    GreaterThanOrEqual(String),
+    LowerThan(String),
+    LowerThanOrEqual(String),
+    /// An inclusive range, ie `year 2000 TO 2010`.
+    To(String, String),
+    In(Vec<String>),
+    Exists,
+    IsNull,
+    IsEmpty,
+    /// Requires the experimental `containsFilter` feature.
+    Contains(String),
+    /// Requires the experimental `containsFilter` feature.
+    StartsWith(String),
+}
+
+impl FilterOperator {
+    /// The experimental feature that must be enabled on Meilisearch to use this operator, if any.
+    pub fn experimental_feature(&self) -> Option<&'static str> {
+        match self {
+            FilterOperator::Contains(_) | FilterOperator::StartsWith(_) => Some("containsFilter"),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            FilterOperator::Equal(_) => "=",
+            FilterOperator::NotEqual(_) => "!=",
+            FilterOperator::GreaterThan(_) => ">",
+            FilterOperator::GreaterThanOrEqual(_) => ">=",
+            FilterOperator::LowerThan(_) => "<",
+            FilterOperator::LowerThanOrEqual(_) => "<=",
+            FilterOperator::To(_, _) => "TO",
+            FilterOperator::In(_) => "IN",
+            FilterOperator::Exists => "EXISTS",
+            FilterOperator::IsNull => "IS NULL",
+            FilterOperator::IsEmpty => "IS EMPTY",
+            FilterOperator::Contains(_) => "CONTAINS",
+            FilterOperator::StartsWith(_) => "STARTS WITH",
+        }
+    }
+}
+
+#[allow(missing_docs)]
+impl FilterExpr {
+    pub fn condition(field: impl Into<String>, operator: FilterOperator) -> FilterExpr {
+        FilterExpr::Condition {
+            field: field.into(),
+            operator,
+        }
+    }
+    pub fn and(expressions: impl IntoIterator<Item = FilterExpr>) -> FilterExpr {
+        FilterExpr::And(expressions.into_iter().collect())
+    }
+    pub fn or(expressions: impl IntoIterator<Item = FilterExpr>) -> FilterExpr {
+        FilterExpr::Or(expressions.into_iter().collect())
+    }
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(expression: FilterExpr) -> FilterExpr {
+        FilterExpr::Not(Box::new(expression))
+    }
+
+    /// Call `f` on every condition of the expression, with the field and the operator it uses.
+    /// The geo expressions are reported as conditions on `_geo`.
+    fn visit<'a>(&'a self, f: &mut impl FnMut(&'a str, &'static str, Option<&'static str>)) {
+        match self {
+            FilterExpr::Condition { field, operator } => {
+                f(field, operator.name(), operator.experimental_feature())
+            }
+            FilterExpr::GeoRadius { .. } => f("_geo", "_geoRadius", None),
+            FilterExpr::GeoBoundingBox { .. } => f("_geo", "_geoBoundingBox", None),
+            FilterExpr::And(expressions) | FilterExpr::Or(expressions) => {
+                for expression in expressions {
+                    expression.visit(f);
+                }
+            }
+            FilterExpr::Not(expression) => expression.visit(f),
+        }
+    }
+}
+
+fn write_value(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(
+        f,
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    expressions: &[FilterExpr],
+    separator: &str,
+) -> fmt::Result {
+    write!(f, "(")?;
+    for (i, expression) in expressions.iter().enumerate() {
+        if i > 0 {
+            write!(f, " {} ", separator)?;
+        }
+        write!(f, "{}", expression)?;
+    }
+    write!(f, ")")
+}
+
+impl Display for FilterExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterExpr::Condition { field, operator } => {
+                write!(f, "{} ", field)?;
+                match operator {
+                    FilterOperator::Equal(value)
+                    | FilterOperator::NotEqual(value)
+                    | FilterOperator::GreaterThan(value)
+                    | FilterOperator::GreaterThanOrEqual(value)
+                    | FilterOperator::LowerThan(value)
+                    | FilterOperator::LowerThanOrEqual(value)
+                    | FilterOperator::Contains(value)
+                    | FilterOperator::StartsWith(value) => {
+                        write!(f, "{} ", operator.name())?;
+                        write_value(f, value)
+                    }
+                    FilterOperator::To(from, to) => {
+                        write_value(f, from)?;
+                        write!(f, " TO ")?;
+                        write_value(f, to)
+                    }
+                    FilterOperator::In(values) => {
+                        write!(f, "IN [")?;
+                        for (i, value) in values.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write_value(f, value)?;
+                        }
+                        write!(f, "]")
+                    }
+                    FilterOperator::Exists | FilterOperator::IsNull | FilterOperator::IsEmpty => {
+                        write!(f, "{}", operator.name())
+                    }
+                }
+            }
+            FilterExpr::GeoRadius {
+                lat,
+                lng,
+                distance_in_meters,
+            } => write!(f, "_geoRadius({}, {}, {})", lat, lng, distance_in_meters),
+            FilterExpr::GeoBoundingBox {
+                top_right,
+                bottom_left,
+            } => write!(
+                f,
+                "_geoBoundingBox([{}, {}], [{}, {}])",
+                top_right.0, top_right.1, bottom_left.0, bottom_left.1
+            ),
+            FilterExpr::And(expressions) => write_list(f, expressions, "AND"),
+            FilterExpr::Or(expressions) => write_list(f, expressions, "OR"),
+            FilterExpr::Not(expression) => write!(f, "NOT {}", expression),
+        }
+    }
+}
+
+/// A problem found by [Settings::validate_filter_compatibility].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterIssue {
+    /// The field is not part of the filterable attributes, nor nested in one of them.
+    UnknownField {
+        /// The index of the filter in the validated list.
+        filter: usize,
+        field: String,
+    },
+    /// The operator can only be used once an experimental feature is enabled.
+    ExperimentalOperator {
+        /// The index of the filter in the validated list.
+        filter: usize,
+        field: String,
+        operator: &'static str,
+        feature: &'static str,
+    },
+}
+
+/// Check whether `field` is one of the `attributes`, ie the filterable attributes.
+/// A field nested in one of them, ie `author.name` in `author`, is covered as well.
+pub(crate) fn is_covered(field: &str, attributes: &[String]) -> bool {
+    attributes.iter().any(|attribute| {
+        field == attribute
+            || (field.starts_with(attribute.as_str()) && field[attribute.len()..].starts_with('.'))
+    })
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Quoted(&'a str),
+    Symbol(char),
+}
+
+fn tokenize(filter: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = filter.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '"' | '\'' => {
+                let mut end = filter.len();
+                let mut escaped = false;
+                for (i, next) in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        end = i;
+                        break;
+                    }
+                }
+                tokens.push(Token::Quoted(&filter[start + 1..end]));
+            }
+            '(' | ')' | '[' | ']' | ',' | '=' | '!' | '<' | '>' => tokens.push(Token::Symbol(c)),
+            _ => {
+                let mut end = filter.len();
+                while let Some(&(i, next)) = chars.peek() {
+                    if next.is_whitespace() || "()[],=!<>\"'".contains(next) {
+                        end = i;
+                        break;
+                    }
+                    chars.next();
+                }
+                tokens.push(Token::Word(&filter[start..end]));
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Get the fields a filter expression, as given to [SearchQuery::with_filter](crate::search::SearchQuery::with_filter),
+/// depends on, ie `["genres", "year"]` for `genres = horror AND year > 2000`. The geo filters depend on `_geo`.
+///
+/// The filter isn't validated, an invalid filter only yields the fields that could be found.
+pub(crate) fn filter_fields(filter: &str) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+    let mut expect_field = true;
+    let mut tokens = tokenize(filter).into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        let field = match token {
+            Token::Word(word) if !expect_field => {
+                if word.eq_ignore_ascii_case("AND") || word.eq_ignore_ascii_case("OR") {
+                    expect_field = true;
+                }
+                continue;
+            }
+            Token::Word(word) if word.eq_ignore_ascii_case("NOT") => continue,
+            Token::Word(word)
+                if (word == "_geoRadius" || word == "_geoBoundingBox")
+                    && tokens.peek() == Some(&Token::Symbol('(')) =>
+            {
+                // skip the arguments
+                let mut depth = 0;
+                for token in tokens.by_ref() {
+                    match token {
+                        Token::Symbol('(') => depth += 1,
+                        Token::Symbol(')') if depth == 1 => break,
+                        Token::Symbol(')') => depth -= 1,
+                        _ => (),
+                    }
+                }
+                "_geo"
+            }
+            Token::Word(field) | Token::Quoted(field) if expect_field => field,
+            _ => continue,
+        };
+
+        expect_field = false;
+        if !fields.iter().any(|known| known == field) {
+            fields.push(field.to_string());
+        }
+    }
+
+    fields
+}
+
+/// Get the field of a sort criterion, ie `price` for `price:asc`. The geo sort criteria depend on `_geo`.
+pub(crate) fn sort_field(criterion: &str) -> &str {
+    if criterion.starts_with("_geoPoint(") {
+        return "_geo";
+    }
+    match criterion.rfind(':') {
+        Some(colon) => &criterion[..colon],
+        None => criterion,
+    }
+}
+
+impl Settings {
+    /// Check that the given filters can be used with these settings, without doing any HTTP call.
+    ///
+    /// Every field referenced by a filter must be one of the [filterable attributes](Settings::filterable_attributes)
+    /// or nested in one of them, and the geo filters need `_geo` to be filterable.
+    /// The operators depending on an experimental feature, like `CONTAINS`, are reported as well.
+    /// When the filterable attributes are not defined, no field is considered filterable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{filters::*, settings::Settings};
+    /// let settings = Settings::new().with_filterable_attributes(["author", "year"]);
+    /// let filters = [
+    ///     FilterExpr::condition("author.name", FilterOperator::Equal("Tolkien".to_string())),
+    ///     FilterExpr::condition("genres", FilterOperator::Exists),
+    /// ];
+    ///
+    /// let issues = settings.validate_filter_compatibility(&filters);
+    /// assert_eq!(issues, vec![FilterIssue::UnknownField { filter: 1, field: "genres".to_string() }]);
+    /// ```
+    pub fn validate_filter_compatibility(&self, filters: &[FilterExpr]) -> Vec<FilterIssue> {
+        let filterable_attributes = self
+            .filterable_attributes
+            .as_option()
+            .map_or(&[][..], Vec::as_slice);
+        let mut issues = Vec::new();
+
+        for (index, filter) in filters.iter().enumerate() {
+            filter.visit(&mut |field, operator, feature| {
+                if !is_covered(field, filterable_attributes) {
+                    let issue = FilterIssue::UnknownField {
+                        filter: index,
+                        field: field.to_string(),
+                    };
+                    if !issues.contains(&issue) {
+                        issues.push(issue);
+                    }
+                }
+                if let Some(feature) = feature {
+                    issues.push(FilterIssue::ExperimentalOperator {
+                        filter: index,
+                        field: field.to_string(),
+                        operator,
+                        feature,
+                    });
+                }
+            });
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_fields() {
+        assert_eq!(
+            filter_fields(
+                r#"genres = horror AND (year > 2000 OR NOT "author.name" IN ['a b', "c AND d"])"#
+            ),
+            vec!["genres", "year", "author.name"]
+        );
+        assert_eq!(
+            filter_fields("rating 1 TO 5 and tags IS NOT EMPTY or tags EXISTS"),
+            vec!["rating", "tags"]
+        );
+        assert_eq!(
+            filter_fields("_geoRadius(45.47, 9.18, 2000) AND price<=10"),
+            vec!["_geo", "price"]
+        );
+        assert_eq!(sort_field("price:asc"), "price");
+        assert_eq!(sort_field("_geoPoint(45.47, 9.18):desc"), "_geo");
+    }
+
+    fn equal(field: &str, value: &str) -> FilterExpr {
+        FilterExpr::condition(field, FilterOperator::Equal(value.to_string()))
+    }
+
+    #[test]
+    fn test_display() {
+        let filter = FilterExpr::or([
+            FilterExpr::and([
+                equal("title", "The \"Matrix\""),
+                FilterExpr::condition(
+                    "year",
+                    FilterOperator::To("1990".to_string(), "2000".to_string()),
+                ),
+            ]),
+            FilterExpr::condition(
+                "genres",
+                FilterOperator::In(vec!["horror".to_string(), "comedy".to_string()]),
+            ),
+            FilterExpr::not(FilterExpr::condition("poster", FilterOperator::IsNull)),
+            FilterExpr::GeoRadius {
+                lat: 45.5,
+                lng: -3.0,
+                distance_in_meters: 2000.0,
+            },
+        ]);
+
+        assert_eq!(
+            filter.to_string(),
+            r#"((title = "The \"Matrix\"" AND year "1990" TO "2000") OR genres IN ["horror", "comedy"] OR NOT poster IS NULL OR _geoRadius(45.5, -3, 2000))"#
+        );
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        let settings = Settings::new().with_filterable_attributes(["author", "meta.tags"]);
+        let filters = [
+            equal("author", "Tolkien"),
+            equal("author.name", "Tolkien"),
+            equal("author.address.city", "Oxford"),
+            equal("authors", "Tolkien"),
+            equal("meta", "x"),
+            equal("meta.tags", "x"),
+            equal("meta.tags.name", "x"),
+            equal("meta.other", "x"),
+        ];
+
+        assert_eq!(
+            settings.validate_filter_compatibility(&filters),
+            vec![
+                FilterIssue::UnknownField {
+                    filter: 3,
+                    field: "authors".to_string()
+                },
+                FilterIssue::UnknownField {
+                    filter: 4,
+                    field: "meta".to_string()
+                },
+                FilterIssue::UnknownField {
+                    filter: 7,
+                    field: "meta.other".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_geo_filters() {
+        let radius = FilterExpr::GeoRadius {
+            lat: 48.8,
+            lng: 2.3,
+            distance_in_meters: 1000.0,
+        };
+        let bounding_box = FilterExpr::GeoBoundingBox {
+            top_right: (48.9, 2.4),
+            bottom_left: (48.7, 2.2),
+        };
+
+        let settings = Settings::new().with_filterable_attributes(["city"]);
+        assert_eq!(
+            settings.validate_filter_compatibility(&[radius.clone(), bounding_box.clone()]),
+            vec![
+                FilterIssue::UnknownField {
+                    filter: 0,
+                    field: "_geo".to_string()
+                },
+                FilterIssue::UnknownField {
+                    filter: 1,
+                    field: "_geo".to_string()
+                },
+            ]
+        );
+
+        let settings = Settings::new().with_filterable_attributes(["city", "_geo"]);
+        assert!(settings
+            .validate_filter_compatibility(&[radius, bounding_box])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_contains_filter() {
+        let settings = Settings::new().with_filterable_attributes(["title"]);
+        let filters = [
+            FilterExpr::and([
+                FilterExpr::condition("title", FilterOperator::Contains("ring".to_string())),
+                FilterExpr::condition("title", FilterOperator::StartsWith("the".to_string())),
+            ]),
+            FilterExpr::condition("overview", FilterOperator::Contains("ring".to_string())),
+        ];
+
+        assert_eq!(
+            settings.validate_filter_compatibility(&filters),
+            vec![
+                FilterIssue::ExperimentalOperator {
+                    filter: 0,
+                    field: "title".to_string(),
+                    operator: "CONTAINS",
+                    feature: "containsFilter"
+                },
+                FilterIssue::ExperimentalOperator {
+                    filter: 0,
+                    field: "title".to_string(),
+                    operator: "STARTS WITH",
+                    feature: "containsFilter"
+                },
+                FilterIssue::UnknownField {
+                    filter: 1,
+                    field: "overview".to_string()
+                },
+                FilterIssue::ExperimentalOperator {
+                    filter: 1,
+                    field: "overview".to_string(),
+                    operator: "CONTAINS",
+                    feature: "containsFilter"
+                },
+            ]
+        );
+        assert_eq!(
+            filters[0].to_string(),
+            r#"(title CONTAINS "ring" AND title STARTS WITH "the")"#
+        );
+    }
+
+    #[test]
+    fn test_undefined_filterable_attributes() {
+        let issues = Settings::new().validate_filter_compatibility(&[equal("title", "x")]);
+
+        assert_eq!(
+            issues,
+            vec![FilterIssue::UnknownField {
+                filter: 0,
+                field: "title".to_string()
+            }]
+        );
+    }
+}
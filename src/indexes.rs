@@ -1,14 +1,32 @@
 use crate::{
-    client::Client,
-    documents::{DocumentQuery, DocumentsQuery, DocumentsResults},
+    client::{Client, EmbedderDimensions, WaitPolicy},
+    documents::{
+        validate_reserved_fields, DeletionOutcome, DocumentId, DocumentQuery, DocumentsQuery,
+        DocumentsResults,
+    },
     errors::Error,
+    filters::{FilterExpr, FilterOperator},
     request::*,
     search::*,
+    settings::Settings,
     task_info::TaskInfo,
     tasks::*,
 };
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Display, time::Duration};
+use either::Either;
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize, Serialize,
+};
+use serde_json::{json, Map, Value};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::{BufRead, BufReader, Read, Write},
+    marker::PhantomData,
+    ops::Deref,
+    time::Duration,
+};
 use time::OffsetDateTime;
 
 /// An index containing [Document]s.
@@ -75,6 +93,9 @@ pub struct Index {
     pub primary_key: Option<String>,
 }
 
+/// The number of documents fetched by each request of [Index::export_ndjson], and sent by each request of [Index::import_ndjson].
+const NDJSON_BATCH_SIZE: usize = 1000;
+
 impl Index {
     pub fn new(uid: impl Into<String>, client: Client) -> Index {
         Index {
@@ -85,6 +106,92 @@ impl Index {
             updated_at: None,
         }
     }
+
+    /// A copy of this [Index] waiting for its tasks with `wait_policy` instead of the [policy of the client](crate::client::ClientBuilder::with_wait_policy).
+    ///
+    /// The policy is used by [Index::wait_for_task] and by the methods of the index that wait for their task,
+    /// ie [Index::clear_documents_and_wait]. It's stored in the [client](Index::client) of the copy,
+    /// so [TaskInfo::wait_for_completion] uses it too when given this client. The other [Index] of the same client are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// # use std::time::Duration;
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let catalog = client
+    ///     .index("catalog")
+    ///     .with_wait_policy(WaitPolicy::new(Duration::from_secs(1), Duration::from_secs(600)));
+    ///
+    /// assert_eq!(catalog.wait_policy().timeout, Duration::from_secs(600));
+    /// assert_eq!(client.index("config").wait_policy(), WaitPolicy::default());
+    /// ```
+    pub fn with_wait_policy(&self, wait_policy: WaitPolicy) -> Index {
+        let mut index = self.clone();
+        index.client.wait_policy = wait_policy;
+        index
+    }
+
+    /// The [WaitPolicy] used to wait for the tasks of this index.
+    pub fn wait_policy(&self) -> WaitPolicy {
+        self.client.wait_policy
+    }
+
+    /// A copy of this [Index] whose searches only return the documents matching `filter`, ie `tenant_id = 42`.
+    ///
+    /// The base filter is combined with `AND` to the filter of every query [executed](SearchQuery::execute) on the copy,
+    /// of every [facet search](Index::facet_search) and of the [similar documents](Index::get_similar_raw),
+    /// so a query can narrow it but never remove it. Calling this method again adds another base filter rather than replacing it.
+    /// Like the [wait policy](Index::with_wait_policy), the base filters are stored in the [client](Index::client) of the copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::client::*;
+    /// let client = Client::new("http://localhost:7700", "masterKey");
+    /// let tenant = client
+    ///     .index("orders")
+    ///     .with_base_filter("tenant_id = 42");
+    ///
+    /// assert_eq!(tenant.base_filters(), ["tenant_id = 42"]);
+    /// assert!(client.index("orders").base_filters().is_empty());
+    /// ```
+    pub fn with_base_filter(&self, filter: &str) -> Index {
+        let mut index = self.clone();
+        index.client.base_filters.push(filter.to_string());
+        index
+    }
+
+    /// The [base filters](Index::with_base_filter) applied to the searches of this index.
+    pub fn base_filters(&self) -> &[String] {
+        &self.client.base_filters
+    }
+
+    /// Check that an index uid is accepted by Meilisearch, without doing any HTTP call.
+    ///
+    /// A valid uid is made of 1 to 400 alphanumeric characters, hyphens (`-`) and underscores (`_`).
+    /// Otherwise [Error::InvalidIndexUid] is returned with the offending uid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::indexes::Index;
+    /// assert!(Index::validate_uid("movies_2022").is_ok());
+    /// assert!(Index::validate_uid("my movies").is_err());
+    /// ```
+    pub fn validate_uid(uid: &str) -> Result<(), Error> {
+        let is_valid = (1..=400).contains(&uid.len())
+            && uid
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidIndexUid(uid.to_string()))
+        }
+    }
+
     /// Internal Function to create an [Index] from `serde_json::Value` and [Client]
     pub(crate) fn from_value(raw_index: serde_json::Value, client: Client) -> Result<Index, Error> {
         #[derive(Deserialize, Debug)]
@@ -98,7 +205,8 @@ impl Index {
             primaryKey: Option<String>,
         }
 
-        let i: IndexFromSerde = serde_json::from_value(raw_index).map_err(Error::ParseError)?;
+        let i: IndexFromSerde = serde_json::from_value(raw_index.clone())
+            .map_err(|e| Error::parse_error::<Index>(raw_index.to_string(), e))?;
 
         Ok(Index {
             uid: i.uid,
@@ -178,7 +286,7 @@ impl Index {
     pub async fn delete(self) -> Result<TaskInfo, Error> {
         request::<(), TaskInfo>(
             &format!("{}/indexes/{}", self.client.host, self.uid),
-            &self.client.api_key,
+            &self.client,
             Method::Delete,
             202,
         )
@@ -221,13 +329,7 @@ impl Index {
         &self,
         query: &SearchQuery<'_>,
     ) -> Result<SearchResults<T>, Error> {
-        request::<&SearchQuery, SearchResults<T>>(
-            &format!("{}/indexes/{}/search", self.client.host, self.uid),
-            &self.client.api_key,
-            Method::Post(query),
-            200,
-        )
-        .await
+        self.client.execute_search::<T>(&self.uid, query).await
     }
 
     /// Search for documents matching a specific query in the index.\
@@ -271,9 +373,440 @@ impl Index {
         SearchQuery::new(self)
     }
 
+    /// Search for the documents whose `field` is one of the `values`, splitting the `field IN [...]` filter
+    /// into several searches of at most `chunk` values so each request stays under the URL and body limits.
+    ///
+    /// The `base` query is sent for every chunk with the `IN` filter added to its own filter.
+    /// The hits are merged client-side: deduplicated by primary key, sorted by [ranking score](SearchResult::ranking_score),
+    /// then paginated with the `offset` and `limit` of the `base` query.
+    /// The primary key is retrieved even if the `base` query doesn't [retrieve](SearchQuery::with_attributes_to_retrieve) it,
+    /// and removed from the hits afterwards. When it isn't known, it is fetched once then cached by the [Client].
+    /// The hits count and the facet distribution are the sums of the ones of every chunk, so they are only estimates.
+    ///
+    /// Fails with [Error::InvalidChunkSize] if `chunk` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: String,
+    ///     title: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("search_in_chunks");
+    ///
+    /// let ids: Vec<String> = (0..5000).map(|id| id.to_string()).collect();
+    /// let mut query = movies.search();
+    /// query.with_query("space").with_limit(50);
+    ///
+    /// let results = movies
+    ///     .search_in_chunks::<Movie>(&query, "id", &ids, 500)
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn search_in_chunks<T: 'static + DeserializeOwned>(
+        &self,
+        base: &SearchQuery<'_>,
+        field: &str,
+        values: &[String],
+        chunk: usize,
+    ) -> Result<SearchResults<T>, Error> {
+        if chunk == 0 {
+            return Err(Error::InvalidChunkSize);
+        }
+        let primary_key = self.cached_primary_key().await?;
+        // the hits are deduplicated by primary key, so it has to be retrieved
+        let attributes_to_retrieve = match &base.attributes_to_retrieve {
+            Some(Selectors::Some(attributes))
+                if !attributes.contains(&"*") && !attributes.contains(&primary_key.as_str()) =>
+            {
+                let mut attributes = attributes.to_vec();
+                attributes.push(&primary_key);
+                Some(attributes)
+            }
+            _ => None,
+        };
+
+        let mut seen = HashSet::with_capacity(values.len());
+        let unique: Vec<String> = values
+            .iter()
+            .filter(|value| seen.insert(*value))
+            .cloned()
+            .collect();
+        let filters: Vec<String> = unique
+            .chunks(chunk)
+            .map(|values| {
+                FilterExpr::condition(field, FilterOperator::In(values.to_vec())).to_string()
+            })
+            .collect();
+
+        let offset = base.offset.unwrap_or(0);
+        let limit = base.limit.unwrap_or(20);
+        let queries: Vec<SearchQuery> = filters
+            .iter()
+            .map(|filter| {
+                let mut query = base.clone();
+                let mut filters = match base.filter.as_ref().map(|filter| &filter.inner) {
+                    Some(Either::Left(filter)) => vec![filter.clone()],
+                    Some(Either::Right(filters)) => filters.clone(),
+                    None => Vec::new(),
+                };
+                filters.push(Cow::Borrowed(filter.as_str()));
+                query.filter = Some(Filter {
+                    inner: Either::Right(filters),
+                });
+                if let Some(attributes) = &attributes_to_retrieve {
+                    query.with_attributes_to_retrieve(Selectors::Some(attributes));
+                }
+                query
+                    .with_offset(0)
+                    .with_limit(offset + limit)
+                    .with_show_ranking_score(true);
+                query
+            })
+            .collect();
+        let responses = futures::future::try_join_all(
+            queries
+                .iter()
+                .map(|query| self.execute_query::<Map<String, Value>>(query)),
+        )
+        .await?;
+
+        let mut merged = SearchResults {
+            hits: Vec::new(),
+            offset,
+            limit,
+            estimated_total_hits: 0,
+            facet_distribution: None,
+            processing_time_ms: 0,
+            query: base.query.as_deref().unwrap_or_default().to_string(),
+            semantic_hit_count: None,
+            query_vector: None,
+            page: None,
+            hits_per_page: None,
+            total_hits: None,
+            total_pages: None,
+        };
+        let mut hits: Vec<SearchResult<Map<String, Value>>> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for response in responses {
+            merged.estimated_total_hits += response.estimated_total_hits;
+            merged.processing_time_ms = merged.processing_time_ms.max(response.processing_time_ms);
+            if let Some(count) = response.semantic_hit_count {
+                *merged.semantic_hit_count.get_or_insert(0) += count;
+            }
+            if merged.query_vector.is_none() {
+                merged.query_vector = response.query_vector;
+            }
+            if let Some(distribution) = response.facet_distribution {
+                let merged_distribution =
+                    merged.facet_distribution.get_or_insert_with(HashMap::new);
+                for (facet, counts) in distribution {
+                    let merged_counts = merged_distribution.entry(facet).or_default();
+                    for (value, count) in counts {
+                        *merged_counts.entry(value).or_default() += count;
+                    }
+                }
+            }
+            for hit in response.hits {
+                let id = hit
+                    .result
+                    .get(&primary_key)
+                    .map(Value::to_string)
+                    .unwrap_or_default();
+                match positions.get(&id) {
+                    Some(&position) if hits[position].ranking_score < hit.ranking_score => {
+                        hits[position] = hit
+                    }
+                    Some(_) => {}
+                    None => {
+                        positions.insert(id, hits.len());
+                        hits.push(hit);
+                    }
+                }
+            }
+        }
+
+        // the sort is stable, so the hits with the same score stay in the order of the chunks
+        hits.sort_by(|a, b| {
+            b.ranking_score
+                .partial_cmp(&a.ranking_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let show_ranking_score = base.show_ranking_score == Some(true);
+        merged.hits = hits
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|mut hit| {
+                if attributes_to_retrieve.is_some() {
+                    hit.result.remove(&primary_key);
+                }
+                let result = Value::Object(hit.result);
+                Ok(SearchResult {
+                    result: serde_json::from_value(result.clone())
+                        .map_err(|e| Error::parse_error::<T>(result.to_string(), e))?,
+                    formatted_result: hit.formatted_result,
+                    matches_position: hit.matches_position,
+                    ranking_score: hit.ranking_score.filter(|_| show_ranking_score),
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(merged)
+    }
+
+    /// The primary key of the index if it's known, otherwise the one cached by the [Client], fetched if there is none.
+    async fn cached_primary_key(&self) -> Result<String, Error> {
+        if let Some(primary_key) = self.primary_key() {
+            return Ok(primary_key.to_string());
+        }
+        let cache = &self.client.primary_keys_cache;
+        if let Some(primary_key) = cache.get(&self.uid) {
+            return Ok(primary_key);
+        }
+
+        let primary_key = self.clone().primary_key_or_fetch().await?;
+        cache.insert(&self.uid, primary_key.clone());
+        Ok(primary_key)
+    }
+
+    /// Search for the values of a facet matching a facet query.\
+    /// See also [Client::facet_search].
+    ///
+    /// The facet has to be part of the filterable attributes of the index.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("facet_search");
+    ///
+    /// let params = FacetSearchParams::new("genres").with_facet_query("fic");
+    /// let response = movies.facet_search(&params).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn facet_search(
+        &self,
+        params: &FacetSearchParams<'_>,
+    ) -> Result<FacetSearchResponse, Error> {
+        self.client.facet_search(&self.uid, params).await
+    }
+
+    /// Run several [facet searches](Index::facet_search) concurrently, ie to autocomplete multiple facets at once.
+    ///
+    /// The responses are in the order of `requests`. If one of the searches fails, its error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("facet_search_many");
+    ///
+    /// let responses = movies
+    ///     .facet_search_many(&[
+    ///         FacetSearchParams::new("genres").with_facet_query("fic"),
+    ///         FacetSearchParams::new("director").with_facet_query("nol"),
+    ///     ])
+    ///     .await
+    ///     .unwrap();
+    /// let (genres, directors) = (&responses[0], &responses[1]);
+    /// # });
+    /// ```
+    pub async fn facet_search_many(
+        &self,
+        requests: &[FacetSearchParams<'_>],
+    ) -> Result<Vec<FacetSearchResponse>, Error> {
+        futures::future::try_join_all(requests.iter().map(|params| self.facet_search(params))).await
+    }
+
+    /// Get the `limit` documents whose vectors are the closest to `vector`, according to `embedder`,
+    /// with their [ranking score](SearchResult::ranking_score).
+    ///
+    /// It's a purely semantic search, ie to recommend documents from an embedding that isn't indexed.
+    /// Only the documents matching `filter` are returned when it's given.
+    ///
+    /// The dimensions of the embedders are fetched once then cached by the [Client], and
+    /// [Error::VectorDimensionMismatch] is returned without searching when `vector` doesn't have the dimensions
+    /// of `embedder`. They are fetched again before failing, in case the embedders changed since.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// # use serde_json::Value;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("nearest_neighbors");
+    ///
+    /// let results = movies
+    ///     .nearest_neighbors::<Value>("default", &[0.1, 0.7, 0.2], 10, Some("year > 2000"))
+    ///     .await
+    ///     .unwrap();
+    /// for hit in results.hits {
+    ///     println!("{:?}: {:?}", hit.ranking_score, hit.result);
+    /// }
+    /// # });
+    /// ```
+    pub async fn nearest_neighbors<T: 'static + DeserializeOwned>(
+        &self,
+        embedder: &str,
+        vector: &[f32],
+        limit: usize,
+        filter: Option<&str>,
+    ) -> Result<SearchResults<T>, Error> {
+        self.check_vector_dimensions(embedder, vector.len()).await?;
+
+        let mut query = SearchQuery::new(self);
+        query
+            .with_query("")
+            .with_vector(vector)
+            .with_hybrid(embedder, 1.0)
+            .with_limit(limit)
+            .with_show_ranking_score(true);
+        if let Some(filter) = filter {
+            query.with_filter(filter);
+        }
+        self.execute_query::<T>(&query).await
+    }
+
+    async fn check_vector_dimensions(&self, embedder: &str, found: usize) -> Result<(), Error> {
+        let cache = &self.client.embedders_cache;
+        match cache.get(&self.uid, embedder) {
+            Some(None) => return Ok(()),
+            Some(Some(expected)) if expected == found => return Ok(()),
+            _ => (),
+        }
+
+        let dimensions: EmbedderDimensions = self
+            .get_embedders()
+            .await?
+            .into_iter()
+            .map(|(name, embedder)| (name, embedder.dimensions))
+            .collect();
+        let expected = dimensions.get(embedder).copied().flatten();
+        cache.insert(&self.uid, dimensions);
+
+        match expected {
+            Some(expected) if expected != found => {
+                Err(Error::VectorDimensionMismatch { expected, found })
+            }
+            // an unknown embedder is reported by Meilisearch
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the facet distribution of the documents matching `filter`, without returning any document.
+    ///
+    /// It runs a search with a `limit` of `0`, so only the counts of the values of the `facets` are transferred,
+    /// ie to render the facets of a page before anything is typed. The facets have to be filterable.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("facets_overview");
+    ///
+    /// let facets = movies.facets_overview(&["genres", "year"], Some("year > 2000")).await.unwrap();
+    /// for (genre, count) in &facets["genres"] {
+    ///     println!("{} ({})", genre, count);
+    /// }
+    /// # });
+    /// ```
+    pub async fn facets_overview(
+        &self,
+        facets: &[&str],
+        filter: Option<&str>,
+    ) -> Result<HashMap<String, HashMap<String, usize>>, Error> {
+        let mut query = self.search();
+        query.with_limit(0).with_facets(Selectors::Some(facets));
+        if let Some(filter) = filter {
+            query.with_filter(filter);
+        }
+
+        let results = self.execute_query::<IgnoredAny>(&query).await?;
+        Ok(results.facet_distribution.unwrap_or_default())
+    }
+
+    /// Get the documents similar to a document, as returned by Meilisearch.
+    ///
+    /// The similarity is computed by the given `embedder` on the vectors of the documents.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("get_similar_raw");
+    ///
+    /// let similar = movies.get_similar_raw("Interstellar", "default").await.unwrap();
+    /// let hits = similar["hits"].as_array().unwrap();
+    /// # });
+    /// ```
+    pub async fn get_similar_raw(
+        &self,
+        document_id: impl Display,
+        embedder: &str,
+    ) -> Result<Value, Error> {
+        let mut body = json!({
+            "id": document_id.to_string(),
+            "embedder": embedder,
+        });
+        if let Some(filter) = self.client.combine_base_filters(None) {
+            body["filter"] = json!(filter);
+        }
+        request::<Value, Value>(
+            &format!("{}/indexes/{}/similar", self.client.host, self.uid),
+            &self.client,
+            Method::Post(body),
+            200,
+        )
+        .await
+    }
+
     /// Get one [Document] using its unique id.
     /// Serde is needed. Add `serde = {version="1.0", features=["derive"]}` in the dependencies section of your Cargo.toml.
     ///
+    /// The numbers are read without going through `f64`: an integer id, ie a 64-bit snowflake id, keeps
+    /// all its digits when `T` declares it as `u64` or `i64`, or when `T` is a [serde_json::Value].
+    ///
     /// # Example
     ///
     /// ```
@@ -315,7 +848,7 @@ impl Index {
             self.client.host, self.uid, document_id
         );
 
-        request::<(), T>(&url, &self.client.api_key, Method::Get(()), 200).await
+        request::<(), T>(&url, &self.client, Method::Get(()), 200).await
     }
 
     /// Get one document with parameters.
@@ -366,8 +899,7 @@ impl Index {
             self.client.host, self.uid, document_id
         );
 
-        request::<&DocumentQuery, T>(&url, &self.client.api_key, Method::Get(document_query), 200)
-            .await
+        request::<&DocumentQuery, T>(&url, &self.client, Method::Get(document_query), 200).await
     }
 
     /// Get [Document]s by batch.
@@ -409,7 +941,7 @@ impl Index {
     ) -> Result<DocumentsResults<T>, Error> {
         let url = format!("{}/indexes/{}/documents", self.client.host, self.uid);
 
-        request::<(), DocumentsResults<T>>(&url, &self.client.api_key, Method::Get(()), 200).await
+        request::<(), DocumentsResults<T>>(&url, &self.client, Method::Get(()), 200).await
     }
 
     /// Get [Document]s by batch with parameters.
@@ -457,13 +989,151 @@ impl Index {
         let url = format!("{}/indexes/{}/documents", self.client.host, self.uid);
         request::<&DocumentsQuery, DocumentsResults<T>>(
             &url,
-            &self.client.api_key,
+            &self.client,
             Method::Get(documents_query),
             200,
         )
         .await
     }
 
+    /// Write every document of the [Index] to `writer` as [NDJSON](https://github.com/ndjson/ndjson-spec), one document per line,
+    /// and get the number of documents written.
+    ///
+    /// The documents are fetched by pages of 1000 with [Index::get_documents_with], so they're never all in memory.
+    /// Unlike a [dump](Client::create_dump), it only exports the documents, which can be imported back with [Index::import_ndjson].
+    /// The fields of each document are written in alphabetical order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    id: usize,
+    /// #    name: String,
+    /// # }
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("export_ndjson");
+    /// # movies.add_documents(&[Movie { id: 1, name: "Carol".to_string() }, Movie { id: 2, name: "Moana".to_string() }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let mut backup = Vec::new();
+    /// let exported = movies.export_ndjson(&mut backup).await.unwrap();
+    ///
+    /// assert_eq!(exported, 2);
+    /// assert_eq!(String::from_utf8(backup).unwrap().lines().count(), 2);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn export_ndjson(&self, mut writer: impl Write) -> Result<usize, Error> {
+        let mut exported = 0;
+        loop {
+            let page = self
+                .get_documents_with::<Map<String, Value>>(
+                    DocumentsQuery::new(self)
+                        .with_offset(exported)
+                        .with_limit(NDJSON_BATCH_SIZE),
+                )
+                .await?;
+            for document in &page.results {
+                serde_json::to_writer(&mut writer, document)
+                    .map_err(|error| Error::Io(error.into()))?;
+                writer.write_all(b"\n").map_err(Error::Io)?;
+            }
+            exported += page.results.len();
+
+            if page.results.len() < NDJSON_BATCH_SIZE {
+                break;
+            }
+        }
+        writer.flush().map_err(Error::Io)?;
+
+        Ok(exported)
+    }
+
+    /// Add the documents of an [NDJSON](https://github.com/ndjson/ndjson-spec) `reader`, ie written by [Index::export_ndjson],
+    /// after applying `settings` if any, and get the tasks adding the documents.
+    ///
+    /// The settings are applied first and waited for, with the [wait policy](Index::with_wait_policy) of the index,
+    /// so the documents are indexed with them. If their task fails, its error is returned and no document is sent.
+    /// The documents are then read line by line and sent by batches of 1000, so they're never all in memory. The blank lines are skipped.
+    ///
+    /// A line that isn't a JSON object fails with [Error::InvalidNdjsonLine], giving its number counting from `1`.
+    /// The batches of the lines before it are already enqueued.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("import_ndjson");
+    ///
+    /// let ndjson = r#"{ "id": 1, "title": "Carol", "genre": "romance" }
+    /// { "id": 2, "title": "Moana", "genre": "adventure" }
+    /// "#;
+    /// let settings = Settings::new().with_filterable_attributes(["genre"]);
+    /// let tasks = movies
+    ///     .import_ndjson(ndjson.as_bytes(), Some(&settings), Some("id"))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(tasks.len(), 1);
+    /// # for task in tasks {
+    /// #     task.wait_for_completion(&client, None, None).await.unwrap();
+    /// # }
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn import_ndjson(
+        &self,
+        reader: impl Read,
+        settings: Option<&Settings>,
+        primary_key: Option<&str>,
+    ) -> Result<Vec<TaskInfo>, Error> {
+        if let Some(settings) = settings {
+            let task = self.set_settings(settings).await?;
+            if let Task::Failed { content } = self.wait_for_task(task, None, None).await? {
+                return Err(Error::Meilisearch(content.error));
+            }
+        }
+
+        let mut tasks = Vec::new();
+        let mut batch: Vec<Map<String, Value>> = Vec::with_capacity(NDJSON_BATCH_SIZE);
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line.map_err(Error::Io)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let document =
+                serde_json::from_str(&line).map_err(|source| Error::InvalidNdjsonLine {
+                    line: i + 1,
+                    source,
+                })?;
+            batch.push(document);
+
+            if batch.len() == NDJSON_BATCH_SIZE {
+                tasks.push(self.add_or_replace(&batch, primary_key).await?);
+                batch.clear();
+            }
+        }
+        if !batch.is_empty() {
+            tasks.push(self.add_or_replace(&batch, primary_key).await?);
+        }
+
+        Ok(tasks)
+    }
+
     /// Add a list of [Document]s or replace them if they already exist.
     ///
     /// If you send an already existing document (same id) the **whole existing document** will be overwritten by the new document.
@@ -531,7 +1201,14 @@ impl Index {
         } else {
             format!("{}/indexes/{}/documents", self.client.host, self.uid)
         };
-        request::<&[T], TaskInfo>(&url, &self.client.api_key, Method::Post(documents), 202).await
+        let task =
+            request::<&[T], TaskInfo>(&url, &self.client, Method::Post(documents), 202).await?;
+
+        #[cfg(feature = "metrics")]
+        self.client
+            .metrics
+            .record_documents_submitted(&url, &self.client.host, documents.len());
+        Ok(task)
     }
 
     /// Alias for [Index::add_or_replace].
@@ -543,12 +1220,158 @@ impl Index {
         self.add_or_replace(documents, primary_key).await
     }
 
-    /// Add a list of documents and update them if they already.
+    /// Like [Index::add_documents], but an empty `documents` sends no request and returns `None`,
+    /// instead of enqueuing a task that does nothing and clutters the task history.
     ///
-    /// If you send an already existing document (same id) the old document will be only partially updated according to the fields of the new document.
-    /// Thus, any fields not present in the new document are kept and remained unchanged.
+    /// # Example
     ///
-    /// To completely overwrite a document, check out the [Index::add_or_replace] documents method.
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     name: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movie_index = client.index("add_documents_if_any");
+    ///
+    /// let task = movie_index.add_documents_if_any::<Movie>(&[], None).await.unwrap();
+    /// assert!(task.is_none());
+    /// # });
+    /// ```
+    pub async fn add_documents_if_any<T: Serialize>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<Option<TaskInfo>, Error> {
+        if documents.is_empty() {
+            return Ok(None);
+        }
+        self.add_or_replace(documents, primary_key).await.map(Some)
+    }
+
+    /// Like [Index::add_documents], but first check the fields reserved by Meilisearch with [validate_reserved_fields],
+    /// failing with [Error::InvalidReservedField] without sending anything if one of the documents has an invalid `_geo` or `_vectors`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use serde_json::{json, Map, Value};
+    /// # use meilisearch_sdk::{client::*, errors::Error, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Restaurant {
+    ///     id: usize,
+    ///     name: String,
+    ///     #[serde(flatten)]
+    ///     extra: Map<String, Value>,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let restaurants = client.index("add_documents_validated");
+    ///
+    /// let mut extra = Map::new();
+    /// extra.insert("_geo".to_string(), json!({ "lat": 45.47, "long": 9.18 }));
+    /// let error = restaurants
+    ///     .add_documents_validated(&[Restaurant { id: 1, name: "Pizza".to_string(), extra }], Some("id"))
+    ///     .await
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(error, Error::InvalidReservedField { document: 0, field, .. } if field == "_geo"));
+    /// # });
+    /// ```
+    pub async fn add_documents_validated<T: Serialize>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        validate_reserved_fields(documents)?;
+        self.add_or_replace(documents, primary_key).await
+    }
+
+    /// Like [Index::add_or_update], but an empty `documents` sends no request and returns `None`.
+    pub async fn update_documents_if_any<T: Serialize>(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<Option<TaskInfo>, Error> {
+        if documents.is_empty() {
+            return Ok(None);
+        }
+        self.add_or_update(documents, primary_key).await.map(Some)
+    }
+
+    /// Add documents, wait for Meilisearch to index them, then search the index, so the results include them.
+    ///
+    /// It's a convenience for tests and small applications, which add a document then look for it right away:
+    /// the documents are added asynchronously and [Index::add_documents] returns before they're searchable.
+    /// The primary key is inferred, and the task is awaited with the default interval and timeout of [Index::wait_for_task].
+    /// A failed task is returned as an [Error::Meilisearch].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     name: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("add_documents_and_search");
+    ///
+    /// let results = movies
+    ///     .add_documents_and_search::<_, Movie>(
+    ///         &[Movie { id: 1, name: String::from("Interstellar") }],
+    ///         &movies.search().with_query("interstellar").build(),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(results.hits[0].result.name, "Interstellar");
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_and_search<D: Serialize, T: 'static + DeserializeOwned>(
+        &self,
+        documents: &[D],
+        query: &SearchParams<'_>,
+    ) -> Result<SearchResults<T>, Error> {
+        let task = self
+            .add_documents(documents, None)
+            .await?
+            .wait_for_completion(&self.client, None, None)
+            .await?;
+        if task.is_failure() {
+            return Err(Error::Meilisearch(task.unwrap_failure()));
+        }
+        self.client.execute_search(&self.uid, query).await
+    }
+
+    /// Add a list of documents and update them if they already.
+    ///
+    /// If you send an already existing document (same id) the old document will be only partially updated according to the fields of the new document.
+    /// Thus, any fields not present in the new document are kept and remained unchanged.
+    ///
+    /// To completely overwrite a document, check out the [Index::add_or_replace] documents method.
     ///
     /// # Example
     ///
@@ -611,7 +1434,14 @@ impl Index {
         } else {
             format!("{}/indexes/{}/documents", self.client.host, self.uid)
         };
-        request::<&[T], TaskInfo>(&url, &self.client.api_key, Method::Put(documents), 202).await
+        let task =
+            request::<&[T], TaskInfo>(&url, &self.client, Method::Put(documents), 202).await?;
+
+        #[cfg(feature = "metrics")]
+        self.client
+            .metrics
+            .record_documents_submitted(&url, &self.client.host, documents.len());
+        Ok(task)
     }
 
     /// Delete all documents in the index.
@@ -654,13 +1484,58 @@ impl Index {
     pub async fn delete_all_documents(&self) -> Result<TaskInfo, Error> {
         request::<(), TaskInfo>(
             &format!("{}/indexes/{}/documents", self.client.host, self.uid),
-            &self.client.api_key,
+            &self.client,
             Method::Delete,
             202,
         )
         .await
     }
 
+    /// Delete all the documents of the index and wait for the deletion to be processed.
+    ///
+    /// `interval` and `timeout` are used as in [Index::wait_for_task].
+    /// Use [Index::delete_all_documents] to only enqueue the deletion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("clear_documents_and_wait");
+    /// # movies.add_documents(&[json!({ "id": 1 })], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let task = movies.clear_documents_and_wait(None, None).await.unwrap();
+    /// assert!(task.is_success());
+    /// assert_eq!(movies.get_stats().await.unwrap().number_of_documents, 0);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn clear_documents_and_wait(
+        &self,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.delete_all_documents()
+            .await?
+            .wait_for_completion(&self.client, interval, timeout)
+            .await
+    }
+
+    /// Alias of [Index::clear_documents_and_wait].
+    pub async fn truncate(
+        &self,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Task, Error> {
+        self.clear_documents_and_wait(interval, timeout).await
+    }
+
     /// Delete one document based on its unique id.
     ///
     /// # Example
@@ -702,13 +1577,77 @@ impl Index {
                 "{}/indexes/{}/documents/{}",
                 self.client.host, self.uid, uid
             ),
-            &self.client.api_key,
+            &self.client,
             Method::Delete,
             202,
         )
         .await
     }
 
+    /// Delete one document, wait for the deletion to be processed, and report whether the document existed.
+    ///
+    /// Meilisearch doesn't fail when deleting a missing document, so [DeletionOutcome::existed] is computed
+    /// from the number of deleted documents reported by the task.
+    ///
+    /// `interval` and `timeout` are used as in [Index::wait_for_task].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # #[derive(Serialize, Deserialize, Debug)]
+    /// # struct Movie {
+    /// #    id: usize,
+    /// #    name: String,
+    /// # }
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("delete_document_and_wait");
+    /// # movies.add_documents(&[Movie { id: 1, name: String::from("Interstellar") }], Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let outcome = movies.delete_document_and_wait(1, None, None).await.unwrap();
+    /// assert!(outcome.existed);
+    ///
+    /// let outcome = movies.delete_document_and_wait(1, None, None).await.unwrap();
+    /// assert!(!outcome.existed);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn delete_document_and_wait(
+        &self,
+        document_id: impl Into<DocumentId>,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<DeletionOutcome, Error> {
+        let task = self
+            .delete_document(document_id.into())
+            .await?
+            .wait_for_completion(&self.client, interval, timeout)
+            .await?;
+
+        let existed = matches!(
+            &task,
+            Task::Succeeded {
+                content: SucceededTask {
+                    update_type: TaskType::DocumentDeletion {
+                        details: Some(DocumentDeletion {
+                            deleted_documents: Some(deleted),
+                        }),
+                    },
+                    ..
+                },
+            } if *deleted > 0
+        );
+
+        Ok(DeletionOutcome { existed, task })
+    }
+
     /// Delete a selection of documents based on array of document id's.
     ///
     /// # Example
@@ -754,21 +1693,53 @@ impl Index {
                 "{}/indexes/{}/documents/delete-batch",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
+            &self.client,
             Method::Post(uids),
             202,
         )
         .await
     }
 
-    /// Alias for the [Index::update] method.
+    /// Update the primary key of the index, like [Index::update] with this primary key.
+    ///
+    /// The task isn't waited for, so the cached [primary key](Index::primary_key) is updated as soon as the task is enqueued,
+    /// even if the task fails later, ie because the index already has documents.
+    /// Use [Index::set_primary_key_and_wait] to only update the cache once the task succeeded.
     pub async fn set_primary_key(
         &mut self,
         primary_key: impl AsRef<str>,
     ) -> Result<TaskInfo, Error> {
+        let task = IndexUpdater::new(&*self, &self.client)
+            .with_primary_key(primary_key.as_ref())
+            .execute()
+            .await?;
+
         self.primary_key = Some(primary_key.as_ref().to_string());
+        Ok(task)
+    }
+
+    /// Like [Index::set_primary_key], but wait for the update task and only update the cached [primary key](Index::primary_key) once it succeeded.
+    ///
+    /// If the task failed, ie because the index already has documents, its error is returned and the cache is kept.
+    pub async fn set_primary_key_and_wait(
+        &mut self,
+        primary_key: impl AsRef<str>,
+    ) -> Result<Task, Error> {
+        let task = IndexUpdater::new(&*self, &self.client)
+            .with_primary_key(primary_key.as_ref())
+            .execute()
+            .await?;
 
-        self.update().await
+        match self.wait_for_task(task, None, None).await? {
+            Task::Failed { content } => Err(Error::Meilisearch(content.error)),
+            task => {
+                self.primary_key = Some(primary_key.as_ref().to_string());
+                self.client
+                    .primary_keys_cache
+                    .insert(&self.uid, primary_key.as_ref().to_string());
+                Ok(task)
+            }
+        }
     }
 
     /// Fetch the information of the index as a raw JSON [Index], this index should already exist.
@@ -825,6 +1796,59 @@ impl Index {
         Ok(self.primary_key.as_deref())
     }
 
+    /// The primary key of the index known by this [Index], without doing any HTTP call.
+    ///
+    /// It is known when the index was fetched from Meilisearch, or after [Index::set_primary_key] or [Index::get_primary_key].
+    /// Use [Index::primary_key_or_fetch] to fetch it when it's unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let mut movies = client.index("movies");
+    /// assert_eq!(movies.primary_key(), None);
+    ///
+    /// movies.primary_key = Some(String::from("movie_id"));
+    /// assert_eq!(movies.primary_key(), Some("movie_id"));
+    /// ```
+    pub fn primary_key(&self) -> Option<&str> {
+        self.primary_key.as_deref()
+    }
+
+    /// Get the [primary key](Index::primary_key) of the index, and fetch it from Meilisearch only when it's unknown.
+    ///
+    /// Returns [Error::MissingPrimaryKey] when the index doesn't have a primary key yet,
+    /// ie when no document was added to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("primary_key_or_fetch", Some("movie_id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut movies = client.index("primary_key_or_fetch");
+    ///
+    /// assert_eq!(movies.primary_key_or_fetch().await.unwrap(), "movie_id");
+    /// // the primary key is now known
+    /// assert_eq!(movies.primary_key(), Some("movie_id"));
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn primary_key_or_fetch(&mut self) -> Result<String, Error> {
+        if self.primary_key.is_none() {
+            self.fetch_info().await?;
+        }
+        self.primary_key
+            .clone()
+            .ok_or_else(|| Error::MissingPrimaryKey(self.uid.clone()))
+    }
+
     /// Get a [Task] from a specific [Index] to keep track of [asynchronous operations](https://docs.meilisearch.com/learn/advanced/asynchronous_operations.html).
     ///
     /// # Example
@@ -872,7 +1896,7 @@ impl Index {
     pub async fn get_task(&self, uid: impl AsRef<u32>) -> Result<Task, Error> {
         request::<(), Task>(
             &format!("{}/tasks/{}", self.client.host, uid.as_ref()),
-            &self.client.api_key,
+            &self.client,
             Method::Get(()),
             200,
         )
@@ -962,7 +1986,7 @@ impl Index {
     pub async fn get_stats(&self) -> Result<IndexStats, Error> {
         request::<(), IndexStats>(
             &format!("{}/indexes/{}/stats", self.client.host, self.uid),
-            &self.client.api_key,
+            &self.client,
             Method::Get(()),
             200,
         )
@@ -971,8 +1995,8 @@ impl Index {
 
     /// Wait until Meilisearch processes a [Task], and get its status.
     ///
-    /// `interval` = The frequency at which the server should be polled. Default = 50ms
-    /// `timeout` = The maximum time to wait for processing to complete. Default = 5000ms
+    /// `interval` = The frequency at which the server should be polled. Default = the [wait policy](Index::wait_policy) of the index, 50ms
+    /// `timeout` = The maximum time to wait for processing to complete. Default = the [wait policy](Index::wait_policy) of the index, 5000ms
     ///
     /// If the waited time exceeds `timeout` then an [Error::Timeout] will be returned.
     ///
@@ -1025,6 +2049,8 @@ impl Index {
     /// `batch_size` = Optional parameter that allows you to specify the size of the batch
     /// `batch_size` is 1000 by default
     ///
+    /// An empty `documents` sends no request and returns no task.
+    ///
     /// # Example
     ///
     /// ```
@@ -1084,12 +2110,63 @@ impl Index {
         Ok(task)
     }
 
+    /// Add documents to the index in batches whose JSON body stays under `target_batch_bytes`.
+    ///
+    /// Unlike [Index::add_documents_in_batches], the batches are sized by the serialized documents rather than by count,
+    /// which keeps the requests even when mixing tiny and huge documents.
+    /// A document that is bigger than `target_batch_bytes` on its own is sent alone.
+    /// An empty `documents` sends no request and returns no task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Serialize, Deserialize};
+    /// use meilisearch_sdk::client::*;
+    ///
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     name: String,
+    ///     description: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movie_index = client.index("add_documents_auto_batched");
+    ///
+    /// let tasks = movie_index.add_documents_auto_batched(&[
+    ///     Movie { name: String::from("Interstellar"), description: String::from("A group of explorers travel through a wormhole.") },
+    ///     Movie { name: String::from("Apollo13"), description: String::from("The true story of the Apollo 13 lunar mission.") },
+    /// ], 100, Some("name")).await.unwrap();
+    ///
+    /// client.wait_for_task(tasks.last().unwrap(), None, None).await.unwrap();
+    /// # movie_index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn add_documents_auto_batched<T: Serialize>(
+        &self,
+        documents: &[T],
+        target_batch_bytes: usize,
+        primary_key: Option<&str>,
+    ) -> Result<Vec<TaskInfo>, Error> {
+        let mut tasks = Vec::new();
+        for document_batch in batches_by_size(documents, target_batch_bytes)? {
+            tasks.push(self.add_documents(document_batch, primary_key).await?);
+        }
+        Ok(tasks)
+    }
+
     /// Update documents to the index in batches
     ///
     /// `documents` = A slice of documents
     /// `batch_size` = Optional parameter that allows you to specify the size of the batch
     /// `batch_size` is 1000 by default
     ///
+    /// An empty `documents` sends no request and returns no task.
+    ///
     /// # Example
     ///
     /// ```
@@ -1175,27 +2252,218 @@ impl Index {
     }
 }
 
+/// Split `documents` into consecutive batches whose serialized JSON array is at most `target_bytes` long,
+/// except for the documents bigger than `target_bytes` which get a batch of their own.
+fn batches_by_size<T: Serialize>(documents: &[T], target_bytes: usize) -> Result<Vec<&[T]>, Error> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+    // the size of the `[]` around the documents
+    let mut batch_bytes = 2;
+
+    for (i, document) in documents.iter().enumerate() {
+        let document_bytes = serde_json::to_vec(document)
+            .map_err(|e| Error::parse_error::<T>(String::new(), e))?
+            .len();
+        // the documents after the first one are preceded by a `,`
+        let added_bytes = if i == start {
+            document_bytes
+        } else {
+            document_bytes + 1
+        };
+
+        if i > start && batch_bytes + added_bytes > target_bytes {
+            batches.push(&documents[start..i]);
+            start = i;
+            batch_bytes = 2 + document_bytes;
+        } else {
+            batch_bytes += added_bytes;
+        }
+    }
+    if start < documents.len() {
+        batches.push(&documents[start..]);
+    }
+
+    Ok(batches)
+}
+
 impl AsRef<str> for Index {
     fn as_ref(&self) -> &str {
         &self.uid
     }
 }
 
-/// An [IndexUpdater] used to update the specifics of an index
+/// An [Index] bound to the type of the documents it contains.
+///
+/// All the document methods use `T`, so there is no need to specify the type of the documents on every call.
+/// All the other methods of [Index] are available through [Deref].
 ///
 /// # Example
 ///
 /// ```
-/// # use meilisearch_sdk::{client::*, indexes::*, task_info::*, tasks::{Task, SucceededTask}};
+/// # use serde::{Serialize, Deserialize};
+/// # use meilisearch_sdk::{client::*, indexes::*, search::*};
 /// #
 /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
 /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
 /// #
+/// #[derive(Serialize, Deserialize, Debug)]
+/// struct Movie {
+///     id: String,
+///     name: String,
+/// }
+///
 /// # futures::executor::block_on(async move {
-/// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-/// # let index = client
-/// #   .create_index("index_updater", None)
-/// #   .await
+/// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+/// let movies = client.typed_index::<Movie>("typed_index");
+///
+/// movies.add_documents(&[Movie { id: String::from("1"), name: String::from("Interstellar") }], Some("id"))
+///     .await
+///     .unwrap()
+///     .wait_for_completion(&client, None, None)
+///     .await
+///     .unwrap();
+///
+/// let movie = movies.get_document("1").await.unwrap();
+/// let results = movies.search(SearchParams::new().with_query("Interstellar")).await.unwrap();
+/// assert_eq!(movie.name, results.hits[0].result.name);
+/// # movies.into_inner().delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+/// # });
+/// ```
+pub struct TypedIndex<T> {
+    index: Index,
+    document_type: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static + Serialize + DeserializeOwned> TypedIndex<T> {
+    pub fn new(index: Index) -> TypedIndex<T> {
+        TypedIndex {
+            index,
+            document_type: PhantomData,
+        }
+    }
+
+    /// Get back the untyped [Index].
+    pub fn into_inner(self) -> Index {
+        self.index
+    }
+
+    /// See [Index::add_or_replace].
+    pub async fn add_or_replace(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.index.add_or_replace(documents, primary_key).await
+    }
+
+    /// See [Index::add_documents].
+    pub async fn add_documents(
+        &self,
+        documents: &[T],
+        primary_key: Option<&str>,
+    ) -> Result<TaskInfo, Error> {
+        self.index.add_documents(documents, primary_key).await
+    }
+
+    /// See [Index::add_or_update].
+    pub async fn add_or_update(
+        &self,
+        documents: &[T],
+        primary_key: Option<impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        self.index.add_or_update(documents, primary_key).await
+    }
+
+    /// See [Index::get_document].
+    pub async fn get_document(&self, document_id: &str) -> Result<T, Error> {
+        self.index.get_document::<T>(document_id).await
+    }
+
+    /// See [Index::get_document_with].
+    pub async fn get_document_with(
+        &self,
+        document_id: &str,
+        document_query: &DocumentQuery<'_>,
+    ) -> Result<T, Error> {
+        self.index
+            .get_document_with::<T>(document_id, document_query)
+            .await
+    }
+
+    /// See [Index::get_documents].
+    pub async fn get_documents(&self) -> Result<DocumentsResults<T>, Error> {
+        self.index.get_documents::<T>().await
+    }
+
+    /// See [Index::get_documents_with].
+    pub async fn get_documents_with(
+        &self,
+        documents_query: &DocumentsQuery<'_>,
+    ) -> Result<DocumentsResults<T>, Error> {
+        self.index.get_documents_with::<T>(documents_query).await
+    }
+
+    /// See [Index::execute_query].
+    pub async fn execute_query(&self, query: &SearchQuery<'_>) -> Result<SearchResults<T>, Error> {
+        self.index.execute_query::<T>(query).await
+    }
+
+    /// Search for documents matching the given parameters.\
+    /// Use [Index::search] through [Deref] to build a [SearchQuery] instead.
+    pub async fn search(&self, params: SearchParams<'_>) -> Result<SearchResults<T>, Error> {
+        self.index
+            .client
+            .execute_search::<T>(&self.index.uid, &params)
+            .await
+    }
+}
+
+impl<T> Deref for TypedIndex<T> {
+    type Target = Index;
+
+    fn deref(&self) -> &Index {
+        &self.index
+    }
+}
+
+impl<T> Clone for TypedIndex<T> {
+    fn clone(&self) -> TypedIndex<T> {
+        TypedIndex {
+            index: self.index.clone(),
+            document_type: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for TypedIndex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedIndex")
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<T> AsRef<str> for TypedIndex<T> {
+    fn as_ref(&self) -> &str {
+        &self.index.uid
+    }
+}
+
+/// An [IndexUpdater] used to update the specifics of an index
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::{client::*, indexes::*, task_info::*, tasks::{Task, SucceededTask}};
+/// #
+/// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+/// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+/// #
+/// # futures::executor::block_on(async move {
+/// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+/// # let index = client
+/// #   .create_index("index_updater", None)
+/// #   .await
 /// #   .unwrap()
 /// #   .wait_for_completion(&client, None, None)
 /// #   .await
@@ -1318,7 +2586,7 @@ impl<'a> IndexUpdater<'a> {
     pub async fn execute(&'a self) -> Result<TaskInfo, Error> {
         request::<&IndexUpdater, TaskInfo>(
             &format!("{}/indexes/{}", self.client.host, self.uid),
-            &self.client.api_key,
+            self.client,
             Method::Patch(self),
             202,
         )
@@ -1343,7 +2611,99 @@ impl<'a> AsRef<IndexUpdater<'a>> for IndexUpdater<'a> {
 pub struct IndexStats {
     pub number_of_documents: usize,
     pub is_indexing: bool,
-    pub field_distribution: HashMap<String, usize>,
+    pub field_distribution: FieldDistribution,
+}
+
+impl IndexStats {
+    /// The share of the documents having the field, between `0.0` and `1.0`.
+    /// It's `0.0` when the index is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::indexes::IndexStats;
+    /// let stats: IndexStats = serde_json::from_str(r#"{
+    ///     "numberOfDocuments": 4,
+    ///     "isIndexing": false,
+    ///     "fieldDistribution": { "id": 4, "poster": 3 }
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(stats.coverage_of("poster"), 0.75);
+    /// assert_eq!(stats.coverage_of("director"), 0.0);
+    /// ```
+    pub fn coverage_of(&self, field: &str) -> f64 {
+        if self.number_of_documents == 0 {
+            return 0.0;
+        }
+        self.field_distribution.count_of(field) as f64 / self.number_of_documents as f64
+    }
+}
+
+/// The number of documents having each field of an index, see [IndexStats::field_distribution].
+///
+/// The map is available through [Deref], or with [FieldDistribution::into_inner].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct FieldDistribution(HashMap<String, usize>);
+
+impl FieldDistribution {
+    pub fn into_inner(self) -> HashMap<String, usize> {
+        self.0
+    }
+
+    /// The number of documents having the field, `0` when no document has it.
+    pub fn count_of(&self, field: &str) -> usize {
+        self.0.get(field).copied().unwrap_or_default()
+    }
+
+    /// The fields of `expected` that no document has, in the order of `expected`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::indexes::IndexStats;
+    /// let stats: IndexStats = serde_json::from_str(r#"{
+    ///     "numberOfDocuments": 2,
+    ///     "isIndexing": false,
+    ///     "fieldDistribution": { "id": 2, "title": 2, "legacy_rating": 1 }
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(stats.field_distribution.fields_missing_from(&["id", "title", "genres"]), ["genres"]);
+    /// assert_eq!(stats.field_distribution.unexpected_fields(&["id", "title", "genres"]), ["legacy_rating"]);
+    /// ```
+    pub fn fields_missing_from<'a>(&self, expected: &[&'a str]) -> Vec<&'a str> {
+        expected
+            .iter()
+            .filter(|field| !self.0.contains_key(**field))
+            .copied()
+            .collect()
+    }
+
+    /// The fields some documents have that are not part of `expected`, sorted.
+    pub fn unexpected_fields(&self, expected: &[&str]) -> Vec<&str> {
+        let mut unexpected: Vec<&str> = self
+            .0
+            .keys()
+            .map(String::as_str)
+            .filter(|field| !expected.contains(field))
+            .collect();
+        unexpected.sort_unstable();
+        unexpected
+    }
+}
+
+impl Deref for FieldDistribution {
+    type Target = HashMap<String, usize>;
+
+    fn deref(&self) -> &HashMap<String, usize> {
+        &self.0
+    }
+}
+
+impl From<FieldDistribution> for HashMap<String, usize> {
+    fn from(field_distribution: FieldDistribution) -> Self {
+        field_distribution.0
+    }
 }
 
 // An [IndexesQuery] containing filter and pagination parameters when searching for [Index]es
@@ -1509,7 +2869,7 @@ impl<'a> IndexesQuery<'a> {
     /// # });
     /// ```
     pub async fn execute(&self) -> Result<IndexesResults, Error> {
-        self.client.list_all_indexes_with(self).await
+        self.client.get_indexes_with(self).await
     }
 }
 
@@ -1525,6 +2885,11 @@ pub struct IndexesResults {
 mod tests {
     use super::*;
 
+    use crate::{
+        client::ClientBuilder,
+        clock::{Clock, MockClock},
+        errors::{ErrorCode, MeilisearchError},
+    };
     use meilisearch_test_macro::meilisearch_test;
     use serde_json::json;
 
@@ -1560,6 +2925,934 @@ mod tests {
         assert_eq!(res.client.api_key, idx.client.api_key);
     }
 
+    #[meilisearch_test]
+    async fn test_get_similar_raw() -> Result<(), Error> {
+        let mock_server_url = &mockito::server_url();
+        let client = Client::new(mock_server_url, "masterKey");
+        let index = client.index("movies");
+
+        let mock_res = mockito::mock("POST", "/indexes/movies/similar")
+            .match_body(mockito::Matcher::Json(
+                json!({ "id": "42", "embedder": "default" }),
+            ))
+            .with_status(200)
+            .with_body(r#"{ "hits": [], "id": "42" }"#)
+            .create();
+
+        let similar = index.get_similar_raw(42, "default").await?;
+
+        mock_res.assert();
+        assert_eq!(similar["id"], "42");
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_primary_key_cache() -> Result<(), Error> {
+        let client = Client::new(
+            format!("{}/primary_key_cache", mockito::server_url()),
+            "masterKey",
+        );
+        let mut index = client.index("primary_key_cache");
+
+        let fetch = mockito::mock("GET", "/primary_key_cache/indexes/primary_key_cache")
+            .with_status(200)
+            .with_body(r#"{ "uid": "primary_key_cache", "createdAt": "2022-02-03T15:17:02.801341Z", "updatedAt": "2022-02-03T15:17:02.801341Z", "primaryKey": "id" }"#)
+            .expect(1)
+            .create();
+        assert_eq!(index.primary_key(), None);
+        assert_eq!(index.primary_key_or_fetch().await?, "id");
+        // the second call uses the cached primary key
+        assert_eq!(index.primary_key_or_fetch().await?, "id");
+        assert_eq!(index.primary_key(), Some("id"));
+        fetch.assert();
+
+        let update = mockito::mock("PATCH", "/primary_key_cache/indexes/primary_key_cache")
+            .match_body(mockito::Matcher::Json(json!({ "primaryKey": "movie_id" })))
+            .with_status(202)
+            .with_body(r#"{ "enqueuedAt": "2022-02-03T15:17:02.801341Z", "indexUid": "primary_key_cache", "status": "enqueued", "type": "indexUpdate", "taskUid": 3 }"#)
+            .create();
+        let succeeded = mockito::mock("GET", "/primary_key_cache/tasks/3")
+            .with_status(200)
+            .with_body(r#"{ "details": { "primaryKey": "movie_id" }, "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "finishedAt": "2022-02-03T15:17:02.811341Z", "indexUid": "primary_key_cache", "startedAt": "2022-02-03T15:17:02.802338Z", "status": "succeeded", "type": "indexUpdate", "uid": 3 }"#)
+            .expect(1)
+            .create();
+        let task = index.set_primary_key_and_wait("movie_id").await?;
+        assert!(task.is_success());
+        assert_eq!(*task.as_ref(), 3);
+        assert_eq!(index.primary_key(), Some("movie_id"));
+        update.assert();
+        succeeded.assert();
+
+        // the cache is kept when the update task fails
+        let _refused = mockito::mock("PATCH", "/primary_key_cache/indexes/primary_key_cache")
+            .match_body(mockito::Matcher::Json(json!({ "primaryKey": "uid" })))
+            .with_status(202)
+            .with_body(r#"{ "enqueuedAt": "2022-02-03T15:17:02.801341Z", "indexUid": "primary_key_cache", "status": "enqueued", "type": "indexUpdate", "taskUid": 4 }"#)
+            .create();
+        let failed = mockito::mock("GET", "/primary_key_cache/tasks/4")
+            .with_status(200)
+            .with_body(r#"{ "details": { "primaryKey": "uid" }, "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "finishedAt": "2022-02-03T15:17:02.811341Z", "indexUid": "primary_key_cache", "startedAt": "2022-02-03T15:17:02.802338Z", "status": "failed", "type": "indexUpdate", "uid": 4, "error": { "message": "Index `primary_key_cache` already has a primary key: `movie_id`.", "code": "index_primary_key_already_present", "type": "invalid_request", "link": "https://docs.meilisearch.com/errors#index_primary_key_already_present" } }"#)
+            .expect(1)
+            .create();
+        assert!(matches!(
+            index.set_primary_key_and_wait("uid").await,
+            Err(Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::IndexPrimaryKeyAlreadyPresent,
+                ..
+            }))
+        ));
+        assert_eq!(index.primary_key(), Some("movie_id"));
+        failed.assert();
+
+        // without waiting, the task is returned and the cache is updated right away
+        let not_waited = mockito::mock("GET", "/primary_key_cache/tasks/4")
+            .expect(0)
+            .create();
+        assert_eq!(index.set_primary_key("uid").await?.task_uid, 4);
+        assert_eq!(index.primary_key(), Some("uid"));
+        not_waited.assert();
+
+        let _missing = mockito::mock("GET", "/primary_key_cache/indexes/primary_key_missing")
+            .with_status(200)
+            .with_body(r#"{ "uid": "primary_key_missing", "createdAt": "2022-02-03T15:17:02.801341Z", "updatedAt": "2022-02-03T15:17:02.801341Z", "primaryKey": null }"#)
+            .create();
+        let mut index = client.index("primary_key_missing");
+        assert!(matches!(
+            index.primary_key_or_fetch().await,
+            Err(Error::MissingPrimaryKey(uid)) if uid == "primary_key_missing"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_distribution() {
+        let stats: IndexStats = serde_json::from_str(
+            r#"{
+                "numberOfDocuments": 8,
+                "isIndexing": false,
+                "fieldDistribution": { "id": 8, "title": 8, "poster": 6, "release_date": 2, "old_rating": 1 }
+            }"#,
+        )
+        .unwrap();
+        let fields = &stats.field_distribution;
+
+        assert_eq!(
+            fields.fields_missing_from(&["genres", "id", "overview", "title"]),
+            ["genres", "overview"]
+        );
+        assert!(fields.fields_missing_from(&["id", "title"]).is_empty());
+        assert_eq!(
+            fields.unexpected_fields(&["id", "title", "poster"]),
+            ["old_rating", "release_date"]
+        );
+        assert_eq!(stats.coverage_of("id"), 1.0);
+        assert_eq!(stats.coverage_of("poster"), 0.75);
+        assert_eq!(stats.coverage_of("release_date"), 0.25);
+        assert_eq!(stats.coverage_of("genres"), 0.0);
+
+        // the raw map is still available
+        assert_eq!(fields["poster"], 6);
+        assert_eq!(fields.len(), 5);
+        let raw: HashMap<String, usize> = stats.field_distribution.into_inner();
+        assert_eq!(raw["old_rating"], 1);
+
+        let empty: IndexStats = serde_json::from_str(
+            r#"{ "numberOfDocuments": 0, "isIndexing": true, "fieldDistribution": {} }"#,
+        )
+        .unwrap();
+        assert_eq!(empty.coverage_of("id"), 0.0);
+    }
+
+    #[test]
+    fn test_try_make_index_knows_the_primary_key() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let task: Task = serde_json::from_str(
+            r#"{ "details": { "primaryKey": "movie_id" }, "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "finishedAt": "2022-02-03T15:17:02.811341Z", "indexUid": "movies", "startedAt": "2022-02-03T15:17:02.802338Z", "status": "succeeded", "type": "indexCreation", "uid": 1 }"#,
+        )
+        .unwrap();
+
+        let index = task.try_make_index(&client).unwrap();
+        assert_eq!(index.primary_key(), Some("movie_id"));
+    }
+
+    #[meilisearch_test]
+    async fn test_index_wait_policy() {
+        let clock = MockClock::new();
+        let client = ClientBuilder::new(
+            format!("{}/index_wait_policy", mockito::server_url()),
+            "masterKey",
+        )
+        .with_clock(clock.clone())
+        .with_wait_policy(WaitPolicy::new(
+            Duration::from_secs(1),
+            Duration::from_secs(4),
+        ))
+        .build();
+        let task = json!({
+            "uid": 12,
+            "indexUid": "catalog",
+            "status": "processing",
+            "type": "documentDeletion",
+            "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+            "startedAt": "2022-02-03T15:17:02.812338Z"
+        });
+        let _processing = mockito::mock("GET", "/index_wait_policy/tasks/12")
+            .with_status(200)
+            .with_body(task.to_string())
+            .create();
+        let task: Task = serde_json::from_value(task).unwrap();
+
+        let catalog = client.index("catalog").with_wait_policy(WaitPolicy::new(
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+        ));
+        let error = catalog.wait_for_task(&task, None, None).await.unwrap_err();
+        assert!(matches!(error, Error::Timeout));
+        assert_eq!(clock.now(), Duration::from_secs(60));
+
+        // the clones keep the policy, the other indexes use the one of the client
+        let error = catalog
+            .clone()
+            .wait_for_task(&task, None, Some(Duration::from_secs(20)))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Timeout));
+        assert_eq!(clock.now(), Duration::from_secs(80));
+
+        let error = client
+            .index("catalog")
+            .wait_for_task(&task, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Timeout));
+        assert_eq!(clock.now(), Duration::from_secs(84));
+        assert_eq!(
+            client.index("catalog").wait_policy().interval,
+            Duration::from_secs(1)
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_base_filter() {
+        let client = Client::new(
+            format!("{}/base_filter", mockito::server_url()),
+            "masterKey",
+        );
+        let body = r#"{ "hits": [], "offset": 0, "limit": 20, "estimatedTotalHits": 0, "processingTimeMs": 1, "query": "" }"#;
+        let mock_filter = |filter: Value| {
+            mockito::mock("POST", "/base_filter/indexes/orders/search")
+                .match_body(mockito::Matcher::Json(json!({ "filter": filter })))
+                .with_status(200)
+                .with_body(body)
+                .expect(1)
+                .create()
+        };
+        let tenant = client.index("orders").with_base_filter("tenant_id = 42");
+
+        // the base filter is sent alone, or combined with the filter of the query
+        let alone = mock_filter(json!(["tenant_id = 42"]));
+        tenant.search().execute::<Value>().await.unwrap();
+        alone.assert();
+
+        let combined = mock_filter(json!(["tenant_id = 42", "status = paid"]));
+        tenant
+            .search()
+            .with_filter("status = paid")
+            .execute::<Value>()
+            .await
+            .unwrap();
+        combined.assert();
+
+        // a query can't override it, and another base filter is added to it
+        let narrowed = mock_filter(json!([
+            "tenant_id = 42",
+            "region = eu",
+            "tenant_id = 7 OR tenant_id = 42",
+            "status = paid"
+        ]));
+        tenant
+            .with_base_filter("region = eu")
+            .search()
+            .with_array_filter(vec!["tenant_id = 7 OR tenant_id = 42", "status = paid"])
+            .execute::<Value>()
+            .await
+            .unwrap();
+        narrowed.assert();
+
+        // a cacheable search is still sent in the body, where a filter can't escape the base filter
+        let injection = mock_filter(json!([
+            "tenant_id = 42",
+            "status = paid) OR (tenant_id = 7"
+        ]));
+        let get = mockito::mock("GET", "/base_filter/indexes/orders/search")
+            .match_query(mockito::Matcher::Any)
+            .expect(0)
+            .create();
+        tenant
+            .search()
+            .with_filter("status = paid) OR (tenant_id = 7")
+            .with_cacheable(true)
+            .execute::<Value>()
+            .await
+            .unwrap();
+        injection.assert();
+        get.assert();
+
+        // the facet searches and the similar documents are filtered the same way
+        let facet_search = mockito::mock("POST", "/base_filter/indexes/orders/facet-search")
+            .match_body(mockito::Matcher::Json(json!({
+                "facetName": "status",
+                "filter": ["tenant_id = 42", "status != refunded"]
+            })))
+            .with_status(200)
+            .with_body(r#"{ "facetHits": [], "facetQuery": null, "processingTimeMs": 1 }"#)
+            .expect(2)
+            .create();
+        tenant
+            .facet_search(&FacetSearchParams::new("status").with_filter("status != refunded"))
+            .await
+            .unwrap();
+        tenant
+            .facet_search_many(
+                &[FacetSearchParams::new("status").with_filter("status != refunded")],
+            )
+            .await
+            .unwrap();
+        facet_search.assert();
+
+        let similar = mockito::mock("POST", "/base_filter/indexes/orders/similar")
+            .match_body(mockito::Matcher::Json(json!({
+                "id": "1",
+                "embedder": "default",
+                "filter": ["tenant_id = 42"]
+            })))
+            .with_status(200)
+            .with_body(r#"{ "hits": [] }"#)
+            .expect(1)
+            .create();
+        tenant.get_similar_raw(1, "default").await.unwrap();
+        similar.assert();
+
+        // the other indexes of the client are not filtered
+        let unfiltered = mockito::mock("POST", "/base_filter/indexes/orders/search")
+            .match_body(mockito::Matcher::Json(json!({})))
+            .with_status(200)
+            .with_body(body)
+            .expect(1)
+            .create();
+        client
+            .index("orders")
+            .search()
+            .execute::<Value>()
+            .await
+            .unwrap();
+        unfiltered.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_search_rejects_invalid_vector() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("movies");
+
+        let mock_res = mockito::mock("POST", "/indexes/movies/search")
+            .expect(0)
+            .create();
+
+        let res = index
+            .search()
+            .with_vector(&[0.5, f32::NAN])
+            .execute::<Value>()
+            .await;
+
+        mock_res.assert();
+        assert!(matches!(res, Err(Error::InvalidVector)));
+    }
+
+    #[meilisearch_test]
+    async fn test_empty_document_batches_send_no_request() {
+        let client = Client::new(
+            format!("{}/empty_batches", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let add = mockito::mock("POST", "/empty_batches/indexes/movies/documents")
+            .expect(0)
+            .create();
+        let update = mockito::mock("PUT", "/empty_batches/indexes/movies/documents")
+            .expect(0)
+            .create();
+        let documents: &[Value] = &[];
+
+        assert!(index
+            .add_documents_if_any(documents, None)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(index
+            .update_documents_if_any(documents, None)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(index
+            .add_documents_in_batches(documents, None, None)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(index
+            .add_documents_auto_batched(documents, 1024, None)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(index
+            .update_documents_in_batches(documents, None, None)
+            .await
+            .unwrap()
+            .is_empty());
+
+        add.assert();
+        update.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_add_documents_validated() {
+        let client = Client::new(
+            format!("{}/add_documents_validated", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("restaurants");
+        let valid = [json!({ "id": 1, "_geo": { "lat": 45.47, "lng": 9.18 } })];
+        let add = mockito::mock("POST", "/add_documents_validated/indexes/restaurants/documents")
+            .match_body(mockito::Matcher::Json(json!(valid)))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "restaurants", "status": "enqueued", "type": "documentAdditionOrUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#)
+            .expect(1)
+            .create();
+
+        let task = index.add_documents_validated(&valid, None).await.unwrap();
+        assert_eq!(task.task_uid, 1);
+
+        // the invalid documents are not sent
+        let invalid = [
+            json!({ "id": 1 }),
+            json!({ "id": 2, "_vectors": { "default": "garbage" } }),
+        ];
+        let error = index
+            .add_documents_validated(&invalid, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidReservedField { document: 1, field, .. } if field == "_vectors.default"
+        ));
+        add.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_import_ndjson() {
+        let client = Client::new(
+            format!("{}/import_ndjson", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let enqueued = |uid: u32, task_type: &str| {
+            json!({ "taskUid": uid, "indexUid": "movies", "status": "enqueued", "type": task_type, "enqueuedAt": "2023-01-01T00:00:00Z" }).to_string()
+        };
+        let set_settings = mockito::mock("PATCH", "/import_ndjson/indexes/movies/settings")
+            .match_body(mockito::Matcher::Json(
+                json!({ "filterableAttributes": ["genre"] }),
+            ))
+            .with_status(202)
+            .with_body(enqueued(1, "settingsUpdate"))
+            .expect(1)
+            .create();
+        let settings_task = mockito::mock("GET", "/import_ndjson/tasks/1")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "uid": 1,
+                    "indexUid": "movies",
+                    "status": "succeeded",
+                    "type": "settingsUpdate",
+                    "duration": "PT0.01S",
+                    "enqueuedAt": "2023-01-01T00:00:00Z",
+                    "startedAt": "2023-01-01T00:00:00Z",
+                    "finishedAt": "2023-01-01T00:00:01Z"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+        let add = mockito::mock(
+            "POST",
+            "/import_ndjson/indexes/movies/documents?primaryKey=id",
+        )
+        .match_body(mockito::Matcher::Json(json!([
+            { "id": 1, "title": "Carol", "genre": "romance" },
+            { "id": 2, "title": "Moana", "genre": "adventure" }
+        ])))
+        .with_status(202)
+        .with_body(enqueued(2, "documentAdditionOrUpdate"))
+        .expect(1)
+        .create();
+
+        let ndjson = r#"{ "id": 1, "title": "Carol", "genre": "romance" }
+
+{ "id": 2, "title": "Moana", "genre": "adventure" }
+"#;
+        let settings = Settings::new().with_filterable_attributes(["genre"]);
+        let tasks = index
+            .import_ndjson(ndjson.as_bytes(), Some(&settings), Some("id"))
+            .await
+            .unwrap();
+
+        set_settings.assert();
+        settings_task.assert();
+        add.assert();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].task_uid, 2);
+
+        // the malformed line is reported with its number, and the documents before it are not sent alone
+        let malformed = "{ \"id\": 1 }\n\n{ \"id\": 2,\n{ \"id\": 3 }\n";
+        let error = index
+            .import_ndjson(malformed.as_bytes(), None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidNdjsonLine { line: 3, .. }));
+        let error = index
+            .import_ndjson("[1, 2]".as_bytes(), None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidNdjsonLine { line: 1, .. }));
+        add.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_export_ndjson() {
+        let client = Client::new(
+            format!("{}/export_ndjson", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let page = |offset: usize, count: usize| {
+            let documents: Vec<Value> = (offset..offset + count)
+                .map(|id| json!({ "title": format!("movie {}", id), "id": id }))
+                .collect();
+            mockito::mock("GET", "/export_ndjson/indexes/movies/documents")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("offset".into(), offset.to_string()),
+                    mockito::Matcher::UrlEncoded("limit".into(), NDJSON_BATCH_SIZE.to_string()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    json!({ "results": documents, "offset": offset, "limit": NDJSON_BATCH_SIZE, "total": 1002 })
+                        .to_string(),
+                )
+                .expect(1)
+                .create()
+        };
+        let first = page(0, NDJSON_BATCH_SIZE);
+        let last = page(NDJSON_BATCH_SIZE, 2);
+
+        let mut ndjson = Vec::new();
+        let exported = index.export_ndjson(&mut ndjson).await.unwrap();
+
+        first.assert();
+        last.assert();
+        assert_eq!(exported, 1002);
+        let ndjson = String::from_utf8(ndjson).unwrap();
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 1002);
+        assert_eq!(lines[0], r#"{"id":0,"title":"movie 0"}"#);
+        assert_eq!(lines[1001], r#"{"id":1001,"title":"movie 1001"}"#);
+        assert!(ndjson.ends_with('\n'));
+    }
+
+    #[meilisearch_test]
+    async fn test_large_integer_ids_keep_their_precision() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Message {
+            id: u64,
+            text: String,
+        }
+
+        // 2^53 + 1, the first integer an f64 can't represent
+        let id = 9_007_199_254_740_993_u64;
+        let body = r#"{"id":9007199254740993,"text":"hello"}"#;
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("large_integer_ids");
+        let add = mockito::mock("POST", "/indexes/large_integer_ids/documents")
+            .match_body(mockito::Matcher::Exact(format!("[{}]", body)))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "large_integer_ids", "status": "enqueued", "type": "documentAdditionOrUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#)
+            .create();
+        let get = mockito::mock(
+            "GET",
+            "/indexes/large_integer_ids/documents/9007199254740993",
+        )
+        .with_status(200)
+        .with_body(body)
+        .expect(2)
+        .create();
+        let message = Message {
+            id,
+            text: "hello".to_string(),
+        };
+
+        let task = index.add_documents(&[&message], None).await.unwrap();
+        assert_eq!(task.task_uid, 1);
+        let typed: Message = index.get_document(&id.to_string()).await.unwrap();
+        let untyped: Value = index.get_document(&id.to_string()).await.unwrap();
+
+        add.assert();
+        get.assert();
+        assert_eq!(typed, message);
+        assert_eq!(untyped["id"].as_u64(), Some(id));
+    }
+
+    #[meilisearch_test]
+    async fn test_delete_document_and_wait(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(
+                &[
+                    json!({ "id": 1, "name": "one" }),
+                    json!({ "id": "two", "name": "two" }),
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let outcome = index.delete_document_and_wait(1, None, None).await?;
+        assert!(outcome.existed);
+        assert!(outcome.task.is_success());
+
+        let outcome = index.delete_document_and_wait("two", None, None).await?;
+        assert!(outcome.existed);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_add_documents_and_search() {
+        let client = Client::new(
+            format!("{}/read_your_writes", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let add = mockito::mock("POST", "/read_your_writes/indexes/movies/documents")
+            .match_body(mockito::Matcher::Json(json!([{ "id": 1, "title": "Interstellar" }])))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "documentAdditionOrUpdate", "enqueuedAt": "2022-02-03T15:17:02.801341Z" }"#)
+            .create();
+        let task = mockito::mock("GET", "/read_your_writes/tasks/1")
+            .with_status(200)
+            .with_body(r#"{ "details": { "receivedDocuments": 1, "indexedDocuments": 1 }, "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "finishedAt": "2022-02-03T15:17:02.811341Z", "indexUid": "movies", "startedAt": "2022-02-03T15:17:02.802338Z", "status": "succeeded", "type": "documentAdditionOrUpdate", "uid": 1 }"#)
+            .create();
+        // the search is only sent once the documents are indexed
+        let search = mockito::mock("POST", "/read_your_writes/indexes/movies/search")
+            .match_body(mockito::Matcher::Json(json!({ "q": "interstellar" })))
+            .with_status(200)
+            .with_body(r#"{ "hits": [{ "id": 1, "title": "Interstellar" }], "offset": 0, "limit": 20, "estimatedTotalHits": 1, "processingTimeMs": 1, "query": "interstellar" }"#)
+            .create();
+
+        let results = index
+            .add_documents_and_search::<_, Value>(
+                &[json!({ "id": 1, "title": "Interstellar" })],
+                &index.search().with_query("interstellar").build(),
+            )
+            .await
+            .unwrap();
+
+        add.assert();
+        task.assert();
+        search.assert();
+        assert_eq!(results.hits[0].result["title"], "Interstellar");
+    }
+
+    #[meilisearch_test]
+    async fn test_add_documents_and_search_fails_with_the_task() {
+        let client = Client::new(
+            format!("{}/read_your_writes_failure", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let _add = mockito::mock("POST", "/read_your_writes_failure/indexes/movies/documents")
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "documentAdditionOrUpdate", "enqueuedAt": "2022-02-03T15:17:02.801341Z" }"#)
+            .create();
+        let _task = mockito::mock("GET", "/read_your_writes_failure/tasks/1")
+            .with_status(200)
+            .with_body(r#"{ "details": { "receivedDocuments": 1, "indexedDocuments": 0 }, "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "finishedAt": "2022-02-03T15:17:02.811341Z", "indexUid": "movies", "startedAt": "2022-02-03T15:17:02.802338Z", "status": "failed", "type": "documentAdditionOrUpdate", "uid": 1, "error": { "message": "The primary key inference failed.", "code": "primary_key_inference_failed", "type": "invalid_request", "link": "https://docs.meilisearch.com/errors#primary_key_inference_failed" } }"#)
+            .create();
+        let search = mockito::mock("POST", "/read_your_writes_failure/indexes/movies/search")
+            .expect(0)
+            .create();
+
+        let error = index
+            .add_documents_and_search::<_, Value>(
+                &[json!({ "title": "Interstellar" })],
+                &index.search().build(),
+            )
+            .await
+            .unwrap_err();
+
+        search.assert();
+        assert!(matches!(
+            error,
+            Error::Meilisearch(MeilisearchError {
+                error_code: ErrorCode::PrimaryKeyInferenceFailed,
+                ..
+            })
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_search_in_chunks() {
+        let client = Client::new(
+            format!("{}/search_in_chunks", mockito::server_url()),
+            "masterKey",
+        );
+        let mut index = client.index("movies");
+        index.primary_key = Some("id".to_string());
+        // the duplicated value is only sent once
+        let values: Vec<String> = (0..500)
+            .map(|id| id.to_string())
+            .chain(Some("42".to_string()))
+            .collect();
+        let chunks = [
+            (
+                0..200,
+                r#"[{ "id": "1", "_rankingScore": 0.5 }, { "id": "2", "_rankingScore": 0.9 }]"#,
+            ),
+            (
+                200..400,
+                r#"[{ "id": "1", "_rankingScore": 0.7 }, { "id": "300", "_rankingScore": 0.8 }]"#,
+            ),
+            (
+                400..500,
+                r#"[{ "id": "450", "_rankingScore": 0.95 }, { "id": "499", "_rankingScore": 0.1 }]"#,
+            ),
+        ];
+        let mocks: Vec<_> = chunks
+            .iter()
+            .map(|(ids, hits)| {
+                let filter = FilterExpr::condition(
+                    "id",
+                    FilterOperator::In(ids.clone().map(|id| id.to_string()).collect()),
+                );
+                mockito::mock("POST", "/search_in_chunks/indexes/movies/search")
+                    .match_body(mockito::Matcher::Json(json!({
+                        "q": "space",
+                        "offset": 0,
+                        "limit": 10,
+                        "filter": ["genre = scifi", filter.to_string()],
+                        "showRankingScore": true,
+                    })))
+                    .with_status(200)
+                    .with_body(format!(
+                        r#"{{ "hits": {}, "offset": 0, "limit": 10, "estimatedTotalHits": 2, "processingTimeMs": 1, "query": "space" }}"#,
+                        hits
+                    ))
+                    .create()
+            })
+            .collect();
+
+        let mut query = index.search();
+        query
+            .with_query("space")
+            .with_filter("genre = scifi")
+            .with_limit(10);
+        let results = index
+            .search_in_chunks::<Value>(&query, "id", &values, 200)
+            .await
+            .unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+        let ids: Vec<_> = results.hits.iter().map(|hit| &hit.result["id"]).collect();
+        assert_eq!(ids, ["450", "2", "300", "1", "499"]);
+        // the ranking score wasn't requested by the base query
+        assert!(results.hits.iter().all(|hit| hit.ranking_score.is_none()));
+        assert_eq!(results.estimated_total_hits, 6);
+        assert_eq!(results.limit, 10);
+    }
+
+    #[meilisearch_test]
+    async fn test_search_in_chunks_retrieves_the_primary_key() {
+        let client = Client::new(
+            format!("{}/search_in_chunks_primary_key", mockito::server_url()),
+            "masterKey",
+        );
+        let fetch = mockito::mock("GET", "/search_in_chunks_primary_key/indexes/movies")
+            .with_status(200)
+            .with_body(r#"{ "uid": "movies", "createdAt": "2022-02-03T15:17:02.801341Z", "updatedAt": "2022-02-03T15:17:02.801341Z", "primaryKey": "id" }"#)
+            .expect(1)
+            .create();
+        let search = mockito::mock("POST", "/search_in_chunks_primary_key/indexes/movies/search")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({ "attributesToRetrieve": ["title", "id"] }),
+            ))
+            .with_status(200)
+            .with_body(r#"{ "hits": [{ "id": 1, "title": "Dune", "_rankingScore": 0.9 }, { "id": 2, "title": "Dune", "_rankingScore": 0.8 }], "offset": 0, "limit": 20, "estimatedTotalHits": 2, "processingTimeMs": 1, "query": "" }"#)
+            .expect(2)
+            .create();
+
+        let index = client.index("movies");
+        let mut query = index.search();
+        query.with_attributes_to_retrieve(Selectors::Some(&["title"]));
+        let values = ["1".to_string(), "2".to_string()];
+        for _ in 0..2 {
+            let results = index
+                .search_in_chunks::<Value>(&query, "id", &values, 10)
+                .await
+                .unwrap();
+            // the hits with the same title are kept, without the primary key that wasn't asked for
+            let hits: Vec<_> = results.hits.iter().map(|hit| &hit.result).collect();
+            assert_eq!(
+                hits,
+                [&json!({ "title": "Dune" }), &json!({ "title": "Dune" })]
+            );
+        }
+        // the primary key was fetched once then cached by the client
+        fetch.assert();
+        search.assert();
+
+        assert!(matches!(
+            index
+                .search_in_chunks::<Value>(&query, "id", &values, 0)
+                .await,
+            Err(Error::InvalidChunkSize)
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_truncate(client: Client, index: Index) -> Result<(), Error> {
+        index
+            .add_documents(
+                &[json!({ "id": 1 }), json!({ "id": 2 }), json!({ "id": 3 })],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let task = index.truncate(None, None).await?;
+
+        assert!(task.is_success());
+        assert_eq!(index.get_stats().await?.number_of_documents, 0);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_delete_missing_document_and_wait(index: Index) -> Result<(), Error> {
+        let outcome = index.delete_document_and_wait(42, None, None).await?;
+
+        assert!(!outcome.existed);
+        assert!(outcome.task.is_success());
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_typed_index(client: Client, index: Index) -> Result<(), Error> {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Movie {
+            id: usize,
+            name: String,
+        }
+
+        let movies: TypedIndex<Movie> = client.typed_index(index.uid.clone());
+        movies
+            .add_documents(
+                &[
+                    Movie {
+                        id: 1,
+                        name: "Interstellar".to_string(),
+                    },
+                    Movie {
+                        id: 2,
+                        name: "Inception".to_string(),
+                    },
+                ],
+                Some("id"),
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let results = movies
+            .search(SearchParams::new().with_query("Interstellar"))
+            .await?;
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(
+            results.hits[0].result,
+            Movie {
+                id: 1,
+                name: "Interstellar".to_string()
+            }
+        );
+
+        let movie = movies.get_document("2").await?;
+        assert_eq!(movie.name, "Inception");
+        assert_eq!(movies.get_documents().await?.results.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batches_by_size() {
+        let documents: Vec<Value> = (0..50)
+            .map(|i| json!({ "id": i, "text": "a".repeat((i * 37) % 200) }))
+            .collect();
+        let target = 512;
+
+        let batches = batches_by_size(&documents, target).unwrap();
+
+        assert!(batches.len() > 1);
+        for batch in &batches {
+            assert!(serde_json::to_vec(batch).unwrap().len() <= target);
+        }
+        assert_eq!(batches.concat(), documents);
+    }
+
+    #[test]
+    fn test_batches_by_size_with_huge_document() {
+        let documents = vec![
+            json!({ "id": 0 }),
+            json!({ "id": 1, "text": "a".repeat(1000) }),
+            json!({ "id": 2 }),
+        ];
+
+        let batches = batches_by_size(&documents, 100).unwrap();
+
+        assert_eq!(
+            batches,
+            vec![&documents[0..1], &documents[1..2], &documents[2..3]]
+        );
+        assert!(batches_by_size::<Value>(&[], 100).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_uid() {
+        for uid in ["movies", "Movies_2022", "a-b_c", "0", &"a".repeat(400)] {
+            assert!(Index::validate_uid(uid).is_ok(), "{} should be valid", uid);
+        }
+
+        for uid in [
+            "",
+            "my movies",
+            "movies.json",
+            "film-é",
+            "索引",
+            &"a".repeat(401),
+        ] {
+            assert!(
+                matches!(Index::validate_uid(uid), Err(Error::InvalidIndexUid(ref invalid)) if invalid == uid),
+                "{} should be invalid",
+                uid
+            );
+        }
+    }
+
     #[meilisearch_test]
     async fn test_fetch_info(mut index: Index) {
         let res = index.fetch_info().await;
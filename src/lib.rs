@@ -223,18 +223,34 @@
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
+/// Module representing the [batches::Batch]es of tasks.
+pub mod batches;
 /// Module containing the [client::Client] struct.
 pub mod client;
+/// Module containing the [clock::Clock] used to wait, and a [clock::MockClock] to test the waits.
+#[cfg(feature = "test-utils")]
+pub mod clock;
+#[cfg(not(feature = "test-utils"))]
+mod clock;
 /// Module representing the [documents] structures.
 pub mod documents;
 /// Module containing the [document::Document] trait.
 pub mod dumps;
+/// Module parsing the ISO 8601 durations returned by Meilisearch.
+pub mod duration;
 /// Module containing the [errors::Error] struct.
 pub mod errors;
+/// Module containing a typed representation of the filter expressions.
+pub mod filters;
 /// Module containing the Index struct.
 pub mod indexes;
 /// Module containing the [key::Key] struct.
 pub mod key;
+/// Module exposing the metrics of the client.
+#[cfg(feature = "metrics")]
+pub mod metrics;
+/// Module to provision the indexes and keys of tenants.
+pub mod provisioning;
 mod request;
 /// Module related to search queries and results.
 pub mod search;
@@ -248,5 +264,7 @@ pub mod tasks;
 mod tenant_tokens;
 /// Module containing utilies functions.
 mod utils;
+/// Module containing helpers to validate and normalize vectors.
+pub mod vectors;
 
 pub use client::*;
@@ -0,0 +1,503 @@
+//! Metrics about the requests sent by a [Client](crate::client::Client), available with the `metrics` feature.
+//!
+//! A [MetricsRecorder] given to [ClientBuilder::with_metrics_recorder](crate::client::ClientBuilder::with_metrics_recorder)
+//! is called for every HTTP request, every [wait for a task](crate::client::Client::wait_for_task) and every batch
+//! of documents submitted. The labels are low-cardinality: the routes are templates like `/indexes/{index_uid}/search`.
+//! Without a recorder nothing is measured.
+//!
+//! An implementation exporting the metrics with OpenTelemetry is provided by the `metrics-opentelemetry` feature.
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::{client::*, metrics::*};
+//! #
+//! # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+//! # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+//! #
+//! struct LogRecorder;
+//!
+//! impl MetricsRecorder for LogRecorder {
+//!     fn record_request(&self, request: &RequestMetric) {
+//!         println!("{} {} {} in {:?}", request.method, request.route, request.status_class, request.duration);
+//!     }
+//! }
+//!
+//! let client = ClientBuilder::new(MEILISEARCH_URL, MEILISEARCH_API_KEY)
+//!     .with_metrics_recorder(LogRecorder)
+//!     .build();
+//! ```
+
+use std::{fmt, sync::Arc, time::Duration};
+
+/// A HTTP request sent to Meilisearch.
+#[derive(Debug, Clone)]
+pub struct RequestMetric<'a> {
+    /// The route template, ie `/indexes/{index_uid}/search`.
+    pub route: &'a str,
+    /// The HTTP method, ie `POST`.
+    pub method: &'static str,
+    /// The class of the status of the response, ie `2xx`, or `network_error` when no response was received.
+    pub status_class: &'static str,
+    /// The time until the response was received.
+    pub duration: Duration,
+}
+
+/// A wait for the processing of a task.
+#[derive(Debug, Clone)]
+pub struct TaskWaitMetric {
+    /// How the wait ended: `succeeded`, `failed`, `canceled`, `vanished` when the task was deleted, `timeout`,
+    /// or `error` when the task could not be fetched.
+    pub outcome: &'static str,
+    pub duration: Duration,
+}
+
+/// Receives the metrics of a [Client](crate::client::Client).
+///
+/// Every method does nothing by default, so only the metrics of interest have to be implemented.
+pub trait MetricsRecorder: Send + Sync {
+    /// Called after each HTTP request.
+    fn record_request(&self, _request: &RequestMetric) {}
+
+    /// Called when a wait for a task ends.
+    fn record_task_wait(&self, _wait: &TaskWaitMetric) {}
+
+    /// Called when Meilisearch accepted a batch of documents sent to `route`.
+    fn record_documents_submitted(&self, _route: &str, _count: usize) {}
+}
+
+impl<R: MetricsRecorder + ?Sized> MetricsRecorder for Arc<R> {
+    fn record_request(&self, request: &RequestMetric) {
+        (**self).record_request(request)
+    }
+
+    fn record_task_wait(&self, wait: &TaskWaitMetric) {
+        (**self).record_task_wait(wait)
+    }
+
+    fn record_documents_submitted(&self, route: &str, count: usize) {
+        (**self).record_documents_submitted(route, count)
+    }
+}
+
+/// The recorder of a client, if any.
+#[derive(Clone, Default)]
+pub(crate) struct Metrics(pub(crate) Option<Arc<dyn MetricsRecorder>>);
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Metrics")
+            .field(&self.0.as_ref().map(|_| "MetricsRecorder"))
+            .finish()
+    }
+}
+
+impl Metrics {
+    pub(crate) fn record_request(
+        &self,
+        url: &str,
+        host: &str,
+        method: &'static str,
+        status: Option<u16>,
+        stopwatch: Stopwatch,
+    ) {
+        if let Some(recorder) = &self.0 {
+            recorder.record_request(&RequestMetric {
+                route: &route_template(url, host),
+                method,
+                status_class: status_class(status),
+                duration: stopwatch.elapsed(),
+            });
+        }
+    }
+
+    pub(crate) fn record_task_wait(&self, outcome: &'static str, stopwatch: Stopwatch) {
+        if let Some(recorder) = &self.0 {
+            recorder.record_task_wait(&TaskWaitMetric {
+                outcome,
+                duration: stopwatch.elapsed(),
+            });
+        }
+    }
+
+    pub(crate) fn record_documents_submitted(&self, url: &str, host: &str, count: usize) {
+        if let Some(recorder) = &self.0 {
+            recorder.record_documents_submitted(&route_template(url, host), count);
+        }
+    }
+}
+
+/// Measures a duration, on wasm as well.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+    #[cfg(target_arch = "wasm32")]
+    start_ms: f64,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Stopwatch {
+        Stopwatch {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+            #[cfg(target_arch = "wasm32")]
+            start_ms: js_sys::Date::now(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.start.elapsed();
+        #[cfg(target_arch = "wasm32")]
+        return Duration::from_secs_f64((js_sys::Date::now() - self.start_ms).max(0.) / 1000.);
+    }
+}
+
+fn status_class(status: Option<u16>) -> &'static str {
+    match status {
+        Some(100..=199) => "1xx",
+        Some(200..=299) => "2xx",
+        Some(300..=399) => "3xx",
+        Some(400..=499) => "4xx",
+        Some(_) => "5xx",
+        None => "network_error",
+    }
+}
+
+/// Replace the variable parts of the path of `url`, like the index uids, by placeholders.
+fn route_template(url: &str, host: &str) -> String {
+    let path = url.strip_prefix(host).unwrap_or(url);
+    let path = path.split('?').next().unwrap_or_default();
+
+    let mut route = String::new();
+    let mut previous = "";
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        let template = match previous {
+            "indexes" => "{index_uid}",
+            "keys" => "{key}",
+            "tasks" | "batches" if segment.parse::<u32>().is_ok() => "{task_uid}",
+            "documents" if !["fetch", "delete", "delete-batch"].contains(&segment) => {
+                "{document_id}"
+            }
+            _ => segment,
+        };
+        route.push('/');
+        route.push_str(template);
+        previous = segment;
+    }
+    route
+}
+
+/// A [MetricsRecorder] exporting the metrics with OpenTelemetry, available with the `metrics-opentelemetry` feature.
+///
+/// | Metric | Kind | Labels |
+/// |---|---|---|
+/// | `meilisearch.client.requests` | counter | `route`, `method`, `status_class` |
+/// | `meilisearch.client.request.duration` | histogram, in seconds | `route`, `method`, `status_class` |
+/// | `meilisearch.client.task.wait.duration` | histogram, in seconds | `outcome` |
+/// | `meilisearch.client.documents.submitted` | counter | `route` |
+#[cfg(feature = "metrics-opentelemetry")]
+#[derive(Debug)]
+pub struct OpenTelemetryRecorder {
+    requests: opentelemetry::metrics::Counter<u64>,
+    request_duration: opentelemetry::metrics::Histogram<f64>,
+    task_wait_duration: opentelemetry::metrics::Histogram<f64>,
+    documents_submitted: opentelemetry::metrics::Counter<u64>,
+}
+
+#[cfg(feature = "metrics-opentelemetry")]
+impl OpenTelemetryRecorder {
+    /// Create the instruments with the given meter, ie `opentelemetry::global::meter("meilisearch")`.
+    pub fn new(meter: &opentelemetry::metrics::Meter) -> OpenTelemetryRecorder {
+        use opentelemetry::metrics::Unit;
+
+        OpenTelemetryRecorder {
+            requests: meter
+                .u64_counter("meilisearch.client.requests")
+                .with_description("Number of HTTP requests sent to Meilisearch")
+                .init(),
+            request_duration: meter
+                .f64_histogram("meilisearch.client.request.duration")
+                .with_description("Duration of the HTTP requests sent to Meilisearch")
+                .with_unit(Unit::new("s"))
+                .init(),
+            task_wait_duration: meter
+                .f64_histogram("meilisearch.client.task.wait.duration")
+                .with_description("Duration of the waits for the processing of a task")
+                .with_unit(Unit::new("s"))
+                .init(),
+            documents_submitted: meter
+                .u64_counter("meilisearch.client.documents.submitted")
+                .with_description("Number of documents accepted by Meilisearch")
+                .init(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics-opentelemetry")]
+impl MetricsRecorder for OpenTelemetryRecorder {
+    fn record_request(&self, request: &RequestMetric) {
+        use opentelemetry::KeyValue;
+
+        let labels = [
+            KeyValue::new("route", request.route.to_string()),
+            KeyValue::new("method", request.method),
+            KeyValue::new("status_class", request.status_class),
+        ];
+        self.requests.add(1, &labels);
+        self.request_duration
+            .record(request.duration.as_secs_f64(), &labels);
+    }
+
+    fn record_task_wait(&self, wait: &TaskWaitMetric) {
+        self.task_wait_duration.record(
+            wait.duration.as_secs_f64(),
+            &[opentelemetry::KeyValue::new("outcome", wait.outcome)],
+        );
+    }
+
+    fn record_documents_submitted(&self, route: &str, count: usize) {
+        self.documents_submitted.add(
+            count as u64,
+            &[opentelemetry::KeyValue::new("route", route.to_string())],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::*, settings::Settings};
+    use meilisearch_test_macro::meilisearch_test;
+    use mockito::mock;
+    use serde_json::Value;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestRecorder {
+        labels: Mutex<Vec<String>>,
+    }
+
+    impl MetricsRecorder for TestRecorder {
+        fn record_request(&self, request: &RequestMetric) {
+            self.labels.lock().unwrap().push(format!(
+                "{} {} {}",
+                request.method, request.route, request.status_class
+            ));
+        }
+
+        fn record_task_wait(&self, wait: &TaskWaitMetric) {
+            self.labels
+                .lock()
+                .unwrap()
+                .push(format!("task wait {}", wait.outcome));
+        }
+    }
+
+    #[test]
+    fn test_route_template() {
+        let host = "http://localhost:7700";
+        let route = |path: &str| route_template(&format!("{}{}", host, path), host);
+
+        assert_eq!(
+            route("/indexes/movies/search"),
+            "/indexes/{index_uid}/search"
+        );
+        assert_eq!(
+            route("/indexes/movies/documents/42?fields=id"),
+            "/indexes/{index_uid}/documents/{document_id}"
+        );
+        assert_eq!(
+            route("/indexes/movies/documents/delete-batch"),
+            "/indexes/{index_uid}/documents/delete-batch"
+        );
+        assert_eq!(route("/tasks/12"), "/tasks/{task_uid}");
+        assert_eq!(route("/tasks/cancel?uids=1"), "/tasks/cancel");
+        assert_eq!(route("/keys/abc"), "/keys/{key}");
+    }
+
+    #[meilisearch_test]
+    async fn test_recorder_labels() {
+        let recorder = Arc::new(TestRecorder::default());
+        let client = ClientBuilder::new(mockito::server_url(), "masterKey")
+            .with_metrics_recorder(Arc::clone(&recorder))
+            .build();
+        let index = client.index("recorded");
+        let _search = mock("POST", "/indexes/recorded/search")
+            .with_status(200)
+            .with_body(r#"{ "hits": [], "offset": 0, "limit": 20, "estimatedTotalHits": 0, "processingTimeMs": 1, "query": "" }"#)
+            .create();
+        let _settings = mock("PATCH", "/indexes/recorded/settings")
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 7, "indexUid": "recorded", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2022-02-03T15:17:02.801341Z" }"#)
+            .create();
+        let _task = mock("GET", "/tasks/7")
+            .with_status(200)
+            .with_body(r#"{ "uid": 7, "indexUid": "recorded", "status": "succeeded", "type": "settingsUpdate", "duration": "PT0.01S", "enqueuedAt": "2022-02-03T15:17:02.801341Z", "startedAt": "2022-02-03T15:17:02.812338Z", "finishedAt": "2022-02-03T15:17:02.821295Z" }"#)
+            .create();
+
+        index.search().execute::<Value>().await.unwrap();
+        let task = index
+            .set_settings(&Settings::new().with_stop_words(["the"]))
+            .await
+            .unwrap();
+        client.wait_for_task(task, None, None).await.unwrap();
+
+        assert_eq!(
+            *recorder.labels.lock().unwrap(),
+            [
+                "POST /indexes/{index_uid}/search 2xx",
+                "PATCH /indexes/{index_uid}/settings 2xx",
+                "GET /tasks/{task_uid} 2xx",
+                "task wait succeeded",
+            ]
+        );
+    }
+
+    /// A point recorded by an instrument, as `(instrument name, value, attributes)`.
+    #[cfg(feature = "metrics-opentelemetry")]
+    type Point = (String, f64, Vec<(String, String)>);
+
+    /// An OpenTelemetry instrument provider keeping the recorded points in memory.
+    #[cfg(feature = "metrics-opentelemetry")]
+    #[derive(Clone, Default)]
+    struct InMemoryInstruments {
+        points: std::sync::Arc<Mutex<Vec<Point>>>,
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    struct InMemoryInstrument {
+        name: String,
+        instruments: InMemoryInstruments,
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    impl InMemoryInstrument {
+        fn push(&self, value: f64, attributes: &[opentelemetry::KeyValue]) {
+            let attributes = attributes
+                .iter()
+                .map(|kv| (kv.key.to_string(), kv.value.to_string()))
+                .collect();
+            self.instruments
+                .points
+                .lock()
+                .unwrap()
+                .push((self.name.clone(), value, attributes));
+        }
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    impl opentelemetry::metrics::SyncCounter<u64> for InMemoryInstrument {
+        fn add(&self, value: u64, attributes: &[opentelemetry::KeyValue]) {
+            self.push(value as f64, attributes);
+        }
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    impl opentelemetry::metrics::SyncHistogram<f64> for InMemoryInstrument {
+        fn record(&self, value: f64, attributes: &[opentelemetry::KeyValue]) {
+            self.push(value, attributes);
+        }
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    impl opentelemetry::metrics::InstrumentProvider for InMemoryInstruments {
+        fn u64_counter(
+            &self,
+            name: std::borrow::Cow<'static, str>,
+            _description: Option<std::borrow::Cow<'static, str>>,
+            _unit: Option<opentelemetry::metrics::Unit>,
+        ) -> opentelemetry::metrics::Result<opentelemetry::metrics::Counter<u64>> {
+            Ok(opentelemetry::metrics::Counter::new(std::sync::Arc::new(
+                InMemoryInstrument {
+                    name: name.into_owned(),
+                    instruments: self.clone(),
+                },
+            )))
+        }
+
+        fn f64_histogram(
+            &self,
+            name: std::borrow::Cow<'static, str>,
+            _description: Option<std::borrow::Cow<'static, str>>,
+            _unit: Option<opentelemetry::metrics::Unit>,
+        ) -> opentelemetry::metrics::Result<opentelemetry::metrics::Histogram<f64>> {
+            Ok(opentelemetry::metrics::Histogram::new(std::sync::Arc::new(
+                InMemoryInstrument {
+                    name: name.into_owned(),
+                    instruments: self.clone(),
+                },
+            )))
+        }
+
+        // the recorder only uses synchronous instruments
+        fn register_callback(
+            &self,
+            _instruments: &[std::sync::Arc<dyn std::any::Any>],
+            _callbacks: Box<dyn Fn(&dyn opentelemetry::metrics::Observer) + Send + Sync>,
+        ) -> opentelemetry::metrics::Result<Box<dyn opentelemetry::metrics::CallbackRegistration>>
+        {
+            Err(opentelemetry::metrics::MetricsError::Other(String::from(
+                "callbacks aren't supported",
+            )))
+        }
+    }
+
+    #[cfg(feature = "metrics-opentelemetry")]
+    #[test]
+    fn test_opentelemetry_recorder() {
+        let instruments = InMemoryInstruments::default();
+        let meter = opentelemetry::metrics::Meter::new(std::sync::Arc::new(instruments.clone()));
+        let recorder = OpenTelemetryRecorder::new(&meter);
+
+        recorder.record_request(&RequestMetric {
+            route: "/indexes/{index_uid}/search",
+            method: "POST",
+            status_class: "2xx",
+            duration: Duration::from_millis(3),
+        });
+        recorder.record_task_wait(&TaskWaitMetric {
+            outcome: "succeeded",
+            duration: Duration::from_millis(50),
+        });
+        recorder.record_documents_submitted("/indexes/{index_uid}/documents", 10);
+
+        let attributes = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+        let request = attributes(&[
+            ("route", "/indexes/{index_uid}/search"),
+            ("method", "POST"),
+            ("status_class", "2xx"),
+        ]);
+        assert_eq!(
+            *instruments.points.lock().unwrap(),
+            [
+                (
+                    String::from("meilisearch.client.requests"),
+                    1.0,
+                    request.clone()
+                ),
+                (
+                    String::from("meilisearch.client.request.duration"),
+                    0.003,
+                    request
+                ),
+                (
+                    String::from("meilisearch.client.task.wait.duration"),
+                    0.05,
+                    attributes(&[("outcome", "succeeded")])
+                ),
+                (
+                    String::from("meilisearch.client.documents.submitted"),
+                    10.0,
+                    attributes(&[("route", "/indexes/{index_uid}/documents")])
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! The `provisioning` module creates the index and the keys of tenants, one at a time or by batch.
+//!
+//! Provisioning is idempotent: an index or a key that already exists is reported as
+//! [already present](ProvisionStatus::AlreadyPresent) instead of failing, so a batch can safely be run again.
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::{client::*, key::*, provisioning::*, settings::Settings};
+//! #
+//! # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+//! # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+//! #
+//! # futures::executor::block_on(async move {
+//! let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+//!
+//! let mut search_key = KeySpec::new();
+//! search_key
+//!     .with_name("tenant_provisioning_search")
+//!     .with_action(Action::Search)
+//!     .with_index("tenant_provisioning");
+//!
+//! let tenant = TenantSpec {
+//!     index: IndexSpec::new("tenant_provisioning")
+//!         .with_primary_key("id")
+//!         .with_settings(Settings::new().with_filterable_attributes(["kind"])),
+//!     keys: vec![search_key],
+//! };
+//!
+//! let report = client.provision_tenant(&tenant).await.unwrap();
+//! assert_eq!(report.index_uid, "tenant_provisioning");
+//! # for key in report.keys { client.delete_key(key.key).await.unwrap(); }
+//! # client.index("tenant_provisioning").delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+//! # });
+//! ```
+
+use crate::{
+    client::Client,
+    errors::{Error, ErrorCode, MeilisearchError},
+    key::{Key, KeyBuilder, KeysQuery},
+    settings::{Settings, SettingsPlan},
+    tasks::Task,
+};
+use futures::stream::{self, StreamExt};
+
+/// The description of a key to provision.
+///
+/// A key is recognized as already present when a key with the same `uid` exists or,
+/// when no `uid` is given, a key with the same `name`. A key without `uid` nor `name` is always created.
+pub type KeySpec = KeyBuilder;
+
+/// The description of an index to provision.
+#[derive(Debug, Clone)]
+pub struct IndexSpec {
+    pub uid: String,
+    pub primary_key: Option<String>,
+    /// Settings applied to the index, even if it was already present.
+    pub settings: Option<Settings>,
+}
+
+#[allow(missing_docs)]
+impl IndexSpec {
+    pub fn new(uid: impl Into<String>) -> IndexSpec {
+        IndexSpec {
+            uid: uid.into(),
+            primary_key: None,
+            settings: None,
+        }
+    }
+    pub fn with_primary_key(self, primary_key: impl Into<String>) -> IndexSpec {
+        IndexSpec {
+            primary_key: Some(primary_key.into()),
+            ..self
+        }
+    }
+    pub fn with_settings(self, settings: Settings) -> IndexSpec {
+        IndexSpec {
+            settings: Some(settings),
+            ..self
+        }
+    }
+}
+
+/// Everything to provision for a tenant.
+#[derive(Debug)]
+pub struct TenantSpec {
+    pub index: IndexSpec,
+    pub keys: Vec<KeySpec>,
+}
+
+/// Whether a resource has been created by the provisioning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProvisionStatus {
+    Created,
+    AlreadyPresent,
+}
+
+/// A key of a tenant after its provisioning.
+#[derive(Debug, Clone)]
+pub struct ProvisionedKey {
+    pub status: ProvisionStatus,
+    pub key: Key,
+}
+
+/// The outcome of the provisioning of a tenant.
+#[derive(Debug, Clone)]
+pub struct TenantReport {
+    pub index_uid: String,
+    pub index: ProvisionStatus,
+    /// The keys, in the order of [TenantSpec::keys].
+    pub keys: Vec<ProvisionedKey>,
+}
+
+fn has_error_code(error: &Error, code: ErrorCode) -> bool {
+    matches!(error, Error::Meilisearch(MeilisearchError { error_code, .. }) if *error_code == code)
+}
+
+/// Turn a processed task into an error if it failed.
+fn check_task(task: Task) -> Result<(), Error> {
+    match task {
+        Task::Failed { content } => Err(Error::Meilisearch(content.error)),
+        _ => Ok(()),
+    }
+}
+
+/// Tenant provisioning methods.\
+/// See the [provisioning](crate::provisioning) module.
+impl Client {
+    /// Create the index of a tenant, apply its settings and create its keys, waiting for every task to be processed.
+    ///
+    /// The index and keys that already exist are left untouched and reported as [ProvisionStatus::AlreadyPresent].
+    /// The first error stops the provisioning of the tenant.
+    ///
+    /// See the [provisioning](crate::provisioning) module for an example.
+    pub async fn provision_tenant(&self, tenant: &TenantSpec) -> Result<TenantReport, Error> {
+        let index = self.provision_index(&tenant.index).await?;
+
+        let mut keys = Vec::with_capacity(tenant.keys.len());
+        let mut existing_keys = None;
+        for spec in &tenant.keys {
+            if let Some(uid) = &spec.uid {
+                match self.get_key(uid).await {
+                    Ok(key) => {
+                        keys.push(ProvisionedKey {
+                            status: ProvisionStatus::AlreadyPresent,
+                            key,
+                        });
+                        continue;
+                    }
+                    Err(error) if has_error_code(&error, ErrorCode::ApiKeyNotFound) => (),
+                    Err(error) => return Err(error),
+                }
+            } else if let Some(name) = &spec.name {
+                if existing_keys.is_none() {
+                    existing_keys = Some(self.get_all_keys().await?);
+                }
+                let existing = existing_keys
+                    .iter()
+                    .flatten()
+                    .find(|key| key.name.as_ref() == Some(name));
+                if let Some(key) = existing {
+                    keys.push(ProvisionedKey {
+                        status: ProvisionStatus::AlreadyPresent,
+                        key: key.clone(),
+                    });
+                    continue;
+                }
+            }
+
+            keys.push(ProvisionedKey {
+                status: ProvisionStatus::Created,
+                key: self.create_key(spec).await?,
+            });
+        }
+
+        Ok(TenantReport {
+            index_uid: tenant.index.uid.clone(),
+            index,
+            keys,
+        })
+    }
+
+    /// Provision many tenants, with at most `concurrency` tenants provisioned at the same time.
+    ///
+    /// A failure only affects its own tenant: the results are returned in the order of `tenants`,
+    /// each one being the outcome of [Client::provision_tenant].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, provisioning::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let tenants: Vec<TenantSpec> = (0..3)
+    ///     .map(|i| TenantSpec { index: IndexSpec::new(format!("provision_tenants_{}", i)), keys: vec![] })
+    ///     .collect();
+    ///
+    /// let reports = client.provision_tenants(&tenants, 2).await;
+    /// assert!(reports.iter().all(|report| report.is_ok()));
+    /// # for tenant in tenants { client.index(tenant.index.uid).delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap(); }
+    /// # });
+    /// ```
+    pub async fn provision_tenants(
+        &self,
+        tenants: &[TenantSpec],
+        concurrency: usize,
+    ) -> Vec<Result<TenantReport, Error>> {
+        stream::iter(tenants)
+            .map(|tenant| self.provision_tenant(tenant))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Get the settings that [Client::provision_tenants] would apply to the index of each tenant, without sending anything.
+    ///
+    /// The tenants without settings are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, provisioning::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let tenants = [TenantSpec {
+    ///     index: IndexSpec::new("tenant_a").with_settings(Settings::new().with_filterable_attributes(["kind"])),
+    ///     keys: vec![],
+    /// }];
+    ///
+    /// for plan in client.provision_tenants_dry_run(&tenants) {
+    ///     println!("{}", plan);
+    /// }
+    /// ```
+    pub fn provision_tenants_dry_run(&self, tenants: &[TenantSpec]) -> Vec<SettingsPlan> {
+        tenants
+            .iter()
+            .filter_map(|tenant| {
+                let settings = tenant.index.settings.as_ref()?;
+                Some(self.index(&tenant.index.uid).set_settings_dry_run(settings))
+            })
+            .collect()
+    }
+
+    async fn provision_index(&self, spec: &IndexSpec) -> Result<ProvisionStatus, Error> {
+        let status = match self.get_index(&spec.uid).await {
+            Ok(_) => ProvisionStatus::AlreadyPresent,
+            Err(error) if has_error_code(&error, ErrorCode::IndexNotFound) => {
+                let task = self
+                    .create_index(&spec.uid, spec.primary_key.as_deref())
+                    .await?
+                    .wait_for_completion(self, None, None)
+                    .await?;
+                match check_task(task) {
+                    Ok(()) => ProvisionStatus::Created,
+                    // the index has been created by someone else in the meantime
+                    Err(error) if has_error_code(&error, ErrorCode::IndexAlreadyExists) => {
+                        ProvisionStatus::AlreadyPresent
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+
+        if let Some(settings) = &spec.settings {
+            let task = self
+                .index(&spec.uid)
+                .set_settings(settings)
+                .await?
+                .wait_for_completion(self, None, None)
+                .await?;
+            check_task(task)?;
+        }
+
+        Ok(status)
+    }
+
+    async fn get_all_keys(&self) -> Result<Vec<Key>, Error> {
+        let mut keys = Vec::new();
+        let mut query = KeysQuery::new();
+        query.with_limit(100);
+        loop {
+            let page = self.get_keys_with(query.with_offset(keys.len())).await?;
+            let count = page.results.len();
+            keys.extend(page.results);
+            if count < 100 {
+                return Ok(keys);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{indexes::Index, key::Action, settings::Setting};
+    use meilisearch_test_macro::meilisearch_test;
+
+    #[meilisearch_test]
+    async fn test_provision_tenants(client: Client, index: Index) -> Result<(), Error> {
+        // the index of the first tenant already exists
+        let tenants: Vec<TenantSpec> = [
+            index.uid.clone(),
+            format!("{}_b", index.uid),
+            format!("{}_c", index.uid),
+        ]
+        .iter()
+        .map(|uid| {
+            let mut search_key = KeySpec::new();
+            search_key
+                .with_name(format!("{}_search", uid))
+                .with_action(Action::Search)
+                .with_index(uid);
+            let mut admin_key = KeySpec::new();
+            admin_key
+                .with_name(format!("{}_admin", uid))
+                .with_actions([
+                    Action::DocumentsAdd,
+                    Action::DocumentsGet,
+                    Action::DocumentsDelete,
+                    Action::SettingsGet,
+                    Action::SettingsUpdate,
+                ])
+                .with_index(uid);
+            TenantSpec {
+                index: IndexSpec::new(uid.clone())
+                    .with_settings(Settings::new().with_filterable_attributes(["kind"])),
+                keys: vec![search_key, admin_key],
+            }
+        })
+        .collect();
+
+        let reports = client.provision_tenants(&tenants, 2).await;
+
+        let reports = reports.into_iter().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(reports[0].index, ProvisionStatus::AlreadyPresent);
+        assert_eq!(reports[1].index, ProvisionStatus::Created);
+        assert_eq!(reports[2].index, ProvisionStatus::Created);
+        for (report, tenant) in reports.iter().zip(&tenants) {
+            assert_eq!(report.index_uid, tenant.index.uid);
+            assert_eq!(report.keys.len(), 2);
+            assert!(report
+                .keys
+                .iter()
+                .all(|key| key.status == ProvisionStatus::Created));
+            let settings = client.index(&report.index_uid).get_settings().await?;
+            assert_eq!(
+                settings.filterable_attributes,
+                Setting::Set(vec!["kind".to_string()])
+            );
+        }
+
+        // provisioning again doesn't create anything
+        let again = client.provision_tenant(&tenants[1]).await?;
+        assert_eq!(again.index, ProvisionStatus::AlreadyPresent);
+        assert!(again
+            .keys
+            .iter()
+            .all(|key| key.status == ProvisionStatus::AlreadyPresent));
+        assert_eq!(again.keys[0].key.uid, reports[1].keys[0].key.uid);
+
+        for report in reports {
+            for key in report.keys {
+                client.delete_key(key.key).await?;
+            }
+        }
+        for tenant in &tenants[1..] {
+            client
+                .index(&tenant.index.uid)
+                .delete()
+                .await?
+                .wait_for_completion(&client, None, None)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
-use crate::errors::{Error, MeilisearchError};
+use crate::{
+    client::Client,
+    errors::{Error, ErrorCode, MeilisearchError},
+    search::{SearchResult, SearchResults},
+};
 use log::{error, trace, warn};
 use serde::{de::DeserializeOwned, Serialize};
-use serde_json::{from_str, to_string};
+use serde_json::from_str;
 
 #[derive(Debug)]
 pub(crate) enum Method<T: Serialize> {
@@ -12,20 +16,213 @@ pub(crate) enum Method<T: Serialize> {
     Delete,
 }
 
+#[cfg(feature = "metrics")]
+impl<T: Serialize> Method<T> {
+    fn name(&self) -> &'static str {
+        match self {
+            Method::Get(_) => "GET",
+            Method::Post(_) => "POST",
+            Method::Patch(_) => "PATCH",
+            Method::Put(_) => "PUT",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
+fn configure_proxy(
+    builder: isahc::http::request::Builder,
+    client: &Client,
+) -> Result<isahc::http::request::Builder, Error> {
+    use isahc::config::Configurable;
+
+    let proxy = &client.proxy;
+    let builder = match (&proxy.url, proxy.system) {
+        (Some(url), _) => builder.proxy(Some(url.parse().map_err(|_| Error::InvalidRequest)?)),
+        // curl reads the proxy environment variables unless we explicitly disable the proxy
+        (None, false) => builder.proxy(None),
+        (None, true) => builder,
+    };
+
+    if proxy.no_proxy.is_empty() {
+        Ok(builder)
+    } else {
+        Ok(builder.proxy_blacklist(proxy.no_proxy.iter().cloned()))
+    }
+}
+
 pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static>(
     url: &str,
-    apikey: &str,
+    client: &Client,
     method: Method<Input>,
     expected_status_code: u16,
 ) -> Result<Output, Error> {
+    let method = &method;
+    abortable(
+        client,
+        with_api_key(client, move |api_key| {
+            request_with_key(url, client, method, expected_status_code, api_key)
+        }),
+    )
+    .await
+}
+
+/// Run `request`, aborting it when the client is dropped if it was built [with abort on drop](crate::client::ClientBuilder::with_abort_on_drop).
+async fn abortable<T>(
+    client: &Client,
+    request: impl std::future::Future<Output = Result<T, Error>>,
+) -> Result<T, Error> {
+    match &client.in_flight {
+        Some(in_flight) => in_flight.run(request).await,
+        None => request.await,
+    }
+}
+
+/// Call `send` with the API key of the client.
+///
+/// With a [key provider](crate::client::ClientBuilder::with_key_provider), the key is refreshed and `send` is
+/// called again once when Meilisearch rejects the key, ie because it expired.
+async fn with_api_key<T, F, Fut>(client: &Client, send: F) -> Result<T, Error>
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<T, Error>>,
+{
+    let provider = match &client.key_provider {
+        Some(provider) => provider,
+        None => return send(client.api_key.clone()).await,
+    };
+
+    let api_key = provider.current().await?;
+    match send(api_key.clone()).await {
+        Err(Error::Meilisearch(MeilisearchError {
+            error_code: ErrorCode::InvalidApiKey,
+            ..
+        })) => {
+            warn!("The API key has been rejected, refreshing it");
+            send(provider.refresh(&api_key).await?).await
+        }
+        result => result,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn request_with_key<Input: Serialize, Output: DeserializeOwned + 'static>(
+    url: &str,
+    client: &Client,
+    method: &Method<Input>,
+    expected_status_code: u16,
+    api_key: String,
+) -> Result<Output, Error> {
+    let mut response = send_request(url, client, method, &api_key).await?;
+
+    let status = response.status().as_u16();
+    let mut body = read_body(&mut response, client).await?;
+    if body.is_empty() {
+        body = "null".to_string();
+    }
+
+    parse_response(status, expected_status_code, body)
+}
+
+/// Send a search request and deserialize its response.
+///
+/// When the client [streams the hits](crate::client::ClientBuilder::with_streamed_hits), they are deserialized
+/// one by one while the response is read instead of buffering the whole response first.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn request_search<Input: Serialize, T: DeserializeOwned + 'static>(
+    url: &str,
+    client: &Client,
+    method: Method<Input>,
+    expected_status_code: u16,
+) -> Result<SearchResults<T>, Error> {
+    if let Some(postprocessor) = &client.search_postprocessor {
+        let response = request(url, client, method, expected_status_code).await?;
+        return postprocessor.apply(response);
+    }
+    if !client.stream_hits {
+        return request(url, client, method, expected_status_code).await;
+    }
+
+    let method = &method;
+    abortable(
+        client,
+        with_api_key(client, move |api_key| {
+            stream_search(url, client, method, expected_status_code, api_key)
+        }),
+    )
+    .await
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn stream_search<Input: Serialize, T: DeserializeOwned + 'static>(
+    url: &str,
+    client: &Client,
+    method: &Method<Input>,
+    expected_status_code: u16,
+    api_key: String,
+) -> Result<SearchResults<T>, Error> {
+    use futures::io::AsyncReadExt;
+
+    let mut response = send_request(url, client, method, &api_key).await?;
+    let status = response.status().as_u16();
+    if status != expected_status_code {
+        let body = read_body(&mut response, client).await?;
+        return parse_response(status, expected_status_code, body);
+    }
+    if let Some(limit) = client.max_response_bytes {
+        check_content_length(response.body().len(), limit)?;
+    }
+
+    let mut splitter = HitsSplitter::default();
+    let mut hits = Vec::new();
+    let mut chunk = vec![0; 8 * 1024];
+    let mut read = 0;
+    loop {
+        let size = response
+            .body_mut()
+            .read(&mut chunk)
+            .await
+            .map_err(|e| Error::HttpError(e.into()))?;
+        if size == 0 {
+            break;
+        }
+        read += size;
+        if let Some(limit) = client.max_response_bytes {
+            if read > limit {
+                return Err(Error::ResponseTooLarge { limit });
+            }
+        }
+        splitter.feed(&chunk[..size], |hit| {
+            hits.push(
+                serde_json::from_slice(hit).map_err(|e| parse_error::<SearchResult<T>>(hit, e))?,
+            );
+            Ok(())
+        })?;
+    }
+
+    let mut results: SearchResults<T> = serde_json::from_slice(&splitter.rest)
+        .map_err(|e| parse_error::<SearchResults<T>>(&splitter.rest, e))?;
+    results.hits = hits;
+    trace!("Request succeed");
+    Ok(results)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn send_request<Input: Serialize>(
+    url: &str,
+    client: &Client,
+    method: &Method<Input>,
+    api_key: &str,
+) -> Result<isahc::Response<isahc::AsyncBody>, Error> {
     use isahc::http::header;
     use isahc::*;
 
-    let auth = format!("Bearer {}", apikey);
+    let auth = format!("Bearer {}", api_key);
     let user_agent = qualified_version();
 
-    let mut response = match &method {
+    #[cfg(feature = "metrics")]
+    let stopwatch = crate::metrics::Stopwatch::start();
+    let response = match method {
         Method::Get(query) => {
             let query = yaup::to_string(query)?;
 
@@ -35,73 +232,114 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
                 format!("{}?{}", url, query)
             };
 
-            Request::get(url)
+            configure_proxy(Request::get(url), client)?
                 .header(header::AUTHORIZATION, auth)
                 .header(header::USER_AGENT, user_agent)
                 .body(())
                 .map_err(|_| crate::errors::Error::InvalidRequest)?
                 .send_async()
-                .await?
         }
-        Method::Delete => {
-            Request::delete(url)
-                .header(header::AUTHORIZATION, auth)
-                .header(header::USER_AGENT, user_agent)
-                .body(())
-                .map_err(|_| crate::errors::Error::InvalidRequest)?
-                .send_async()
-                .await?
-        }
-        Method::Post(body) => {
-            Request::post(url)
-                .header(header::AUTHORIZATION, auth)
-                .header(header::CONTENT_TYPE, "application/json")
-                .header(header::USER_AGENT, user_agent)
-                .body(to_string(&body).unwrap())
-                .map_err(|_| crate::errors::Error::InvalidRequest)?
-                .send_async()
-                .await?
-        }
-        Method::Patch(body) => {
-            Request::patch(url)
-                .header(header::AUTHORIZATION, auth)
-                .header(header::CONTENT_TYPE, "application/json")
-                .header(header::USER_AGENT, user_agent)
-                .body(to_string(&body).unwrap())
-                .map_err(|_| crate::errors::Error::InvalidRequest)?
-                .send_async()
-                .await?
-        }
-        Method::Put(body) => {
-            Request::put(url)
-                .header(header::AUTHORIZATION, auth)
-                .header(header::CONTENT_TYPE, "application/json")
-                .header(header::USER_AGENT, user_agent)
-                .body(to_string(&body).unwrap())
-                .map_err(|_| crate::errors::Error::InvalidRequest)?
-                .send_async()
-                .await?
+        Method::Delete => configure_proxy(Request::delete(url), client)?
+            .header(header::AUTHORIZATION, auth)
+            .header(header::USER_AGENT, user_agent)
+            .body(())
+            .map_err(|_| crate::errors::Error::InvalidRequest)?
+            .send_async(),
+        Method::Post(body) => configure_proxy(Request::post(url), client)?
+            .header(header::AUTHORIZATION, auth)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::USER_AGENT, user_agent)
+            .body(to_json(body))
+            .map_err(|_| crate::errors::Error::InvalidRequest)?
+            .send_async(),
+        Method::Patch(body) => configure_proxy(Request::patch(url), client)?
+            .header(header::AUTHORIZATION, auth)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::USER_AGENT, user_agent)
+            .body(to_json(body))
+            .map_err(|_| crate::errors::Error::InvalidRequest)?
+            .send_async(),
+        Method::Put(body) => configure_proxy(Request::put(url), client)?
+            .header(header::AUTHORIZATION, auth)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::USER_AGENT, user_agent)
+            .body(to_json(body))
+            .map_err(|_| crate::errors::Error::InvalidRequest)?
+            .send_async(),
+    }
+    .await;
+
+    #[cfg(feature = "metrics")]
+    client.metrics.record_request(
+        url,
+        &client.host,
+        method.name(),
+        response
+            .as_ref()
+            .ok()
+            .map(|response| response.status().as_u16()),
+        stopwatch,
+    );
+
+    response.map_err(crate::errors::Error::from)
+}
+
+/// Read the body of a response, without reading more than the
+/// [maximum size](crate::client::ClientBuilder::with_max_response_bytes) of the client.
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_body(
+    response: &mut isahc::Response<isahc::AsyncBody>,
+    client: &Client,
+) -> Result<String, Error> {
+    use futures::io::AsyncReadExt;
+    use isahc::AsyncReadResponseExt;
+
+    let limit = match client.max_response_bytes {
+        Some(limit) => limit,
+        None => {
+            return response
+                .text()
+                .await
+                .map_err(|e| crate::errors::Error::HttpError(e.into()))
         }
     };
+    check_content_length(response.body().len(), limit)?;
 
-    let status = response.status().as_u16();
-    let mut body = response
-        .text()
+    let mut body = Vec::new();
+    // reading one more byte than the limit tells if the body goes beyond it
+    response
+        .body_mut()
+        .take(limit as u64 + 1)
+        .read_to_end(&mut body)
         .await
         .map_err(|e| crate::errors::Error::HttpError(e.into()))?;
-    if body.is_empty() {
-        body = "null".to_string();
+    if body.len() > limit {
+        return Err(Error::ResponseTooLarge { limit });
     }
 
-    parse_response(status, expected_status_code, body)
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+fn check_content_length(content_length: Option<u64>, limit: usize) -> Result<(), Error> {
+    match content_length {
+        Some(length) if length > limit as u64 => {
+            error!(
+                "The response of {} bytes is larger than the limit of {} bytes",
+                length, limit
+            );
+            Err(Error::ResponseTooLarge { limit })
+        }
+        _ => Ok(()),
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
-pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static>(
+async fn request_with_key<Input: Serialize, Output: DeserializeOwned + 'static>(
     url: &str,
-    apikey: &str,
-    method: Method<Input>,
+    client: &Client,
+    method: &Method<Input>,
     expected_status_code: u16,
+    api_key: String,
 ) -> Result<Output, Error> {
     use wasm_bindgen::JsValue;
     use wasm_bindgen_futures::JsFuture;
@@ -114,7 +352,7 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
     let mut mut_url = url.clone().to_string();
     let headers = Headers::new().unwrap();
     headers
-        .append("Authorization", format!("Bearer {}", apikey).as_str())
+        .append("Authorization", format!("Bearer {}", api_key).as_str())
         .unwrap();
     headers
         .append("X-Meilisearch-Client", qualified_version().as_str())
@@ -123,7 +361,7 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
     let mut request: RequestInit = RequestInit::new();
     request.headers(&headers);
 
-    match &method {
+    match method {
         Method::Get(query) => {
             let query = yaup::to_string(query)?;
 
@@ -139,30 +377,52 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
         Method::Patch(body) => {
             request.method("PATCH");
             headers.append(CONTENT_TYPE, JSON).unwrap();
-            request.body(Some(&JsValue::from_str(&to_string(body).unwrap())));
+            request.body(Some(&JsValue::from_str(&to_json(body))));
         }
         Method::Post(body) => {
             request.method("POST");
             headers.append(CONTENT_TYPE, JSON).unwrap();
-            request.body(Some(&JsValue::from_str(&to_string(body).unwrap())));
+            request.body(Some(&JsValue::from_str(&to_json(body))));
         }
         Method::Put(body) => {
             request.method("PUT");
             headers.append(CONTENT_TYPE, JSON).unwrap();
-            request.body(Some(&JsValue::from_str(&to_string(body).unwrap())));
+            request.body(Some(&JsValue::from_str(&to_json(body))));
         }
     }
 
     let window = web_sys::window().unwrap(); // TODO remove this unwrap
-    let response =
-        match JsFuture::from(window.fetch_with_str_and_init(mut_url.as_str(), &request)).await {
-            Ok(response) => Response::from(response),
-            Err(e) => {
-                error!("Network error: {:?}", e);
-                return Err(Error::UnreachableServer);
-            }
-        };
+    #[cfg(feature = "metrics")]
+    let stopwatch = crate::metrics::Stopwatch::start();
+    let response = JsFuture::from(window.fetch_with_str_and_init(mut_url.as_str(), &request)).await;
+    #[cfg(feature = "metrics")]
+    client.metrics.record_request(
+        url,
+        &client.host,
+        method.name(),
+        response
+            .as_ref()
+            .ok()
+            .map(|response| Response::from(response.clone()).status()),
+        stopwatch,
+    );
+    let response = match response {
+        Ok(response) => Response::from(response),
+        Err(e) => {
+            error!("Network error: {:?}", e);
+            return Err(Error::UnreachableServer);
+        }
+    };
     let status = response.status() as u16;
+    if let Some(limit) = client.max_response_bytes {
+        let content_length = response
+            .headers()
+            .get("Content-Length")
+            .ok()
+            .flatten()
+            .and_then(|length| length.parse().ok());
+        check_content_length(content_length, limit)?;
+    }
     let text = match response.text() {
         Ok(text) => match JsFuture::from(text).await {
             Ok(text) => text,
@@ -178,6 +438,10 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
     };
 
     if let Some(t) = text.as_string() {
+        // the browser doesn't let us stop reading the body, but the limit is still enforced
+        if let Some(limit) = client.max_response_bytes {
+            check_content_length(Some(t.len() as u64), limit)?;
+        }
         if t.is_empty() {
             parse_response(status, expected_status_code, String::from("null"))
         } else {
@@ -189,6 +453,139 @@ pub(crate) async fn request<Input: Serialize, Output: DeserializeOwned + 'static
     }
 }
 
+/// Send a search request and deserialize its response.
+///
+/// The hits are never streamed since the browser only gives access to the whole response.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn request_search<Input: Serialize, T: DeserializeOwned + 'static>(
+    url: &str,
+    client: &Client,
+    method: Method<Input>,
+    expected_status_code: u16,
+) -> Result<SearchResults<T>, Error> {
+    match &client.search_postprocessor {
+        Some(postprocessor) => {
+            let response = request(url, client, method, expected_status_code).await?;
+            postprocessor.apply(response)
+        }
+        None => request(url, client, method, expected_status_code).await,
+    }
+}
+
+/// Splits a search response, as it is read, into the raw hits and the rest of the response,
+/// in which the hits are replaced by an empty array.
+#[derive(Debug, Default)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+struct HitsSplitter {
+    rest: Vec<u8>,
+    hit: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    in_hits: bool,
+    // the last string and the last key read at the top level of the response
+    string: Vec<u8>,
+    key: Vec<u8>,
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+impl HitsSplitter {
+    /// Process the next bytes of the response, calling `on_hit` with every hit fully read.
+    fn feed(
+        &mut self,
+        bytes: &[u8],
+        mut on_hit: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        for &byte in bytes {
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+
+                if self.in_hits {
+                    self.hit.push(byte);
+                } else {
+                    if self.depth == 1 && self.in_string {
+                        self.string.push(byte);
+                    }
+                    self.rest.push(byte);
+                }
+                continue;
+            }
+
+            if self.in_hits && self.depth == 2 {
+                match byte {
+                    b',' => {
+                        on_hit(&self.hit)?;
+                        self.hit.clear();
+                        continue;
+                    }
+                    b']' => {
+                        if !self.hit.is_empty() {
+                            on_hit(&self.hit)?;
+                            self.hit.clear();
+                        }
+                        self.in_hits = false;
+                        self.depth = 1;
+                        self.rest.push(byte);
+                        continue;
+                    }
+                    b' ' | b'\n' | b'\r' | b'\t' => continue,
+                    _ => (),
+                }
+            }
+
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+                    self.string.clear();
+                }
+                b':' if self.depth == 1 => self.key = std::mem::take(&mut self.string),
+                b'[' if self.depth == 1 && self.key == b"hits" => {
+                    self.in_hits = true;
+                    self.depth = 2;
+                    self.rest.push(byte);
+                    continue;
+                }
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                _ => (),
+            }
+
+            if self.in_hits {
+                self.hit.push(byte);
+            } else {
+                self.rest.push(byte);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_error<T>(body: &[u8], e: serde_json::Error) -> Error {
+    error!("Request succeeded but failed to parse response");
+    Error::parse_error::<T>(String::from_utf8_lossy(body), e)
+}
+
+/// Read the message of a `503` body, which is a Meilisearch error when it comes from Meilisearch
+/// itself but can be anything when it comes from a proxy in front of it.
+fn service_unavailable(body: String) -> Error {
+    #[derive(serde::Deserialize)]
+    struct Body {
+        message: String,
+    }
+
+    let message = match from_str::<Body>(&body) {
+        Ok(body) => body.message,
+        Err(_) => body.trim().to_string(),
+    };
+    Error::ServiceUnavailable { message }
+}
+
 fn parse_response<Output: DeserializeOwned>(
     status_code: u16,
     expected_status_code: u16,
@@ -200,24 +597,112 @@ fn parse_response<Output: DeserializeOwned>(
                 trace!("Request succeed");
                 return Ok(output);
             }
-            Err(e) => {
-                error!("Request succeeded but failed to parse response");
-                return Err(Error::ParseError(e));
-            }
+            Err(e) => return Err(parse_error::<Output>(body.as_bytes(), e)),
         };
     }
     warn!(
         "Expected response code {}, got {}",
         expected_status_code, status_code
     );
+    if status_code == 503 {
+        return Err(service_unavailable(body));
+    }
     match from_str::<MeilisearchError>(&body) {
         Ok(e) => Err(Error::from(e)),
-        Err(e) => Err(Error::ParseError(e)),
+        Err(e) => Err(Error::parse_error::<MeilisearchError>(body, e)),
     }
 }
 
+/// Serialize the body of a request, with simd-json when the `simd-json` feature is enabled.
+/// Both produce the same compact JSON.
+fn to_json<T: Serialize>(body: &T) -> String {
+    #[cfg(feature = "simd-json")]
+    return simd_json::to_string(body).unwrap();
+    #[cfg(not(feature = "simd-json"))]
+    return serde_json::to_string(body).unwrap();
+}
+
 pub fn qualified_version() -> String {
     const VERSION: Option<&str> = option_env!("CARGO_PKG_VERSION");
 
     format!("Meilisearch Rust (v{})", VERSION.unwrap_or("unknown"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_simd_json_body() {
+        use serde::Deserialize;
+
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Movie {
+            id: u64,
+            title: String,
+            rating: f64,
+            genres: Vec<String>,
+            director: Option<String>,
+        }
+
+        let movies = vec![
+            Movie {
+                id: 1,
+                title: "Amélie \"Le Fabuleux Destin\" \\ 🎬\n".to_string(),
+                rating: 8.3,
+                genres: vec!["romance".to_string(), "comedy".to_string()],
+                director: Some("Jean-Pierre Jeunet".to_string()),
+            },
+            Movie {
+                id: u64::MAX,
+                title: "\u{0}\t".to_string(),
+                rating: 1e-7,
+                genres: vec![],
+                director: None,
+            },
+        ];
+
+        let body = to_json(&movies);
+        assert_eq!(body, serde_json::to_string(&movies).unwrap());
+        assert_eq!(serde_json::from_str::<Vec<Movie>>(&body).unwrap(), movies);
+    }
+
+    #[test]
+    fn test_hits_splitter() {
+        let body = r#"{ "query": "hits", "hits" : [ {"id": 1, "title": "a [\"quoted\"] {title}"}, {"id": 2, "nested": {"hits": [1, 2]}} ],
+            "offset": 0, "facetDistribution": { "hits": { "x": 1 } } }"#;
+
+        // the splitter must not depend on how the response is cut into chunks
+        for chunk_size in 1..=body.len() {
+            let mut splitter = HitsSplitter::default();
+            let mut hits = Vec::new();
+            for chunk in body.as_bytes().chunks(chunk_size) {
+                splitter
+                    .feed(chunk, |hit| {
+                        hits.push(serde_json::from_slice::<serde_json::Value>(hit).unwrap());
+                        Ok(())
+                    })
+                    .unwrap();
+            }
+
+            assert_eq!(
+                hits,
+                vec![
+                    serde_json::json!({ "id": 1, "title": "a [\"quoted\"] {title}" }),
+                    serde_json::json!({ "id": 2, "nested": { "hits": [1, 2] } }),
+                ]
+            );
+            let rest: serde_json::Value = serde_json::from_slice(&splitter.rest).unwrap();
+            assert_eq!(
+                rest,
+                serde_json::json!({
+                    "query": "hits",
+                    "hits": [],
+                    "offset": 0,
+                    "facetDistribution": { "hits": { "x": 1 } },
+                })
+            );
+        }
+    }
+}
@@ -1,8 +1,17 @@
-use crate::{errors::Error, indexes::Index};
+use crate::{
+    client::CachedAttributes,
+    errors::Error,
+    filters::{filter_fields, is_covered, sort_field},
+    indexes::Index,
+};
 use either::Either;
 use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    ops::{Deref, DerefMut},
+};
 
 #[derive(Deserialize, Debug, Eq, PartialEq)]
 pub struct MatchRange {
@@ -14,12 +23,16 @@ pub struct MatchRange {
 #[serde(transparent)]
 pub struct Filter<'a> {
     #[serde(with = "either::serde_untagged")]
-    inner: Either<&'a str, Vec<&'a str>>,
+    pub(crate) inner: Either<Cow<'a, str>, Vec<Cow<'a, str>>>,
 }
 
 impl<'a> Filter<'a> {
     pub fn new(inner: Either<&'a str, Vec<&'a str>>) -> Filter {
-        Filter { inner }
+        Filter {
+            inner: inner
+                .map_left(Cow::Borrowed)
+                .map_right(|filters| filters.into_iter().map(Cow::Borrowed).collect()),
+        }
     }
 }
 
@@ -44,6 +57,47 @@ pub struct SearchResult<T> {
     /// The object that contains information about the matches.
     #[serde(rename = "_matchesPosition")]
     pub matches_position: Option<HashMap<String, Vec<MatchRange>>>,
+    /// The relevancy of the result, between `0.0` and `1.0`.
+    /// Only returned when [requested](SearchQuery::with_show_ranking_score).
+    #[serde(rename = "_rankingScore")]
+    pub ranking_score: Option<f64>,
+}
+
+impl<T> SearchResult<T> {
+    /// Deserialize the [formatted result](SearchResult::formatted_result), ie into the type of the documents
+    /// when all the attributes are highlighted or cropped with [Selectors::All].
+    ///
+    /// Returns `None` when no attribute was highlighted nor cropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::search::SearchResult;
+    /// # use serde::Deserialize;
+    /// # use serde_json::{json, Value};
+    /// #[derive(Deserialize)]
+    /// struct Movie {
+    ///     title: String,
+    ///     overview: String,
+    /// }
+    ///
+    /// let hit: SearchResult<Value> = serde_json::from_value(json!({
+    ///     "title": "Dune",
+    ///     "overview": "A desert planet",
+    ///     "_formatted": { "title": "<em>Dune</em>", "overview": "A <em>desert</em> planet" },
+    /// })).unwrap();
+    ///
+    /// let formatted: Movie = hit.formatted().unwrap().unwrap();
+    /// assert_eq!(formatted.title, "<em>Dune</em>");
+    /// assert_eq!(formatted.overview, "A <em>desert</em> planet");
+    /// ```
+    pub fn formatted<F: DeserializeOwned>(&self) -> Option<Result<F, Error>> {
+        self.formatted_result.as_ref().map(|formatted| {
+            let formatted = Value::Object(formatted.clone());
+            serde_json::from_value(formatted.clone())
+                .map_err(|e| Error::parse_error::<F>(formatted.to_string(), e))
+        })
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -52,18 +106,134 @@ pub struct SearchResult<T> {
 pub struct SearchResults<T> {
     /// Results of the query
     pub hits: Vec<SearchResult<T>>,
-    /// Number of documents skipped
+    /// Number of documents skipped, `0` for a [page](SearchQuery::with_page) of results
+    #[serde(default)]
     pub offset: usize,
-    /// Number of results returned
+    /// Number of results returned, `0` for a [page](SearchQuery::with_page) of results
+    #[serde(default)]
     pub limit: usize,
-    /// Total number of matches
+    /// Estimated total number of matches, `0` for a [page](SearchQuery::with_page) of results
+    #[serde(default)]
     pub estimated_total_hits: usize,
+    /// The number of the page, only returned for a [page](SearchQuery::with_page) of results
+    pub page: Option<usize>,
+    /// The number of hits of a page, only returned for a [page](SearchQuery::with_page) of results
+    pub hits_per_page: Option<usize>,
+    /// Exhaustive total number of matches, only returned for a [page](SearchQuery::with_page) of results
+    pub total_hits: Option<usize>,
+    /// Exhaustive total number of pages, only returned for a [page](SearchQuery::with_page) of results
+    pub total_pages: Option<usize>,
     /// Distribution of the given facets
     pub facet_distribution: Option<HashMap<String, HashMap<String, usize>>>,
     /// Processing time of the query
     pub processing_time_ms: usize,
     /// Query originating the response
     pub query: String,
+    /// Number of hits coming from the semantic search.
+    /// Only returned for [hybrid](SearchQuery::with_hybrid) searches.
+    pub semantic_hit_count: Option<usize>,
+    /// The vector of the query, as generated by the embedder.
+    /// Only returned when [requested](SearchQuery::with_show_query_vector), so it can be reused with
+    /// [SearchQuery::with_vector] to search again without generating it again.
+    pub query_vector: Option<Vec<f32>>,
+}
+
+impl SearchResults<Value> {
+    /// Remove the hits having the same value for `field` as a previous hit, keeping the ranking order.
+    ///
+    /// It's a client-side fallback for when the [distinct attribute](crate::settings::Settings::distinct_attribute)
+    /// can't be configured on the index. Only the hits of the returned page are deduplicated, so a later page may
+    /// still contain a value already seen, and the hits counts returned by Meilisearch are left untouched.
+    /// The hits without `field` are all kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::search::SearchResults;
+    /// # use serde_json::{json, Value};
+    /// let mut results: SearchResults<Value> = serde_json::from_value(json!({
+    ///     "hits": [
+    ///         { "id": 1, "saga": "Star Wars" },
+    ///         { "id": 2, "saga": "Star Wars" },
+    ///         { "id": 3, "saga": "Dune" },
+    ///     ],
+    ///     "offset": 0,
+    ///     "limit": 20,
+    ///     "estimatedTotalHits": 3,
+    ///     "processingTimeMs": 0,
+    ///     "query": "",
+    /// })).unwrap();
+    ///
+    /// results.dedup_by_field("saga");
+    /// let ids: Vec<_> = results.hits.iter().map(|hit| hit.result["id"].clone()).collect();
+    /// assert_eq!(ids, vec![json!(1), json!(3)]);
+    /// ```
+    pub fn dedup_by_field(&mut self, field: &str) {
+        let mut seen = HashSet::new();
+        self.hits.retain(|hit| match hit.result.get(field) {
+            // the values are compared through their JSON representation since `Value` isn't `Hash`
+            Some(value) => seen.insert(value.to_string()),
+            None => true,
+        });
+    }
+}
+
+/// A page of search results, returned by [SearchQuery::execute_page].
+///
+/// The pages are made of [hits_per_page](SearchQuery::with_hits_per_page) hits and numbered from `1`.
+/// They use the page-based pagination of Meilisearch, so the numbers of hits and pages are exhaustive.
+#[derive(Debug)]
+pub struct Page<'a, T> {
+    /// The hits of the page, empty for a page beyond the last one.
+    pub hits: Vec<SearchResult<T>>,
+    /// The number of the page, starting at `1`.
+    pub page: usize,
+    /// The maximum number of hits of a page.
+    pub hits_per_page: usize,
+    /// Total number of matches.
+    pub total_hits: usize,
+    /// Number of pages needed to show all the matches, `0` when there are none.
+    pub total_pages: usize,
+    query: SearchQuery<'a>,
+}
+
+impl<'a, T> Page<'a, T> {
+    fn new(query: SearchQuery<'a>, results: SearchResults<T>) -> Page<'a, T> {
+        Page {
+            hits: results.hits,
+            page: results.page.or(query.page).unwrap_or(1),
+            hits_per_page: results.hits_per_page.or(query.hits_per_page).unwrap_or(0),
+            total_hits: results.total_hits.unwrap_or(results.estimated_total_hits),
+            total_pages: results.total_pages.unwrap_or(0),
+            query,
+        }
+    }
+
+    /// Whether there are matches after this page.
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    /// Whether there is a page before this one.
+    pub fn has_prev(&self) -> bool {
+        self.page > 1
+    }
+
+    /// The query of the next page, `None` when this page is the last one.
+    pub fn next(&self) -> Option<SearchQuery<'a>> {
+        self.has_next().then(|| self.query_at(self.page + 1))
+    }
+
+    /// The query of the previous page, `None` when this page is the first one.
+    pub fn prev(&self) -> Option<SearchQuery<'a>> {
+        self.has_prev().then(|| self.query_at(self.page - 1))
+    }
+
+    fn query_at(&self, page: usize) -> SearchQuery<'a> {
+        let mut query = self.query.clone();
+        query.with_page(page);
+        query
+    }
 }
 
 fn serialize_with_wildcard<S: Serializer, T: Serialize>(
@@ -112,6 +282,18 @@ pub enum Selectors<T> {
 
 type AttributeToCrop<'a> = (&'a str, Option<usize>);
 
+/// Parameters of a search mixing the keyword and the semantic search.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HybridSearch<'a> {
+    /// The embedder used to generate the vector of the query.
+    pub embedder: &'a str,
+    /// The balance between the keyword search (`0.0`) and the semantic search (`1.0`).
+    ///
+    /// Default: `0.5`
+    pub semantic_ratio: f32,
+}
+
 /// A struct representing a query.
 /// You can add search parameters using the builder syntax.
 /// See [this page](https://docs.meilisearch.com/reference/features/search_parameters.html#query-q) for the official list and description of all parameters.
@@ -170,14 +352,58 @@ type AttributeToCrop<'a> = (&'a str, Option<usize>);
 ///     .build(); // you can also execute() instead of build()
 /// ```
 #[derive(Debug, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
 pub struct SearchQuery<'a> {
     #[serde(skip_serializing)]
     index: &'a Index,
+    #[serde(flatten)]
+    params: SearchParams<'a>,
+    #[serde(skip_serializing)]
+    normalize_vector: bool,
+}
+
+impl<'a> Deref for SearchQuery<'a> {
+    type Target = SearchParams<'a>;
+
+    fn deref(&self) -> &SearchParams<'a> {
+        &self.params
+    }
+}
+
+impl<'a> DerefMut for SearchQuery<'a> {
+    fn deref_mut(&mut self) -> &mut SearchParams<'a> {
+        &mut self.params
+    }
+}
+
+/// The parameters of a search, detached from any [Index].
+/// All the fields of a [SearchQuery] can also be accessed through its parameters.
+///
+/// It's used to search an index from its uid with [Client::search](crate::client::Client::search).
+/// You can build this struct using the builder syntax.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::SearchParams;
+/// let params = SearchParams::new()
+///     .with_query("space")
+///     .with_limit(21);
+///
+/// // OR
+///
+/// let params = SearchParams {
+///     query: Some("space".into()),
+///     limit: Some(21),
+///     ..SearchParams::new()
+/// };
+/// ```
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", remote = "Self")]
+pub struct SearchParams<'a> {
     /// The text that will be searched for among the documents.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "q")]
-    pub query: Option<&'a str>,
+    pub query: Option<Cow<'a, str>>,
     /// The number of documents to skip.
     /// If the value of the parameter `offset` is `n`, the `n` first documents (ordered by relevance) will not be returned.
     /// This is helpful for pagination.
@@ -193,6 +419,16 @@ pub struct SearchQuery<'a> {
     /// Default: `20`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
+    /// The page of results to return, starting at `1`.
+    /// When it or [hits_per_page](#structfield.hits_per_page) is set, the `offset` and the `limit` are ignored
+    /// and the [total number of hits](SearchResults::total_hits) is exhaustive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    /// The maximum number of hits of a [page](#structfield.page).
+    ///
+    /// Default: `20`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hits_per_page: Option<usize>,
     /// Filter applied to documents.
     /// Read the [dedicated guide](https://docs.meilisearch.com/reference/features/filtering.html) to learn the syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -206,7 +442,7 @@ pub struct SearchQuery<'a> {
     pub facets: Option<Selectors<&'a [&'a str]>>,
     /// Attributes to sort.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<&'a [&'a str]>,
+    pub sort: Option<Vec<Cow<'a, str>>>,
     /// Attributes to display in the returned documents.
     ///
     /// Can be set to a [wildcard value](enum.Selectors.html#variant.All) that will select all existing attributes.
@@ -256,10 +492,62 @@ pub struct SearchQuery<'a> {
     /// Default: `false`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub show_matches_position: Option<bool>,
+    /// Defines whether the [ranking score](SearchResult::ranking_score) of the documents should be returned or not.
+    ///
+    /// Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_ranking_score: Option<bool>,
 
     /// Defines the strategy on how to handle queries containing multiple words.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub matching_strategy: Option<MatchingStrategies>,
+
+    /// Vector used to search for semantically similar documents.
+    /// See the [vectors](crate::vectors) module to prepare it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector: Option<Vec<f32>>,
+
+    /// Mix the keyword search with a semantic search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hybrid: Option<HybridSearch<'a>>,
+
+    /// Defines whether the [vector of the query](SearchResults::query_vector) should be returned or not.
+    ///
+    /// Default: `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_query_vector: Option<bool>,
+
+    /// Words the returned documents must not contain.
+    /// They are appended to the [query](#structfield.query) as `-word` operators when the search is sent.
+    #[serde(skip)]
+    pub negative_terms: Option<&'a [&'a str]>,
+    /// Phrases the returned documents must not contain.
+    /// They are appended to the [query](#structfield.query) as `-"some phrase"` operators when the search is sent.
+    #[serde(skip)]
+    pub negative_phrases: Option<&'a [&'a str]>,
+
+    /// Send the search with a `GET` request instead of a `POST`, so its response can be cached by a CDN or a proxy.
+    /// The parameters are sorted in the URL, the identical searches share the same cache entry.
+    ///
+    /// Default: `false`
+    #[serde(skip)]
+    pub cacheable: bool,
+}
+
+// The negative operators are merged into the query when the parameters are serialized.
+impl Serialize for SearchParams<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.query_with_negatives() {
+            Some(Cow::Owned(query)) => SearchParams::serialize(
+                &SearchParams {
+                    query: Some(Cow::Owned(query)),
+                    ..self.clone()
+                },
+                serializer,
+            ),
+            _ => SearchParams::serialize(self, serializer),
+        }
+    }
 }
 
 #[allow(missing_docs)]
@@ -267,25 +555,44 @@ impl<'a> SearchQuery<'a> {
     pub fn new(index: &'a Index) -> SearchQuery<'a> {
         SearchQuery {
             index,
-            query: None,
-            offset: None,
-            limit: None,
-            filter: None,
-            sort: None,
-            facets: None,
-            attributes_to_retrieve: None,
-            attributes_to_crop: None,
-            crop_length: None,
-            crop_marker: None,
-            attributes_to_highlight: None,
-            highlight_pre_tag: None,
-            highlight_post_tag: None,
-            show_matches_position: None,
-            matching_strategy: None,
+            params: SearchParams::new(),
+            normalize_vector: false,
         }
     }
+    /// Build a query searching `index` with the parameters of a URL query string,
+    /// in the format of the `GET` search route of Meilisearch, ie `q=space&limit=20&filter=genre%20%3D%20action&sort=year:desc,title:asc`.
+    ///
+    /// It's meant to forward the parameters of a frontend, see [SearchParams::from_query_string].
+    /// The query owns the decoded parameters, so it can be built from a string that doesn't outlive it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// # use serde_json::json;
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// # let movies = client.index("movies");
+    /// let mut query =
+    ///     SearchQuery::from_query_string(&movies, &String::from("q=space&limit=5&sort=year%3Adesc")).unwrap();
+    /// query.with_offset(10);
+    ///
+    /// assert_eq!(
+    ///     query.to_request_body(),
+    ///     json!({ "q": "space", "limit": 5, "offset": 10, "sort": ["year:desc"] })
+    /// );
+    /// ```
+    pub fn from_query_string(
+        index: &'a Index,
+        query_string: &str,
+    ) -> Result<SearchQuery<'a>, Error> {
+        Ok(SearchQuery {
+            index,
+            params: SearchParams::from_query_string(query_string)?,
+            normalize_vector: false,
+        })
+    }
     pub fn with_query<'b>(&'b mut self, query: &'a str) -> &'b mut SearchQuery<'a> {
-        self.query = Some(query);
+        self.query = Some(Cow::Borrowed(query));
         self
     }
 
@@ -297,6 +604,16 @@ impl<'a> SearchQuery<'a> {
         self.limit = Some(limit);
         self
     }
+    /// Return the given page of results, starting at `1`, with exhaustive [numbers of hits and pages](SearchResults::total_pages).
+    pub fn with_page<'b>(&'b mut self, page: usize) -> &'b mut SearchQuery<'a> {
+        self.page = Some(page);
+        self
+    }
+    /// The maximum number of hits of a [page](SearchQuery::with_page).
+    pub fn with_hits_per_page<'b>(&'b mut self, hits_per_page: usize) -> &'b mut SearchQuery<'a> {
+        self.hits_per_page = Some(hits_per_page);
+        self
+    }
     pub fn with_filter<'b>(&'b mut self, filter: &'a str) -> &'b mut SearchQuery<'a> {
         self.filter = Some(Filter::new(Either::Left(filter)));
         self
@@ -313,7 +630,7 @@ impl<'a> SearchQuery<'a> {
         self
     }
     pub fn with_sort<'b>(&'b mut self, sort: &'a [&'a str]) -> &'b mut SearchQuery<'a> {
-        self.sort = Some(sort);
+        self.sort = Some(sort.iter().copied().map(Cow::Borrowed).collect());
         self
     }
     pub fn with_attributes_to_retrieve<'b>(
@@ -366,6 +683,13 @@ impl<'a> SearchQuery<'a> {
         self.show_matches_position = Some(show_matches_position);
         self
     }
+    pub fn with_show_ranking_score<'b>(
+        &'b mut self,
+        show_ranking_score: bool,
+    ) -> &'b mut SearchQuery<'a> {
+        self.show_ranking_score = Some(show_ranking_score);
+        self
+    }
     pub fn with_matching_strategy<'b>(
         &'b mut self,
         matching_strategy: MatchingStrategies,
@@ -373,6 +697,105 @@ impl<'a> SearchQuery<'a> {
         self.matching_strategy = Some(matching_strategy);
         self
     }
+    /// Exclude the documents containing any of these words.
+    ///
+    /// The words are appended to the query as `-word` operators, so they don't need to be escaped:
+    /// the `"` and the leading `-` are dropped, and a term made of several words excludes each of them.
+    /// The excluded words are always enforced, whatever the [matching strategy](MatchingStrategies),
+    /// which only decides which of the other words must match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// # use serde_json::json;
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.index("movies");
+    /// let mut query = movies.search();
+    /// query.with_query("space").with_negative_terms(&["alien"]);
+    ///
+    /// assert_eq!(query.to_request_body(), json!({ "q": "space -alien" }));
+    /// ```
+    pub fn with_negative_terms<'b>(
+        &'b mut self,
+        negative_terms: &'a [&'a str],
+    ) -> &'b mut SearchQuery<'a> {
+        self.negative_terms = Some(negative_terms);
+        self
+    }
+    /// Exclude the documents containing any of these phrases.
+    ///
+    /// The phrases are appended to the query as `-"some phrase"` operators.
+    /// Meilisearch can't escape a `"` inside a phrase, so the `"` are treated as spaces, which they already are for the search.
+    /// Like the [negative terms](SearchQuery::with_negative_terms), they are enforced whatever the matching strategy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// # use serde_json::json;
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.index("movies");
+    /// let mut query = movies.search();
+    /// query.with_query("space").with_negative_phrases(&["alien invasion"]);
+    ///
+    /// assert_eq!(query.to_request_body(), json!({ "q": "space -\"alien invasion\"" }));
+    /// ```
+    pub fn with_negative_phrases<'b>(
+        &'b mut self,
+        negative_phrases: &'a [&'a str],
+    ) -> &'b mut SearchQuery<'a> {
+        self.negative_phrases = Some(negative_phrases);
+        self
+    }
+    /// Search with a vector.
+    ///
+    /// The vector is validated when the query is executed: a vector containing a `NaN` or an
+    /// infinite value is rejected with [Error::InvalidVector] before anything is sent.
+    pub fn with_vector<'b>(&'b mut self, vector: &[f32]) -> &'b mut SearchQuery<'a> {
+        let mut vector = vector.to_vec();
+        if self.normalize_vector {
+            crate::vectors::normalize(&mut vector);
+        }
+        self.vector = Some(vector);
+        self
+    }
+    /// Normalize the vector given to [SearchQuery::with_vector] before sending it.
+    ///
+    /// Default: `false`
+    pub fn with_vector_normalization<'b>(&'b mut self, normalize: bool) -> &'b mut SearchQuery<'a> {
+        self.normalize_vector = normalize;
+        if let (true, Some(vector)) = (normalize, &mut self.vector) {
+            crate::vectors::normalize(vector);
+        }
+        self
+    }
+    pub fn with_hybrid<'b>(
+        &'b mut self,
+        embedder: &'a str,
+        semantic_ratio: f32,
+    ) -> &'b mut SearchQuery<'a> {
+        self.hybrid = Some(HybridSearch {
+            embedder,
+            semantic_ratio,
+        });
+        self
+    }
+    pub fn with_show_query_vector<'b>(
+        &'b mut self,
+        show_query_vector: bool,
+    ) -> &'b mut SearchQuery<'a> {
+        self.show_query_vector = Some(show_query_vector);
+        self
+    }
+    /// Send the search with a `GET` request and its parameters in the URL when `cacheable` is `true`,
+    /// so its response can be cached by a CDN or a proxy, instead of the default `POST` request.
+    ///
+    /// The searches of an index with [base filters](Index::with_base_filter) are always sent with a `POST` request.
+    pub fn with_cacheable<'b>(&'b mut self, cacheable: bool) -> &'b mut SearchQuery<'a> {
+        self.cacheable = cacheable;
+        self
+    }
     pub fn build(&mut self) -> SearchQuery<'a> {
         self.clone()
     }
@@ -382,11 +805,651 @@ impl<'a> SearchQuery<'a> {
     ) -> Result<SearchResults<T>, Error> {
         self.index.execute_query::<T>(self).await
     }
+
+    /// Execute the query and fetch the results as a [Page], which builds the queries of the adjacent pages.
+    ///
+    /// When the query has no [page](SearchQuery::with_page), the page containing its offset is fetched.
+    /// Without [hits_per_page](SearchQuery::with_hits_per_page), its limit is used as the number of hits per page.
+    /// A page beyond the last one has no hits, it isn't an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Serialize, Deserialize};
+    /// # use meilisearch_sdk::{client::*, indexes::*, search::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// #[derive(Serialize, Deserialize, Debug)]
+    /// struct Movie {
+    ///     id: usize,
+    ///     name: String,
+    /// }
+    ///
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # let movies = client.index("execute_page");
+    /// # let movie_list = (0..5).map(|id| Movie { id, name: format!("movie {}", id) }).collect::<Vec<_>>();
+    /// # movies.add_documents(&movie_list, Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let query = movies.search().with_hits_per_page(2).build();
+    /// let mut page = query.execute_page::<Movie>().await.unwrap();
+    /// assert_eq!((page.page, page.total_pages), (1, 3));
+    ///
+    /// while let Some(next) = page.next() {
+    ///     page = next.execute_page::<Movie>().await.unwrap();
+    /// }
+    /// assert_eq!(page.page, 3);
+    /// assert!(page.has_prev() && !page.has_next());
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn execute_page<T: 'static + DeserializeOwned>(&self) -> Result<Page<'a, T>, Error> {
+        let mut query = self.clone();
+        // without a page, the offset and the limit of the query are turned into the page containing the offset
+        let hits_per_page = query.hits_per_page.or(query.limit).unwrap_or(20);
+        let page = query.page.unwrap_or_else(|| {
+            query
+                .offset
+                .unwrap_or(0)
+                .checked_div(hits_per_page)
+                .unwrap_or(0)
+                + 1
+        });
+        query.with_page(page).with_hits_per_page(hits_per_page);
+        query.offset = None;
+        query.limit = None;
+
+        let results = self.index.execute_query::<T>(&query).await?;
+        Ok(Page::new(query, results))
+    }
+
+    /// Like [SearchQuery::execute], but first check that the fields used by the filter and the sort are
+    /// filterable and sortable, failing with [Error::AttributeNotFilterable] or [Error::AttributeNotSortable] otherwise.
+    ///
+    /// The filterable and sortable attributes are fetched once then cached by the [Client](crate::client::Client).
+    /// When a field is missing from the cached attributes, they are fetched again before failing,
+    /// in case the settings changed since.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, errors::Error, indexes::*, search::*};
+    /// # use serde_json::Value;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("execute_checked");
+    ///
+    /// match movies.search().with_filter("genres = horror").execute_checked::<Value>().await {
+    ///     Ok(results) => println!("{:?}", results.hits),
+    ///     Err(Error::AttributeNotFilterable { field, configured }) => {
+    ///         println!("{} is not filterable, only {:?} are", field, configured)
+    ///     }
+    ///     Err(error) => panic!("{}", error),
+    /// }
+    /// # });
+    /// ```
+    pub async fn execute_checked<T: 'static + DeserializeOwned>(
+        &'a self,
+    ) -> Result<SearchResults<T>, Error> {
+        let filter_fields: Vec<String> = match self.filter.as_ref().map(|filter| &filter.inner) {
+            Some(Either::Left(filter)) => filter_fields(filter),
+            Some(Either::Right(filters)) => filters
+                .iter()
+                .flat_map(|filter| filter_fields(filter))
+                .collect(),
+            None => Vec::new(),
+        };
+        let sort_fields: Vec<&str> = self
+            .sort
+            .iter()
+            .flatten()
+            .map(|criterion| sort_field(criterion))
+            .collect();
+
+        if !filter_fields.is_empty() || !sort_fields.is_empty() {
+            let cache = &self.index.client.attributes_cache;
+            let up_to_date = match cache.get(&self.index.uid) {
+                Some(cached) => check_attributes(&filter_fields, &sort_fields, &cached).is_ok(),
+                None => false,
+            };
+            if !up_to_date {
+                let settings = self.index.get_settings().await?;
+                let attributes = CachedAttributes {
+                    filterable: settings
+                        .filterable_attributes
+                        .into_option()
+                        .unwrap_or_default(),
+                    sortable: settings
+                        .sortable_attributes
+                        .into_option()
+                        .unwrap_or_default(),
+                };
+                cache.insert(&self.index.uid, attributes.clone());
+                check_attributes(&filter_fields, &sort_fields, &attributes)?;
+            }
+        }
+
+        self.execute().await
+    }
+}
+
+fn check_attributes(
+    filter_fields: &[String],
+    sort_fields: &[&str],
+    attributes: &CachedAttributes,
+) -> Result<(), Error> {
+    if let Some(field) = filter_fields
+        .iter()
+        .find(|field| !is_covered(field, &attributes.filterable))
+    {
+        return Err(Error::AttributeNotFilterable {
+            field: field.clone(),
+            configured: attributes.filterable.clone(),
+        });
+    }
+    if let Some(field) = sort_fields
+        .iter()
+        .find(|field| !is_covered(field, &attributes.sortable))
+    {
+        return Err(Error::AttributeNotSortable {
+            field: field.to_string(),
+            configured: attributes.sortable.clone(),
+        });
+    }
+    Ok(())
+}
+
+#[allow(missing_docs)]
+impl<'a> SearchParams<'a> {
+    /// Create search parameters that don't define anything
+    pub fn new() -> SearchParams<'a> {
+        SearchParams::default()
+    }
+    /// Read search parameters from a URL query string, with or without its leading `?`,
+    /// in the format of the `GET` search route of Meilisearch, ie `q=space&limit=20&filter=genre%20%3D%20action&sort=year:desc,title:asc`.
+    ///
+    /// It's meant to forward the parameters of a frontend: the parameters other than `q`, `offset`, `limit`, `filter` and `sort`
+    /// are rejected with [Error::UnknownQueryParameter], and the invalid or repeated ones with [Error::InvalidQueryParameter].
+    /// Several `filter` parameters can be given, the documents have to match all of them.
+    /// The decoded parameters are owned, so they don't borrow the query string.
+    pub fn from_query_string(query_string: &str) -> Result<SearchParams<'static>, Error> {
+        fn number(name: &str, value: &str) -> Result<usize, Error> {
+            value.parse().map_err(|_| Error::InvalidQueryParameter {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        }
+
+        let query_string = query_string.strip_prefix('?').unwrap_or(query_string);
+        let mut params = SearchParams::new();
+        let mut filters = Vec::new();
+        let mut sort = None;
+        for (name, value) in form_urlencoded::parse(query_string.as_bytes()) {
+            let duplicated = match name.as_ref() {
+                "q" => params
+                    .query
+                    .replace(Cow::Owned(value.to_string()))
+                    .is_some(),
+                "offset" => params.offset.replace(number(&name, &value)?).is_some(),
+                "limit" => params.limit.replace(number(&name, &value)?).is_some(),
+                "filter" => {
+                    filters.push(Cow::Owned(value.to_string()));
+                    false
+                }
+                "sort" => sort.replace(value.to_string()).is_some(),
+                _ => return Err(Error::UnknownQueryParameter(name.to_string())),
+            };
+            if duplicated {
+                return Err(Error::InvalidQueryParameter {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                });
+            }
+        }
+        params.filter = match filters.len() {
+            0 => None,
+            1 => filters.pop().map(|filter| Filter {
+                inner: Either::Left(filter),
+            }),
+            _ => Some(Filter {
+                inner: Either::Right(filters),
+            }),
+        };
+
+        if let Some(value) = sort {
+            // the commas between the parentheses of `_geoPoint(lat, lng)` don't separate two sorts
+            let mut depth = 0usize;
+            let mut start = 0;
+            let mut rules = Vec::new();
+            for (i, c) in value.char_indices() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth = depth.saturating_sub(1),
+                    ',' if depth == 0 => {
+                        rules.push(&value[start..i]);
+                        start = i + 1;
+                    }
+                    _ => (),
+                }
+            }
+            rules.push(&value[start..]);
+
+            let mut sort = Vec::new();
+            for rule in rules
+                .into_iter()
+                .map(str::trim)
+                .filter(|rule| !rule.is_empty())
+            {
+                if !(rule.ends_with(":asc") || rule.ends_with(":desc")) || depth != 0 {
+                    return Err(Error::InvalidQueryParameter {
+                        name: String::from("sort"),
+                        value: value.clone(),
+                    });
+                }
+                sort.push(Cow::Owned(rule.to_string()));
+            }
+            if !sort.is_empty() {
+                params.sort = Some(sort);
+            }
+        }
+
+        Ok(params)
+    }
+    /// Get the JSON body sent to Meilisearch for this search, without sending it.
+    ///
+    /// It's also available on a [SearchQuery], which is handy to reproduce a search with `curl`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, search::*};
+    /// # use serde_json::json;
+    /// # let client = Client::new("http://localhost:7700", "masterKey");
+    /// let movies = client.index("movies");
+    /// let mut query = movies.search();
+    /// query.with_query("space").with_limit(5);
+    ///
+    /// assert_eq!(query.to_request_body(), json!({ "q": "space", "limit": 5 }));
+    /// ```
+    pub fn to_request_body(&self) -> Value {
+        // serializing the search parameters can't fail
+        serde_json::to_value(self).unwrap_or_default()
+    }
+    pub fn with_query(self, query: &'a str) -> SearchParams<'a> {
+        SearchParams {
+            query: Some(Cow::Borrowed(query)),
+            ..self
+        }
+    }
+    pub fn with_offset(self, offset: usize) -> SearchParams<'a> {
+        SearchParams {
+            offset: Some(offset),
+            ..self
+        }
+    }
+    pub fn with_limit(self, limit: usize) -> SearchParams<'a> {
+        SearchParams {
+            limit: Some(limit),
+            ..self
+        }
+    }
+    /// See [SearchQuery::with_page].
+    pub fn with_page(self, page: usize) -> SearchParams<'a> {
+        SearchParams {
+            page: Some(page),
+            ..self
+        }
+    }
+    /// See [SearchQuery::with_hits_per_page].
+    pub fn with_hits_per_page(self, hits_per_page: usize) -> SearchParams<'a> {
+        SearchParams {
+            hits_per_page: Some(hits_per_page),
+            ..self
+        }
+    }
+    pub fn with_filter(self, filter: &'a str) -> SearchParams<'a> {
+        SearchParams {
+            filter: Some(Filter::new(Either::Left(filter))),
+            ..self
+        }
+    }
+    pub fn with_array_filter(self, filter: Vec<&'a str>) -> SearchParams<'a> {
+        SearchParams {
+            filter: Some(Filter::new(Either::Right(filter))),
+            ..self
+        }
+    }
+    pub fn with_facets(self, facets: Selectors<&'a [&'a str]>) -> SearchParams<'a> {
+        SearchParams {
+            facets: Some(facets),
+            ..self
+        }
+    }
+    pub fn with_sort(self, sort: &'a [&'a str]) -> SearchParams<'a> {
+        SearchParams {
+            sort: Some(sort.iter().copied().map(Cow::Borrowed).collect()),
+            ..self
+        }
+    }
+    pub fn with_attributes_to_retrieve(
+        self,
+        attributes_to_retrieve: Selectors<&'a [&'a str]>,
+    ) -> SearchParams<'a> {
+        SearchParams {
+            attributes_to_retrieve: Some(attributes_to_retrieve),
+            ..self
+        }
+    }
+    pub fn with_attributes_to_crop(
+        self,
+        attributes_to_crop: Selectors<&'a [(&'a str, Option<usize>)]>,
+    ) -> SearchParams<'a> {
+        SearchParams {
+            attributes_to_crop: Some(attributes_to_crop),
+            ..self
+        }
+    }
+    pub fn with_crop_length(self, crop_length: usize) -> SearchParams<'a> {
+        SearchParams {
+            crop_length: Some(crop_length),
+            ..self
+        }
+    }
+    pub fn with_crop_marker(self, crop_marker: &'a str) -> SearchParams<'a> {
+        SearchParams {
+            crop_marker: Some(crop_marker),
+            ..self
+        }
+    }
+    pub fn with_attributes_to_highlight(
+        self,
+        attributes_to_highlight: Selectors<&'a [&'a str]>,
+    ) -> SearchParams<'a> {
+        SearchParams {
+            attributes_to_highlight: Some(attributes_to_highlight),
+            ..self
+        }
+    }
+    pub fn with_highlight_pre_tag(self, highlight_pre_tag: &'a str) -> SearchParams<'a> {
+        SearchParams {
+            highlight_pre_tag: Some(highlight_pre_tag),
+            ..self
+        }
+    }
+    pub fn with_highlight_post_tag(self, highlight_post_tag: &'a str) -> SearchParams<'a> {
+        SearchParams {
+            highlight_post_tag: Some(highlight_post_tag),
+            ..self
+        }
+    }
+    pub fn with_show_matches_position(self, show_matches_position: bool) -> SearchParams<'a> {
+        SearchParams {
+            show_matches_position: Some(show_matches_position),
+            ..self
+        }
+    }
+    pub fn with_show_ranking_score(self, show_ranking_score: bool) -> SearchParams<'a> {
+        SearchParams {
+            show_ranking_score: Some(show_ranking_score),
+            ..self
+        }
+    }
+    pub fn with_matching_strategy(self, matching_strategy: MatchingStrategies) -> SearchParams<'a> {
+        SearchParams {
+            matching_strategy: Some(matching_strategy),
+            ..self
+        }
+    }
+    /// See [SearchQuery::with_negative_terms].
+    pub fn with_negative_terms(self, negative_terms: &'a [&'a str]) -> SearchParams<'a> {
+        SearchParams {
+            negative_terms: Some(negative_terms),
+            ..self
+        }
+    }
+    /// See [SearchQuery::with_negative_phrases].
+    pub fn with_negative_phrases(self, negative_phrases: &'a [&'a str]) -> SearchParams<'a> {
+        SearchParams {
+            negative_phrases: Some(negative_phrases),
+            ..self
+        }
+    }
+    /// The query sent to Meilisearch, followed by the [negative terms](SearchQuery::with_negative_terms)
+    /// and the [negative phrases](SearchQuery::with_negative_phrases).
+    ///
+    /// A phrase left open by the query is closed before the operators, otherwise they would be part of it.
+    pub fn query_with_negatives(&self) -> Option<Cow<'a, str>> {
+        // the `"` start or end a phrase, they can't be part of a word
+        fn words(text: &str) -> impl Iterator<Item = &str> {
+            text.split(|c: char| c == '"' || c.is_whitespace())
+                .map(|word| word.trim_start_matches('-'))
+                .filter(|word| !word.is_empty())
+        }
+
+        let mut operators = Vec::new();
+        for term in self.negative_terms.unwrap_or_default() {
+            operators.extend(words(term).map(|word| format!("-{}", word)));
+        }
+        for phrase in self.negative_phrases.unwrap_or_default() {
+            let phrase = phrase
+                .split(|c: char| c == '"' || c.is_whitespace())
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !phrase.is_empty() {
+                operators.push(format!("-\"{}\"", phrase));
+            }
+        }
+        if operators.is_empty() {
+            return self.query.clone();
+        }
+
+        let query = self
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|query| !query.is_empty())
+            .map(|query| match query.matches('"').count() % 2 {
+                0 => String::from(query),
+                _ => format!("{}\"", query),
+            });
+        Some(Cow::Owned(
+            query
+                .into_iter()
+                .chain(operators)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ))
+    }
+    pub fn with_vector(self, vector: &[f32]) -> SearchParams<'a> {
+        SearchParams {
+            vector: Some(vector.to_vec()),
+            ..self
+        }
+    }
+    pub fn with_hybrid(self, embedder: &'a str, semantic_ratio: f32) -> SearchParams<'a> {
+        SearchParams {
+            hybrid: Some(HybridSearch {
+                embedder,
+                semantic_ratio,
+            }),
+            ..self
+        }
+    }
+    pub fn with_show_query_vector(self, show_query_vector: bool) -> SearchParams<'a> {
+        SearchParams {
+            show_query_vector: Some(show_query_vector),
+            ..self
+        }
+    }
+    /// Send the search with a `GET` request and its parameters in the URL when `cacheable` is `true`,
+    /// see [SearchQuery::with_cacheable].
+    pub fn with_cacheable(self, cacheable: bool) -> SearchParams<'a> {
+        SearchParams { cacheable, ..self }
+    }
+
+    /// The query parameters of a [cacheable](SearchParams::cacheable) search, equivalent to its [request body](SearchParams::to_request_body).
+    ///
+    /// The lists are separated by commas, the filters are combined with `AND`,
+    /// and the fields of the [hybrid search](SearchParams::hybrid) are prefixed by `hybrid`.
+    pub(crate) fn to_query_parameters(&self) -> BTreeMap<String, String> {
+        fn to_string(value: &Value) -> String {
+            match value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            }
+        }
+
+        let mut parameters = BTreeMap::new();
+        let body = match self.to_request_body() {
+            Value::Object(body) => body,
+            _ => return parameters,
+        };
+        for (name, value) in body {
+            match value {
+                Value::Null => (),
+                Value::Array(filters) if name == "filter" => {
+                    let filters = filters
+                        .iter()
+                        .map(|filter| format!("({})", to_string(filter)))
+                        .collect::<Vec<_>>();
+                    if !filters.is_empty() {
+                        parameters.insert(name, filters.join(" AND "));
+                    }
+                }
+                Value::Array(values) => {
+                    let values = values.iter().map(to_string).collect::<Vec<_>>();
+                    parameters.insert(name, values.join(","));
+                }
+                Value::Object(fields) => {
+                    for (field, value) in fields {
+                        let mut chars = field.chars();
+                        let field = chars
+                            .next()
+                            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                            .unwrap_or_default();
+                        parameters.insert(format!("{}{}", name, field), to_string(&value));
+                    }
+                }
+                value => {
+                    parameters.insert(name, to_string(&value));
+                }
+            }
+        }
+        parameters
+    }
+}
+
+/// The parameters of a [facet search](https://docs.meilisearch.com/reference/api/facet_search.html).
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::search::FacetSearchParams;
+/// let params = FacetSearchParams::new("genres")
+///     .with_facet_query("fic")
+///     .with_filter("rating > 3");
+/// ```
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetSearchParams<'a> {
+    /// The name of the facet to search in.
+    pub facet_name: &'a str,
+    /// The text searched for among the values of the facet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_query: Option<&'a str>,
+    /// Only count the documents matching this query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "q")]
+    pub query: Option<&'a str>,
+    /// Only count the documents matching this filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter<'a>>,
+    /// Defines the strategy on how to handle queries containing multiple words.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matching_strategy: Option<MatchingStrategies>,
+}
+
+#[allow(missing_docs)]
+impl<'a> FacetSearchParams<'a> {
+    pub fn new(facet_name: &'a str) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            facet_name,
+            facet_query: None,
+            query: None,
+            filter: None,
+            matching_strategy: None,
+        }
+    }
+    /// Get the JSON body sent to Meilisearch for this facet search, without sending it.
+    pub fn to_request_body(&self) -> Value {
+        // serializing the facet search parameters can't fail
+        serde_json::to_value(self).unwrap_or_default()
+    }
+    pub fn with_facet_query(self, facet_query: &'a str) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            facet_query: Some(facet_query),
+            ..self
+        }
+    }
+    pub fn with_query(self, query: &'a str) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            query: Some(query),
+            ..self
+        }
+    }
+    pub fn with_filter(self, filter: &'a str) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            filter: Some(Filter::new(Either::Left(filter))),
+            ..self
+        }
+    }
+    pub fn with_array_filter(self, filter: Vec<&'a str>) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            filter: Some(Filter::new(Either::Right(filter))),
+            ..self
+        }
+    }
+    pub fn with_matching_strategy(
+        self,
+        matching_strategy: MatchingStrategies,
+    ) -> FacetSearchParams<'a> {
+        FacetSearchParams {
+            matching_strategy: Some(matching_strategy),
+            ..self
+        }
+    }
+}
+
+/// A value of a facet and the number of documents containing it.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FacetHit {
+    /// The value of the facet
+    pub value: String,
+    /// The number of documents containing this value
+    pub count: usize,
+}
+
+/// The results of a facet search.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetSearchResponse {
+    /// The values of the facet matching the facet query
+    pub facet_hits: Vec<FacetHit>,
+    /// The facet query originating the response
+    pub facet_query: Option<String>,
+    /// Processing time of the query
+    pub processing_time_ms: usize,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{client::*, search::*};
+    use crate::{client::*, search::*, task_info::TaskInfo};
     use meilisearch_test_macro::meilisearch_test;
     use serde::{Deserialize, Serialize};
     use serde_json::{json, Map, Value};
@@ -534,6 +1597,304 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_facets_overview(client: Client, index: Index) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let facets = index.facets_overview(&["kind"], None).await?;
+        assert_eq!(facets["kind"]["title"], 8);
+        assert_eq!(facets["kind"]["text"], 2);
+
+        let facets = index
+            .facets_overview(&["kind"], Some("kind = text"))
+            .await?;
+        assert_eq!(facets["kind"].get("title"), None);
+        assert_eq!(facets["kind"]["text"], 2);
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_facets_overview_asks_for_no_hits() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let search = mockito::mock("POST", "/indexes/facets_overview/search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "limit": 0, "facets": ["kind"], "filter": "kind = text" }),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{ "hits": [], "offset": 0, "limit": 0, "estimatedTotalHits": 2, "processingTimeMs": 1, "query": "", "facetDistribution": { "kind": { "text": 2 } } }"#,
+            )
+            .create();
+
+        let facets = client
+            .index("facets_overview")
+            .facets_overview(&["kind"], Some("kind = text"))
+            .await
+            .unwrap();
+
+        search.assert();
+        assert_eq!(facets["kind"]["text"], 2);
+    }
+
+    #[meilisearch_test]
+    async fn test_search_large_integer_ids() {
+        #[derive(Deserialize, Debug)]
+        struct Message {
+            id: u64,
+        }
+
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let search = mockito::mock("POST", "/indexes/search_large_integer_ids/search")
+            .with_status(200)
+            .with_body(
+                r#"{ "hits": [{ "id": 9007199254740993 }], "offset": 0, "limit": 20, "estimatedTotalHits": 1, "processingTimeMs": 1, "query": "" }"#,
+            )
+            .create();
+
+        // the hits are flattened into SearchResult, which buffers the numbers
+        let results = client
+            .index("search_large_integer_ids")
+            .search()
+            .execute::<Message>()
+            .await
+            .unwrap();
+
+        search.assert();
+        assert_eq!(results.hits[0].result.id, 9_007_199_254_740_993);
+    }
+
+    #[meilisearch_test]
+    async fn test_execute_page_navigation() {
+        let client = Client::new(
+            format!("{}/execute_page", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let page = |page: usize, ids: &[usize]| {
+            let hits: Vec<Value> = ids.iter().map(|id| json!({ "id": id })).collect();
+            json!({ "hits": hits, "page": page, "hitsPerPage": 2, "totalHits": 5, "totalPages": 3, "processingTimeMs": 1, "query": "" })
+                .to_string()
+        };
+        let mock_page = |number: usize, response: String| {
+            mockito::mock("POST", "/execute_page/indexes/movies/search")
+                .match_body(mockito::Matcher::Json(
+                    json!({ "page": number, "hitsPerPage": 2 }),
+                ))
+                .with_status(200)
+                .with_body(response)
+        };
+        let first = mock_page(1, page(1, &[0, 1])).expect(2).create();
+        let second = mock_page(2, page(2, &[2, 3])).expect(3).create();
+        let last = mock_page(3, page(3, &[4])).expect(2).create();
+        let beyond = mock_page(6, page(6, &[])).create();
+        let ids = |page: &Page<Value>| -> Vec<Value> {
+            page.hits
+                .iter()
+                .map(|hit| hit.result["id"].clone())
+                .collect()
+        };
+
+        // the offset and the limit are turned into a page
+        let query = index.search().with_limit(2).build();
+        let page_1 = query.execute_page::<Value>().await.unwrap();
+        assert_eq!(ids(&page_1), [0, 1]);
+        assert_eq!(
+            (
+                page_1.page,
+                page_1.hits_per_page,
+                page_1.total_hits,
+                page_1.total_pages
+            ),
+            (1, 2, 5, 3)
+        );
+        assert!(page_1.has_next());
+        assert!(!page_1.has_prev());
+        assert!(page_1.prev().is_none());
+
+        let query = page_1.next().unwrap();
+        let page_2 = query.execute_page::<Value>().await.unwrap();
+        assert_eq!(ids(&page_2), [2, 3]);
+        assert_eq!(page_2.page, 2);
+        assert!(page_2.has_next() && page_2.has_prev());
+
+        let query = page_2.next().unwrap();
+        let page_3 = query.execute_page::<Value>().await.unwrap();
+        assert_eq!(ids(&page_3), [4]);
+        assert_eq!(page_3.page, 3);
+        assert!(!page_3.has_next());
+        assert!(page_3.next().is_none());
+
+        let query = page_3.prev().unwrap();
+        let page_2 = query.execute_page::<Value>().await.unwrap();
+        assert_eq!(ids(&page_2), [2, 3]);
+        assert_eq!(page_2.page, 2);
+
+        let query = index.search().with_page(6).with_hits_per_page(2).build();
+        let page_6 = query.execute_page::<Value>().await.unwrap();
+        assert!(page_6.hits.is_empty());
+        assert_eq!((page_6.page, page_6.total_pages), (6, 3));
+        assert!(!page_6.has_next());
+        assert_eq!(page_6.prev().unwrap().page, Some(5));
+
+        // the pages can be walked through by replacing the page with the next one
+        let mut page = index
+            .search()
+            .with_hits_per_page(2)
+            .execute_page::<Value>()
+            .await
+            .unwrap();
+        let mut seen = ids(&page);
+        while let Some(next) = page.next() {
+            page = next.execute_page::<Value>().await.unwrap();
+            seen.extend(ids(&page));
+        }
+        assert_eq!(seen, [0, 1, 2, 3, 4]);
+
+        first.assert();
+        second.assert();
+        last.assert();
+        beyond.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_execute_page_without_hits() {
+        let client = Client::new(
+            format!("{}/execute_empty_page", mockito::server_url()),
+            "masterKey",
+        );
+        let search = mockito::mock("POST", "/execute_empty_page/indexes/movies/search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "q": "nothing", "page": 1, "hitsPerPage": 20 }),
+            ))
+            .with_status(200)
+            .with_body(r#"{ "hits": [], "page": 1, "hitsPerPage": 20, "totalHits": 0, "totalPages": 0, "processingTimeMs": 1, "query": "nothing" }"#)
+            .create();
+
+        let index = client.index("movies");
+        let query = index.search().with_query("nothing").build();
+        let page = query.execute_page::<Value>().await.unwrap();
+
+        search.assert();
+        assert!(page.hits.is_empty());
+        assert_eq!((page.page, page.total_hits, page.total_pages), (1, 0, 0));
+        assert!(page.next().is_none());
+        assert!(page.prev().is_none());
+    }
+
+    #[meilisearch_test]
+    async fn test_cacheable_search_uses_get() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("cacheable_search");
+        let body = r#"{ "hits": [], "offset": 0, "limit": 5, "estimatedTotalHits": 0, "processingTimeMs": 1, "query": "space" }"#;
+        let get = mockito::mock("GET", "/indexes/cacheable_search/search")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("q".into(), "space".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "5".into()),
+                mockito::Matcher::UrlEncoded("sort".into(), "year:desc,title:asc".into()),
+                mockito::Matcher::UrlEncoded(
+                    "filter".into(),
+                    "(kind = movie) AND (year > 2000)".into(),
+                ),
+                mockito::Matcher::UrlEncoded("hybridEmbedder".into(), "default".into()),
+                mockito::Matcher::UrlEncoded("hybridSemanticRatio".into(), "0.5".into()),
+            ]))
+            .with_status(200)
+            .with_body(body)
+            .create();
+        let post = mockito::mock("POST", "/indexes/cacheable_search/search")
+            .match_body(mockito::Matcher::Json(json!({ "q": "space", "limit": 5 })))
+            .with_status(200)
+            .with_body(body)
+            .create();
+
+        index
+            .search()
+            .with_query("space")
+            .with_limit(5)
+            .with_sort(&["year:desc", "title:asc"])
+            .with_array_filter(vec!["kind = movie", "year > 2000"])
+            .with_hybrid("default", 0.5)
+            .with_cacheable(true)
+            .execute::<Value>()
+            .await
+            .unwrap();
+        index
+            .search()
+            .with_query("space")
+            .with_limit(5)
+            .with_cacheable(false)
+            .execute::<Value>()
+            .await
+            .unwrap();
+
+        get.assert();
+        post.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_execute_checked_refreshes_the_settings() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("execute_checked");
+        let settings = |filterable: Value| {
+            mockito::mock("GET", "/indexes/execute_checked/settings")
+                .with_status(200)
+                .with_body(
+                    json!({ "filterableAttributes": filterable, "sortableAttributes": ["year"] })
+                        .to_string(),
+                )
+                .expect(1)
+                .create()
+        };
+        let search = mockito::mock("POST", "/indexes/execute_checked/search")
+            .with_status(200)
+            .with_body(
+                r#"{ "hits": [], "offset": 0, "limit": 20, "estimatedTotalHits": 0, "processingTimeMs": 1, "query": "" }"#,
+            )
+            .expect(2)
+            .create();
+        let before = settings(json!([]));
+        let after = settings(json!(["kind"]));
+
+        let error = index
+            .search()
+            .with_filter("kind = title")
+            .execute_checked::<Value>()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::AttributeNotFilterable { field, configured } if field == "kind" && configured.is_empty()
+        ));
+        before.assert();
+
+        // the cached settings are enough
+        index
+            .search()
+            .with_sort(&["year:desc"])
+            .execute_checked::<Value>()
+            .await
+            .unwrap();
+
+        // the settings task completed since the last check, the cache is refreshed once
+        index
+            .search()
+            .with_filter("kind = title")
+            .with_sort(&["year:desc"])
+            .execute_checked::<Value>()
+            .await
+            .unwrap();
+        after.assert();
+        search.assert();
+
+        let error = index
+            .search()
+            .with_sort(&["kind:asc"])
+            .execute_checked::<Value>()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::AttributeNotSortable { field, .. } if field == "kind"));
+    }
+
     #[meilisearch_test]
     async fn test_query_facet_distribution(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -776,6 +2137,81 @@ mod tests {
         Ok(())
     }
 
+    #[meilisearch_test]
+    async fn test_query_attributes_to_highlight_wildcard(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        setup_test_index(&client, &index).await?;
+
+        let mut query = SearchQuery::new(&index);
+        query.with_query("dolor text");
+        query.with_attributes_to_highlight(Selectors::Some(&["*"]));
+        let results: SearchResults<Document> = index.execute_query(&query).await?;
+
+        // without custom tags, Meilisearch highlights with `<em>`
+        let formatted: Document = results.hits[0].formatted().unwrap()?;
+        assert_eq!(
+            formatted.value,
+            "<em>dolor</em> sit amet, consectetur adipiscing elit"
+        );
+        assert_eq!(formatted.kind, "<em>text</em>");
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_highlight_all_request() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("highlight_all");
+
+        let mock_res = mockito::mock("POST", "/indexes/highlight_all/search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "q": "dolor text", "attributesToHighlight": ["*"] }),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "hits": [{
+                        "id": 1,
+                        "value": "dolor sit amet",
+                        "kind": "text",
+                        "nested": { "child": "second" },
+                        "_formatted": {
+                            "id": "1",
+                            "value": "<em>dolor</em> sit amet",
+                            "kind": "<em>text</em>",
+                            "nested": { "child": "second" }
+                        }
+                    }],
+                    "offset": 0,
+                    "limit": 20,
+                    "estimatedTotalHits": 1,
+                    "processingTimeMs": 0,
+                    "query": "dolor text"
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create();
+
+        for selectors in [Selectors::All, Selectors::Some(&["*"][..])] {
+            let results: SearchResults<Value> = index
+                .search()
+                .with_query("dolor text")
+                .with_attributes_to_highlight(selectors)
+                .execute()
+                .await?;
+
+            let formatted: Map<String, Value> = results.hits[0].formatted().unwrap()?;
+            assert_eq!(formatted["value"], "<em>dolor</em> sit amet");
+            assert_eq!(formatted["kind"], "<em>text</em>");
+            assert_eq!(formatted["nested"], json!({ "child": "second" }));
+        }
+
+        mock_res.assert();
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_query_show_matches_position(client: Client, index: Index) -> Result<(), Error> {
         setup_test_index(&client, &index).await?;
@@ -886,4 +2322,560 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_with_vector_normalization() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("vectors");
+
+        let query = index.search().with_vector(&[3.0, 4.0]).build();
+        assert_eq!(query.vector, Some(vec![3.0, 4.0]));
+
+        let query = index
+            .search()
+            .with_vector_normalization(true)
+            .with_vector(&[3.0, 4.0])
+            .build();
+        assert_eq!(query.vector, Some(vec![0.6, 0.8]));
+
+        let query = index
+            .search()
+            .with_vector(&[0.0, 2.0])
+            .with_vector_normalization(true)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&query).unwrap()["vector"],
+            json!([0.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn test_deserialize_semantic_hit_count() {
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 20,
+            "estimatedTotalHits": 0,
+            "processingTimeMs": 1,
+            "query": "",
+            "semanticHitCount": 3
+        }))
+        .unwrap();
+        assert_eq!(results.semantic_hit_count, Some(3));
+
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 20,
+            "estimatedTotalHits": 0,
+            "processingTimeMs": 1,
+            "query": ""
+        }))
+        .unwrap();
+        assert_eq!(results.semantic_hit_count, None);
+    }
+
+    #[test]
+    fn test_query_vector() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("query_vector");
+        let query = index
+            .search()
+            .with_query("space")
+            .with_hybrid("default", 0.5)
+            .with_show_query_vector(true)
+            .build();
+        assert_eq!(query.to_request_body()["showQueryVector"], json!(true));
+
+        let results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [],
+            "offset": 0,
+            "limit": 20,
+            "estimatedTotalHits": 0,
+            "processingTimeMs": 1,
+            "query": "space",
+            "queryVector": [0.5, 0.25]
+        }))
+        .unwrap();
+        assert_eq!(results.query_vector, Some(vec![0.5, 0.25]));
+    }
+
+    #[test]
+    fn test_dedup_by_field() {
+        let mut results: SearchResults<Value> = serde_json::from_value(json!({
+            "hits": [
+                { "id": 1, "kind": "title", "author": { "name": "A" } },
+                { "id": 2, "kind": "text" },
+                { "id": 3, "kind": "title" },
+                { "id": 4 },
+                { "id": 5, "kind": "text" },
+                { "id": 6, "kind": "quote" },
+                { "id": 7 },
+            ],
+            "offset": 0,
+            "limit": 20,
+            "estimatedTotalHits": 7,
+            "processingTimeMs": 0,
+            "query": ""
+        }))
+        .unwrap();
+
+        results.dedup_by_field("kind");
+
+        let ids: Vec<&Value> = results.hits.iter().map(|hit| &hit.result["id"]).collect();
+        assert_eq!(
+            ids,
+            vec![&json!(1), &json!(2), &json!(4), &json!(6), &json!(7)]
+        );
+        assert_eq!(results.estimated_total_hits, 7);
+    }
+
+    #[test]
+    fn test_search_to_request_body() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("request_body");
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("space")
+            .with_offset(10)
+            .with_array_filter(vec!["kind = movie", "year > 2000"])
+            .with_facets(Selectors::Some(&["genres"]))
+            .with_sort(&["year:desc"])
+            .with_attributes_to_crop(Selectors::Some(&[("overview", Some(10))]))
+            .with_highlight_pre_tag("<em>")
+            .with_matching_strategy(MatchingStrategies::ALL)
+            .with_hybrid("default", 0.5);
+
+        assert_eq!(
+            query.to_request_body(),
+            json!({
+                "q": "space",
+                "offset": 10,
+                "filter": ["kind = movie", "year > 2000"],
+                "facets": ["genres"],
+                "sort": ["year:desc"],
+                "attributesToCrop": ["overview:10"],
+                "highlightPreTag": "<em>",
+                "matchingStrategy": "all",
+                "hybrid": { "embedder": "default", "semanticRatio": 0.5 },
+            })
+        );
+        assert_eq!(
+            FacetSearchParams::new("genres")
+                .with_facet_query("fic")
+                .to_request_body(),
+            json!({ "facetName": "genres", "facetQuery": "fic" })
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_client_search_from_index_uid() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+
+        let mock_res = mockito::mock("POST", "/indexes/client_search/search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "q": "Interstellar", "limit": 5, "filter": "kind = movie" }),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{ "hits": [{ "id": 1 }], "offset": 0, "limit": 5, "estimatedTotalHits": 1, "processingTimeMs": 1, "query": "Interstellar" }"#,
+            )
+            .create();
+
+        let params = SearchParams::new()
+            .with_query("Interstellar")
+            .with_limit(5)
+            .with_filter("kind = movie");
+        let results: SearchResults<Value> = client.search("client_search", params).await?;
+
+        mock_res.assert();
+        assert_eq!(results.hits.len(), 1);
+        assert_eq!(results.hits[0].result["id"], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_query_from_query_string() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("query_string");
+        // the query owns its parameters, the query string can be dropped
+        let query_string = String::from(
+            "?q=star%20wars&limit=5&offset=10&filter=genre%20%3D%20%22sci-fi%22&filter=year+%3E+1977\
+             &sort=_geoPoint(48.8,2.3):asc,%20year%3Adesc",
+        );
+        let query = SearchQuery::from_query_string(&index, &query_string).unwrap();
+        drop(query_string);
+
+        assert_eq!(query.query.as_deref(), Some("star wars"));
+        assert_eq!((query.offset, query.limit), (Some(10), Some(5)));
+        assert_eq!(
+            query.sort,
+            Some(vec![
+                Cow::from("_geoPoint(48.8,2.3):asc"),
+                Cow::from("year:desc")
+            ])
+        );
+        assert_eq!(
+            query.to_request_body(),
+            json!({
+                "q": "star wars",
+                "offset": 10,
+                "limit": 5,
+                "filter": [r#"genre = "sci-fi""#, "year > 1977"],
+                "sort": ["_geoPoint(48.8,2.3):asc", "year:desc"],
+            })
+        );
+
+        let params = SearchParams::from_query_string("filter=genre%3Daction&q=").unwrap();
+        assert_eq!(
+            params.to_request_body(),
+            json!({ "q": "", "filter": "genre=action" })
+        );
+        assert_eq!(
+            SearchParams::from_query_string("")
+                .unwrap()
+                .to_request_body(),
+            json!({})
+        );
+
+        assert!(matches!(
+            SearchParams::from_query_string("q=space&page=2"),
+            Err(Error::UnknownQueryParameter(name)) if name == "page"
+        ));
+        for (query_string, invalid) in [
+            ("limit=-1", "limit"),
+            ("offset=ten", "offset"),
+            ("limit=", "limit"),
+            ("q=a&q=b", "q"),
+            ("sort=year", "sort"),
+            ("sort=_geoPoint(1,2:asc", "sort"),
+        ] {
+            assert!(
+                matches!(
+                    SearchQuery::from_query_string(&index, query_string),
+                    Err(Error::InvalidQueryParameter { name, .. }) if name == invalid
+                ),
+                "{}",
+                query_string
+            );
+        }
+    }
+
+    #[test]
+    fn test_negative_operators() {
+        let q = |params: SearchParams| params.to_request_body()["q"].clone();
+
+        assert_eq!(
+            q(SearchParams::new()
+                .with_query("space")
+                .with_negative_terms(&["alien"])),
+            json!("space -alien")
+        );
+        assert_eq!(
+            q(SearchParams::new()
+                .with_query(r#" "outer space" travel "#)
+                .with_negative_terms(&["alien", "-robot", "time travel"])
+                .with_negative_phrases(&["star wars"])),
+            json!(r#""outer space" travel -alien -robot -time -travel -"star wars""#)
+        );
+        // the quotes can't be escaped, they would open or close a phrase
+        assert_eq!(
+            q(SearchParams::new()
+                .with_negative_terms(&[r#"wo"rd"#, r#"""#])
+                .with_negative_phrases(&[r#"the "best" movie"#, r#"" ""#])),
+            json!(r#"-wo -rd -"the best movie""#)
+        );
+        // an unbalanced quote of the query is closed, so the operators aren't part of its phrase
+        assert_eq!(
+            q(SearchParams::new()
+                .with_query(r#""outer space travel"#)
+                .with_negative_terms(&["alien"])
+                .with_negative_phrases(&["star wars"])),
+            json!(r#""outer space travel" -alien -"star wars""#)
+        );
+        assert_eq!(
+            q(SearchParams::new()
+                .with_query(r#""outer space" "travel "#)
+                .with_negative_terms(&["alien"])),
+            json!(r#""outer space" "travel" -alien"#)
+        );
+        // without any negative operator the query is sent as is
+        assert_eq!(
+            q(SearchParams::new()
+                .with_query(" space ")
+                .with_negative_terms(&[])
+                .with_negative_phrases(&["\""])),
+            json!(" space ")
+        );
+        assert_eq!(
+            SearchParams::new()
+                .with_negative_terms(&["alien"])
+                .to_request_body(),
+            json!({ "q": "-alien" })
+        );
+
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("negative_operators");
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("space")
+            .with_negative_terms(&["alien"])
+            .with_negative_phrases(&["time travel"])
+            .with_matching_strategy(MatchingStrategies::LAST);
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            json!({ "q": r#"space -alien -"time travel""#, "matchingStrategy": "last" })
+        );
+    }
+
+    #[test]
+    fn test_search_query_serializes_like_params() {
+        let client = Client::new("http://localhost:7700", "masterKey");
+        let index = client.index("serialize");
+        let mut query = SearchQuery::new(&index);
+        query
+            .with_query("space")
+            .with_offset(2)
+            .with_sort(&["year:desc"]);
+        let params = SearchParams::new()
+            .with_query("space")
+            .with_offset(2)
+            .with_sort(&["year:desc"]);
+
+        assert_eq!(
+            serde_json::to_value(&query).unwrap(),
+            serde_json::to_value(&params).unwrap()
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_facet_search() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("facet_search");
+
+        let mock_res = mockito::mock("POST", "/indexes/facet_search/facet-search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "facetName": "genres", "facetQuery": "fic", "filter": "rating > 3" }),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{ "facetHits": [{ "value": "fiction", "count": 7 }], "facetQuery": "fic", "processingTimeMs": 0 }"#,
+            )
+            .expect(2)
+            .create();
+
+        let params = FacetSearchParams::new("genres")
+            .with_facet_query("fic")
+            .with_filter("rating > 3");
+        let from_client = client.facet_search("facet_search", &params).await?;
+        let from_index = index.facet_search(&params).await?;
+
+        mock_res.assert();
+        let expected = vec![FacetHit {
+            value: "fiction".to_string(),
+            count: 7,
+        }];
+        assert_eq!(from_client.facet_hits, expected);
+        assert_eq!(from_index.facet_hits, expected);
+        assert_eq!(from_index.facet_query.as_deref(), Some("fic"));
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_facet_search_many() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("facet_search_many");
+
+        let genres = mockito::mock("POST", "/indexes/facet_search_many/facet-search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "facetName": "genres", "facetQuery": "fic" }),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{ "facetHits": [{ "value": "fiction", "count": 7 }], "facetQuery": "fic", "processingTimeMs": 0 }"#,
+            )
+            .create();
+        let directors = mockito::mock("POST", "/indexes/facet_search_many/facet-search")
+            .match_body(mockito::Matcher::Json(
+                json!({ "facetName": "director", "facetQuery": "nol", "filter": "year > 2000" }),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{ "facetHits": [{ "value": "Christopher Nolan", "count": 3 }], "facetQuery": "nol", "processingTimeMs": 0 }"#,
+            )
+            .create();
+
+        let responses = index
+            .facet_search_many(&[
+                FacetSearchParams::new("genres").with_facet_query("fic"),
+                FacetSearchParams::new("director")
+                    .with_facet_query("nol")
+                    .with_filter("year > 2000"),
+            ])
+            .await?;
+
+        genres.assert();
+        directors.assert();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(
+            responses[0].facet_hits,
+            [FacetHit {
+                value: "fiction".to_string(),
+                count: 7
+            }]
+        );
+        assert_eq!(
+            responses[1].facet_hits,
+            [FacetHit {
+                value: "Christopher Nolan".to_string(),
+                count: 3
+            }]
+        );
+        Ok(())
+    }
+
+    #[meilisearch_test]
+    async fn test_nearest_neighbors() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("nearest_neighbors");
+
+        let embedders = mockito::mock("GET", "/indexes/nearest_neighbors/settings/embedders")
+            .with_status(200)
+            .with_body(r#"{ "default": { "source": "userProvided", "dimensions": 2 } }"#)
+            .expect(2)
+            .create();
+        let search = mockito::mock("POST", "/indexes/nearest_neighbors/search")
+            .match_body(mockito::Matcher::Json(json!({
+                "q": "",
+                "vector": [1.0, 0.0],
+                "hybrid": { "embedder": "default", "semanticRatio": 1.0 },
+                "limit": 2,
+                "filter": "kind = title",
+                "showRankingScore": true
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "hits": [
+                        { "id": 0, "_rankingScore": 0.98 },
+                        { "id": 1, "_rankingScore": 0.5 }
+                    ],
+                    "offset": 0,
+                    "limit": 2,
+                    "estimatedTotalHits": 2,
+                    "processingTimeMs": 0,
+                    "query": ""
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create();
+
+        for _ in 0..2 {
+            let results = index
+                .nearest_neighbors::<Value>("default", &[1.0, 0.0], 2, Some("kind = title"))
+                .await?;
+            let scores: Vec<_> = results.hits.iter().map(|hit| hit.ranking_score).collect();
+            assert_eq!(scores, [Some(0.98), Some(0.5)]);
+        }
+
+        // the embedders are fetched again before failing
+        let error = index
+            .nearest_neighbors::<Value>("default", &[1.0, 0.0, 0.0], 2, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::VectorDimensionMismatch {
+                expected: 2,
+                found: 3
+            }
+        ));
+
+        embedders.assert();
+        search.assert();
+        Ok(())
+    }
+
+    // Needs a Meilisearch instance where the vector store is enabled.
+    #[meilisearch_test]
+    #[ignore]
+    async fn test_hybrid_search_semantic_hit_count(
+        client: Client,
+        index: Index,
+    ) -> Result<(), Error> {
+        use crate::request::{request, Method};
+
+        request::<Value, TaskInfo>(
+            &format!("{}/indexes/{}/settings", client.host, index.uid),
+            &client,
+            Method::Patch(json!({
+                "embedders": { "default": { "source": "userProvided", "dimensions": 2 } }
+            })),
+            202,
+        )
+        .await?
+        .wait_for_completion(&client, None, None)
+        .await?;
+        index
+            .add_documents(
+                &[
+                    json!({ "id": 0, "value": "first", "_vectors": { "default": [1.0, 0.0] } }),
+                    json!({ "id": 1, "value": "second", "_vectors": { "default": [0.0, 1.0] } }),
+                ],
+                None,
+            )
+            .await?
+            .wait_for_completion(&client, None, None)
+            .await?;
+
+        let results: SearchResults<Value> = index
+            .search()
+            .with_vector(&[1.0, 0.0])
+            .with_hybrid("default", 1.0)
+            .execute()
+            .await?;
+        assert!(results.semantic_hit_count.is_some());
+
+        let results: SearchResults<Value> = index.search().with_query("first").execute().await?;
+        assert_eq!(results.semantic_hit_count, None);
+        Ok(())
+    }
+
+    // Needs a Meilisearch instance where the vector store is enabled.
+    #[meilisearch_test]
+    #[ignore]
+    async fn test_hybrid_search_query_vector(client: Client, index: Index) -> Result<(), Error> {
+        use crate::request::{request, Method};
+
+        request::<Value, TaskInfo>(
+            &format!("{}/indexes/{}/settings", client.host, index.uid),
+            &client,
+            Method::Patch(json!({
+                "embedders": { "default": { "source": "userProvided", "dimensions": 2 } }
+            })),
+            202,
+        )
+        .await?
+        .wait_for_completion(&client, None, None)
+        .await?;
+
+        let results: SearchResults<Value> = index
+            .search()
+            .with_vector(&[1.0, 0.0])
+            .with_hybrid("default", 1.0)
+            .with_show_query_vector(true)
+            .execute()
+            .await?;
+        assert_eq!(results.query_vector, Some(vec![1.0, 0.0]));
+
+        let results: SearchResults<Value> = index
+            .search()
+            .with_vector(&[1.0, 0.0])
+            .with_hybrid("default", 1.0)
+            .execute()
+            .await?;
+        assert_eq!(results.query_vector, None);
+        Ok(())
+    }
 }
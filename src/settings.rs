@@ -1,23 +1,604 @@
 use crate::{
+    documents::DocumentsQuery,
     errors::Error,
     indexes::Index,
     request::{request, Method},
     task_info::TaskInfo,
 };
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    io::{Read, Write},
+    marker::PhantomData,
+    path::Path,
+};
+use time::OffsetDateTime;
+
+/// The value of a field of [Settings].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Setting<T> {
+    /// Update the setting to this value.
+    Set(T),
+    /// Reset the setting to its default value. It is sent as `null`, and read from a `null` value.
+    Reset,
+    /// Leave the setting unchanged. It is not sent.
+    #[default]
+    NotSet,
+}
+
+impl<T> Setting<T> {
+    /// Whether the setting is left unchanged.
+    pub fn is_not_set(&self) -> bool {
+        matches!(self, Setting::NotSet)
+    }
+
+    /// The value of a [Setting::Set], `None` otherwise.
+    pub fn as_option(&self) -> Option<&T> {
+        match self {
+            Setting::Set(value) => Some(value),
+            Setting::Reset | Setting::NotSet => None,
+        }
+    }
+
+    /// The value of a [Setting::Set], `None` otherwise.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Setting::Set(value) => Some(value),
+            Setting::Reset | Setting::NotSet => None,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Setting<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Setting::Set(value) => value.serialize(serializer),
+            // the fields not set are skipped by Settings
+            Setting::Reset | Setting::NotSet => serializer.serialize_none(),
+        }
+    }
+}
 
-#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq, Copy)]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Setting<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Setting<T>, D::Error> {
+        // the missing fields are not deserialized, they default to NotSet
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Setting::Set(value),
+            None => Setting::Reset,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginationSetting {
     pub max_total_hits: usize,
 }
 
+// The default of Meilisearch, rather than `0` hits which would make every search empty.
+impl Default for PaginationSetting {
+    fn default() -> PaginationSetting {
+        PaginationSetting {
+            max_total_hits: 1000,
+        }
+    }
+}
+
+impl PaginationSetting {
+    /// Create pagination settings with the default of Meilisearch, `1000` hits at most.
+    #[must_use]
+    pub fn new() -> PaginationSetting {
+        PaginationSetting::default()
+    }
+
+    #[must_use]
+    pub fn with_max_total_hits(mut self, max_total_hits: usize) -> PaginationSetting {
+        self.max_total_hits = max_total_hits;
+        self
+    }
+}
+
+/// How the values of a facet are sorted, see [sortFacetValuesBy](https://www.meilisearch.com/docs/reference/api/settings#faceting-object).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FacetSortBy {
+    /// In alphanumerical order, the default.
+    Alpha,
+    /// By decreasing number of matching documents.
+    Count,
+}
+
+/// The [faceting settings](https://www.meilisearch.com/docs/reference/api/settings#faceting) of an index.
+///
+/// They are updated partially: the fields [not set](Setting::NotSet) keep their current value,
+/// so one of them can be changed or [reset](Setting::Reset) without sending the other.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FacetingSettings {
-    #[serde()]
-    pub max_values_per_facet: usize,
+    /// The maximum number of values returned for each facet.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub max_values_per_facet: Setting<usize>,
+    /// The order of the values of each facet, `*` applies to all the facets not listed.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub sort_facet_values_by: Setting<HashMap<String, FacetSortBy>>,
+}
+
+impl FacetingSettings {
+    /// Create undefined faceting settings.
+    #[must_use]
+    pub fn new() -> FacetingSettings {
+        FacetingSettings::default()
+    }
+
+    #[must_use]
+    pub fn with_max_values_per_facet(self, max_values_per_facet: usize) -> FacetingSettings {
+        FacetingSettings {
+            max_values_per_facet: Setting::Set(max_values_per_facet),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_sort_facet_values_by(
+        self,
+        sort_facet_values_by: impl IntoIterator<Item = (impl AsRef<str>, FacetSortBy)>,
+    ) -> FacetingSettings {
+        FacetingSettings {
+            sort_facet_values_by: Setting::Set(
+                sort_facet_values_by
+                    .into_iter()
+                    .map(|(facet, sort_by)| (facet.as_ref().to_string(), sort_by))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Reset the maximum number of values returned for each facet to its default value.
+    #[must_use]
+    pub fn reset_max_values_per_facet(self) -> FacetingSettings {
+        FacetingSettings {
+            max_values_per_facet: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the order of the values of all the facets to its default value.
+    #[must_use]
+    pub fn reset_sort_facet_values_by(self) -> FacetingSettings {
+        FacetingSettings {
+            sort_facet_values_by: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Check the settings before sending them, [Index::set_faceting] does it before any request.
+    ///
+    /// Returns [Error::InvalidRequest] when [max_values_per_facet](FacetingSettings::max_values_per_facet) is `0`,
+    /// which would make all the facet distributions empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{errors::Error, settings::FacetingSettings};
+    /// assert!(FacetingSettings::new().with_max_values_per_facet(10).validate().is_ok());
+    /// assert!(matches!(
+    ///     FacetingSettings::new().with_max_values_per_facet(0).validate(),
+    ///     Err(Error::InvalidRequest)
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        match self.max_values_per_facet {
+            Setting::Set(0) => Err(Error::InvalidRequest),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The minimum length of the words that can contain typos, see [TypoToleranceSettings::min_word_size_for_typos].
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MinWordSizeForTypos {
+    /// The minimum length of the words that can contain one typo. Default: `5`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub one_typo: Option<u8>,
+    /// The minimum length of the words that can contain two typos. Default: `9`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub two_typos: Option<u8>,
+}
+
+impl MinWordSizeForTypos {
+    /// Create a [MinWordSizeForTyposBuilder], checking the sizes are consistent before building them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{errors::Error, settings::MinWordSizeForTypos};
+    /// let sizes = MinWordSizeForTypos::builder().one_typo(4).two_typos(8).build().unwrap();
+    /// assert_eq!(sizes.one_typo, Some(4));
+    ///
+    /// let invalid = MinWordSizeForTypos::builder().one_typo(8).two_typos(4).build();
+    /// assert!(matches!(invalid, Err(Error::InvalidMinWordSizeForTypos { .. })));
+    /// ```
+    pub fn builder() -> MinWordSizeForTyposBuilder {
+        MinWordSizeForTyposBuilder::default()
+    }
+}
+
+/// A builder of [MinWordSizeForTypos], see [MinWordSizeForTypos::builder].
+///
+/// The sizes not given keep their current value on the server.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct MinWordSizeForTyposBuilder {
+    sizes: MinWordSizeForTypos,
+}
+
+impl MinWordSizeForTyposBuilder {
+    /// The minimum length of the words that can contain one typo.
+    pub fn one_typo(&mut self, size: u8) -> &mut MinWordSizeForTyposBuilder {
+        self.sizes.one_typo = Some(size);
+        self
+    }
+
+    /// The minimum length of the words that can contain two typos.
+    pub fn two_typos(&mut self, size: u8) -> &mut MinWordSizeForTyposBuilder {
+        self.sizes.two_typos = Some(size);
+        self
+    }
+
+    /// Build the [MinWordSizeForTypos].
+    ///
+    /// Returns [Error::InvalidMinWordSizeForTypos] when both sizes are given and `one_typo` is larger than `two_typos`.
+    pub fn build(&self) -> Result<MinWordSizeForTypos, Error> {
+        match self.sizes {
+            MinWordSizeForTypos {
+                one_typo: Some(one_typo),
+                two_typos: Some(two_typos),
+            } if one_typo > two_typos => Err(Error::InvalidMinWordSizeForTypos {
+                one_typo,
+                two_typos,
+            }),
+            sizes => Ok(sizes),
+        }
+    }
+}
+
+/// The [typo tolerance settings](https://www.meilisearch.com/docs/reference/api/settings#typo-tolerance) of an index.
+///
+/// They are updated partially: the fields [not set](Setting::NotSet) keep their current value,
+/// so typo tolerance can be disabled without erasing the words and attributes it is disabled on.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypoToleranceSettings {
+    /// Whether the typos are tolerated at all.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub enabled: Setting<bool>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub min_word_size_for_typos: Setting<MinWordSizeForTypos>,
+    /// The words matched without typos.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub disable_on_words: Setting<Vec<String>>,
+    /// The attributes in which the words are matched without typos.
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub disable_on_attributes: Setting<Vec<String>>,
+}
+
+#[allow(missing_docs)]
+impl TypoToleranceSettings {
+    /// Create undefined typo tolerance settings.
+    #[must_use]
+    pub fn new() -> TypoToleranceSettings {
+        TypoToleranceSettings::default()
+    }
+
+    /// Create typo tolerance settings disabling the typo tolerance, so only the exact words match.
+    #[must_use]
+    pub fn disabled() -> TypoToleranceSettings {
+        TypoToleranceSettings::new().with_enabled(false)
+    }
+
+    #[must_use]
+    pub fn with_enabled(self, enabled: bool) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            enabled: Setting::Set(enabled),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_min_word_size_for_typos(
+        self,
+        min_word_size_for_typos: MinWordSizeForTypos,
+    ) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            min_word_size_for_typos: Setting::Set(min_word_size_for_typos),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_disable_on_words(
+        self,
+        disable_on_words: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            disable_on_words: Setting::Set(
+                disable_on_words
+                    .into_iter()
+                    .map(|word| word.as_ref().to_string())
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_disable_on_attributes(
+        self,
+        disable_on_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> TypoToleranceSettings {
+        TypoToleranceSettings {
+            disable_on_attributes: Setting::Set(
+                disable_on_attributes
+                    .into_iter()
+                    .map(|attribute| attribute.as_ref().to_string())
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+}
+
+/// The languages of the attributes matching some patterns, see the [localized attributes](https://www.meilisearch.com/docs/reference/api/settings#localized-attributes).
+///
+/// The attributes are tokenized in these languages instead of the one detected by Meilisearch,
+/// which is often wrong for short texts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedAttribute {
+    /// The [ISO 639-3 codes](https://en.wikipedia.org/wiki/ISO_639-3) of the languages, ie `fra` or `jpn`.
+    pub locales: Vec<String>,
+    /// The attributes in these languages. A pattern can start or end with `*`, ie `*_fr`.
+    pub attribute_patterns: Vec<String>,
+}
+
+impl LocalizedAttribute {
+    #[must_use]
+    pub fn new(
+        locales: impl IntoIterator<Item = impl AsRef<str>>,
+        attribute_patterns: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> LocalizedAttribute {
+        LocalizedAttribute {
+            locales: locales
+                .into_iter()
+                .map(|locale| locale.as_ref().to_string())
+                .collect(),
+            attribute_patterns: attribute_patterns
+                .into_iter()
+                .map(|pattern| pattern.as_ref().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// How precisely the proximity of the words is computed, see the [proximity precision](https://www.meilisearch.com/docs/reference/api/settings#proximity-precision).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProximityPrecision {
+    /// The distance between the words is computed, the default.
+    ByWord,
+    /// Only whether the words are in the same attribute is computed, which makes the indexing faster.
+    ByAttribute,
+}
+
+impl std::fmt::Display for ProximityPrecision {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProximityPrecision::ByWord => write!(fmt, "byWord"),
+            ProximityPrecision::ByAttribute => write!(fmt, "byAttribute"),
+        }
+    }
+}
+
+/// A [ranking rule](https://www.meilisearch.com/docs/learn/core_concepts/relevancy#ranking-rules),
+/// written as Meilisearch expects it by its [Display](std::fmt::Display) implementation and parsed back with [FromStr](std::str::FromStr).
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::RankingRule;
+/// assert_eq!(RankingRule::Asc("release_date".to_string()).to_string(), "release_date:asc");
+/// assert_eq!("proximity".parse::<RankingRule>().unwrap(), RankingRule::Proximity);
+/// assert!("proximty".parse::<RankingRule>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RankingRule {
+    /// The documents containing the most query terms are ranked first.
+    Words,
+    /// The documents with the fewest typos are ranked first.
+    Typo,
+    /// The documents whose query terms are the closest to each other are ranked first.
+    Proximity,
+    /// The documents matching in the most important attributes are ranked first.
+    Attribute,
+    /// The documents are ranked by the `sort` parameter of the search.
+    Sort,
+    /// The documents matching the query terms exactly are ranked first.
+    Exactness,
+    /// The documents with the lowest value of the attribute are ranked first.
+    Asc(String),
+    /// The documents with the highest value of the attribute are ranked first.
+    Desc(String),
+}
+
+impl std::fmt::Display for RankingRule {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankingRule::Words => write!(fmt, "words"),
+            RankingRule::Typo => write!(fmt, "typo"),
+            RankingRule::Proximity => write!(fmt, "proximity"),
+            RankingRule::Attribute => write!(fmt, "attribute"),
+            RankingRule::Sort => write!(fmt, "sort"),
+            RankingRule::Exactness => write!(fmt, "exactness"),
+            RankingRule::Asc(attribute) => write!(fmt, "{}:asc", attribute),
+            RankingRule::Desc(attribute) => write!(fmt, "{}:desc", attribute),
+        }
+    }
+}
+
+impl std::str::FromStr for RankingRule {
+    type Err = Error;
+
+    fn from_str(rule: &str) -> Result<RankingRule, Error> {
+        Ok(match rule {
+            "words" => RankingRule::Words,
+            "typo" => RankingRule::Typo,
+            "proximity" => RankingRule::Proximity,
+            "attribute" => RankingRule::Attribute,
+            "sort" => RankingRule::Sort,
+            "exactness" => RankingRule::Exactness,
+            _ => match (rule.strip_suffix(":asc"), rule.strip_suffix(":desc")) {
+                (Some(attribute), _) if !attribute.is_empty() => {
+                    RankingRule::Asc(attribute.to_string())
+                }
+                (_, Some(attribute)) if !attribute.is_empty() => {
+                    RankingRule::Desc(attribute.to_string())
+                }
+                _ => return Err(Error::InvalidRankingRule(rule.to_string())),
+            },
+        })
+    }
+}
+
+impl Serialize for RankingRule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RankingRule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<RankingRule, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// The service generating the vectors of an [Embedder].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EmbedderSource {
+    /// The OpenAI API, which needs an `api_key`.
+    OpenAi,
+    /// A Hugging Face model, run by Meilisearch itself.
+    HuggingFace,
+    /// The vectors are sent with the documents and the queries, in their `_vectors` field.
+    UserProvided,
+}
+
+/// An [embedder](https://www.meilisearch.com/docs/reference/api/settings#embedders) generating the vectors used by the vector and hybrid searches.
+///
+/// The fields left to `None` are not sent, so Meilisearch uses its defaults for the `source`.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::settings::{Embedder, EmbedderSource};
+/// let embedder = Embedder::new(EmbedderSource::OpenAi)
+///     .with_model("text-embedding-3-small")
+///     .with_api_key("sk-...")
+///     .with_document_template("A movie titled {{doc.title}}");
+///
+/// let user_provided = Embedder::user_provided(512);
+/// assert_eq!(user_provided.dimensions, Some(512));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Embedder {
+    /// Where the vectors come from.
+    pub source: EmbedderSource,
+    /// The model generating the vectors, for the `openAi` and `huggingFace` sources.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The key authenticating the requests to the source.
+    ///
+    /// Meilisearch only returns a [redacted](Embedder::has_redacted_api_key) version of it, which is not sent back,
+    /// so the embedders read from Meilisearch can be updated without losing their key.
+    #[serde(skip_serializing_if = "is_none_or_redacted")]
+    pub api_key: Option<String>,
+    /// The [liquid](https://shopify.github.io/liquid/) template turning a document into the text embedded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_template: Option<String>,
+    /// The number of dimensions of the vectors, required for the `userProvided` source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<usize>,
+}
+
+#[allow(missing_docs)]
+impl Embedder {
+    /// Create an embedder for `source`, with the defaults of Meilisearch.
+    #[must_use]
+    pub fn new(source: EmbedderSource) -> Embedder {
+        Embedder {
+            source,
+            model: None,
+            api_key: None,
+            document_template: None,
+            dimensions: None,
+        }
+    }
+
+    /// Create an embedder of vectors of `dimensions` dimensions sent with the documents and queries.
+    #[must_use]
+    pub fn user_provided(dimensions: usize) -> Embedder {
+        Embedder::new(EmbedderSource::UserProvided).with_dimensions(dimensions)
+    }
+
+    #[must_use]
+    pub fn with_model(self, model: impl AsRef<str>) -> Embedder {
+        Embedder {
+            model: Some(model.as_ref().to_string()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_api_key(self, api_key: impl AsRef<str>) -> Embedder {
+        Embedder {
+            api_key: Some(api_key.as_ref().to_string()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_document_template(self, document_template: impl AsRef<str>) -> Embedder {
+        Embedder {
+            document_template: Some(document_template.as_ref().to_string()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_dimensions(self, dimensions: usize) -> Embedder {
+        Embedder {
+            dimensions: Some(dimensions),
+            ..self
+        }
+    }
+
+    /// Whether the `api_key` is the redacted version returned by Meilisearch, ie `sk-pr...`, instead of the key itself.
+    pub fn has_redacted_api_key(&self) -> bool {
+        is_redacted(self.api_key.as_deref())
+    }
+}
+
+fn is_redacted(api_key: Option<&str>) -> bool {
+    matches!(api_key, Some(api_key) if api_key.ends_with("..."))
+}
+
+fn is_none_or_redacted(api_key: &Option<String>) -> bool {
+    api_key.is_none() || is_redacted(api_key.as_deref())
 }
 
 /// Struct reprensenting a set of settings.
@@ -26,7 +607,7 @@ pub struct FacetingSettings {
 /// # Example
 ///
 /// ```
-/// # use meilisearch_sdk::settings::Settings;
+/// # use meilisearch_sdk::settings::{Setting, Settings};
 /// let settings = Settings::new()
 ///     .with_stop_words(["a", "the", "of"]);
 ///
@@ -34,49 +615,89 @@ pub struct FacetingSettings {
 ///
 /// let stop_words: Vec<String> = vec!["a".to_string(), "the".to_string(), "of".to_string()];
 /// let mut settings = Settings::new();
-/// settings.stop_words = Some(stop_words);
+/// settings.stop_words = Setting::Set(stop_words);
 ///
 /// // OR
 ///
 /// let stop_words: Vec<String> = vec!["a".to_string(), "the".to_string(), "of".to_string()];
 /// let settings = Settings {
-///     stop_words: Some(stop_words),
+///     stop_words: Setting::Set(stop_words),
 ///     ..Settings::new()
 /// };
 /// ```
+///
+/// A field set to [Setting::Reset] is sent as `null`, so a single [Index::set_settings] can reset some settings
+/// to their default value while updating others. The fields left to [Setting::NotSet] are not sent.
+///
+/// ```
+/// # use meilisearch_sdk::settings::Settings;
+/// # use serde_json::json;
+/// let settings = Settings::new()
+///     .with_distinct_attribute("id")
+///     .reset_stop_words();
+///
+/// assert_eq!(settings.to_request_body(), json!({ "stopWords": null, "distinctAttribute": "id" }));
+/// ```
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     /// List of associated words treated similarly
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub synonyms: Option<HashMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub synonyms: Setting<HashMap<String, Vec<String>>>,
     /// List of words ignored by Meilisearch when present in search queries
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_words: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub stop_words: Setting<Vec<String>>,
+    /// List of multi-word expressions, ie `J. R. R. Tolkien`, that are indexed and searched as a single term
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub dictionary: Setting<Vec<String>>,
+    /// List of characters, in addition to the default ones, that delimit the words
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub separator_tokens: Setting<Vec<String>>,
+    /// List of characters, among the default separators, that don't delimit the words
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub non_separator_tokens: Setting<Vec<String>>,
     /// List of [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#order-of-the-rules) sorted by order of importance
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ranking_rules: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub ranking_rules: Setting<Vec<String>>,
     /// Attributes to use for [filtering and faceted search](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filterable_attributes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub filterable_attributes: Setting<Vec<String>>,
     /// Attributes to sort
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sortable_attributes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub sortable_attributes: Setting<Vec<String>>,
     /// Search returns documents with distinct (different) values of the given field
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub distinct_attribute: Option<String>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub distinct_attribute: Setting<String>,
     /// Fields in which to search for matching query words sorted by order of importance
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub searchable_attributes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub searchable_attributes: Setting<Vec<String>>,
     /// Fields displayed in the returned documents
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub displayed_attributes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub displayed_attributes: Setting<Vec<String>>,
     /// Pagination settings
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pagination: Option<PaginationSetting>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub pagination: Setting<PaginationSetting>,
     /// Faceting settings
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub faceting: Option<FacetingSettings>,
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub faceting: Setting<FacetingSettings>,
+    /// Precision of the proximity ranking rule
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub proximity_precision: Setting<ProximityPrecision>,
+    /// Maximum duration of a search in milliseconds, after which the best results found so far are returned
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub search_cutoff_ms: Setting<u64>,
+    /// Embedders generating the vectors of the documents, by name
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub embedders: Setting<HashMap<String, Embedder>>,
+    /// Whether the values of the facets can be searched, disabling it makes the indexing faster
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub facet_search: Setting<bool>,
+    /// Languages of the attributes, used to tokenize them instead of the detected one
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub localized_attributes: Setting<Vec<LocalizedAttribute>>,
+    /// Typo tolerance settings
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    pub typo_tolerance: Setting<TypoToleranceSettings>,
 }
 
 #[allow(missing_docs)]
@@ -84,16 +705,25 @@ impl Settings {
     /// Create undefined settings
     pub fn new() -> Settings {
         Settings {
-            synonyms: None,
-            stop_words: None,
-            ranking_rules: None,
-            filterable_attributes: None,
-            sortable_attributes: None,
-            distinct_attribute: None,
-            searchable_attributes: None,
-            displayed_attributes: None,
-            pagination: None,
-            faceting: None,
+            synonyms: Setting::NotSet,
+            stop_words: Setting::NotSet,
+            dictionary: Setting::NotSet,
+            separator_tokens: Setting::NotSet,
+            non_separator_tokens: Setting::NotSet,
+            ranking_rules: Setting::NotSet,
+            filterable_attributes: Setting::NotSet,
+            sortable_attributes: Setting::NotSet,
+            distinct_attribute: Setting::NotSet,
+            searchable_attributes: Setting::NotSet,
+            displayed_attributes: Setting::NotSet,
+            pagination: Setting::NotSet,
+            faceting: Setting::NotSet,
+            proximity_precision: Setting::NotSet,
+            search_cutoff_ms: Setting::NotSet,
+            embedders: Setting::NotSet,
+            facet_search: Setting::NotSet,
+            localized_attributes: Setting::NotSet,
+            typo_tolerance: Setting::NotSet,
         }
     }
     pub fn with_synonyms<S, U, V>(self, synonyms: HashMap<S, U>) -> Settings
@@ -103,7 +733,7 @@ impl Settings {
         U: IntoIterator<Item = V>,
     {
         Settings {
-            synonyms: Some(
+            synonyms: Setting::Set(
                 synonyms
                     .into_iter()
                     .map(|(key, value)| {
@@ -123,7 +753,7 @@ impl Settings {
         stop_words: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            stop_words: Some(
+            stop_words: Setting::Set(
                 stop_words
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
@@ -133,20 +763,13 @@ impl Settings {
         }
     }
 
-    pub fn with_pagination(self, pagination_settings: PaginationSetting) -> Settings {
-        Settings {
-            pagination: Some(pagination_settings),
-            ..self
-        }
-    }
-
-    pub fn with_ranking_rules(
+    pub fn with_dictionary(
         self,
-        ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
+        dictionary: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            ranking_rules: Some(
-                ranking_rules
+            dictionary: Setting::Set(
+                dictionary
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
                     .collect(),
@@ -155,13 +778,13 @@ impl Settings {
         }
     }
 
-    pub fn with_filterable_attributes(
+    pub fn with_separator_tokens(
         self,
-        filterable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+        separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            filterable_attributes: Some(
-                filterable_attributes
+            separator_tokens: Setting::Set(
+                separator_tokens
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
                     .collect(),
@@ -170,13 +793,13 @@ impl Settings {
         }
     }
 
-    pub fn with_sortable_attributes(
+    pub fn with_non_separator_tokens(
         self,
-        sortable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+        non_separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            sortable_attributes: Some(
-                sortable_attributes
+            non_separator_tokens: Setting::Set(
+                non_separator_tokens
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
                     .collect(),
@@ -185,20 +808,31 @@ impl Settings {
         }
     }
 
-    pub fn with_distinct_attribute(self, distinct_attribute: impl AsRef<str>) -> Settings {
+    /// Set the [pagination settings](PaginationSetting).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Settings, PaginationSetting};
+    /// # use serde_json::json;
+    /// let settings = Settings::new().with_pagination(PaginationSetting::new().with_max_total_hits(100));
+    ///
+    /// assert_eq!(settings.to_request_body(), json!({ "pagination": { "maxTotalHits": 100 } }));
+    /// ```
+    pub fn with_pagination(self, pagination_settings: PaginationSetting) -> Settings {
         Settings {
-            distinct_attribute: Some(distinct_attribute.as_ref().to_string()),
+            pagination: Setting::Set(pagination_settings),
             ..self
         }
     }
 
-    pub fn with_searchable_attributes(
+    pub fn with_ranking_rules(
         self,
-        searchable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+        ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            searchable_attributes: Some(
-                searchable_attributes
+            ranking_rules: Setting::Set(
+                ranking_rules
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
                     .collect(),
@@ -207,12 +841,72 @@ impl Settings {
         }
     }
 
-    pub fn with_displayed_attributes(
+    /// Like [Settings::with_ranking_rules], with [typed ranking rules](RankingRule).
+    pub fn with_ranking_rules_typed(
+        self,
+        ranking_rules: impl IntoIterator<Item = RankingRule>,
+    ) -> Settings {
+        self.with_ranking_rules(ranking_rules.into_iter().map(|rule| rule.to_string()))
+    }
+
+    pub fn with_filterable_attributes(
+        self,
+        filterable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Settings {
+        Settings {
+            filterable_attributes: Setting::Set(
+                filterable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn with_sortable_attributes(
+        self,
+        sortable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Settings {
+        Settings {
+            sortable_attributes: Setting::Set(
+                sortable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn with_distinct_attribute(self, distinct_attribute: impl AsRef<str>) -> Settings {
+        Settings {
+            distinct_attribute: Setting::Set(distinct_attribute.as_ref().to_string()),
+            ..self
+        }
+    }
+
+    pub fn with_searchable_attributes(
+        self,
+        searchable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Settings {
+        Settings {
+            searchable_attributes: Setting::Set(
+                searchable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn with_displayed_attributes(
         self,
         displayed_attributes: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Settings {
         Settings {
-            displayed_attributes: Some(
+            displayed_attributes: Setting::Set(
                 displayed_attributes
                     .into_iter()
                     .map(|v| v.as_ref().to_string())
@@ -222,245 +916,3022 @@ impl Settings {
         }
     }
 
+    /// Set the [faceting settings](FacetingSettings).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Settings, FacetingSettings};
+    /// # use serde_json::json;
+    /// let settings = Settings::new().with_faceting(&FacetingSettings::new().with_max_values_per_facet(50));
+    ///
+    /// assert_eq!(settings.to_request_body(), json!({ "faceting": { "maxValuesPerFacet": 50 } }));
+    /// ```
     pub fn with_faceting(self, faceting: &FacetingSettings) -> Settings {
         Settings {
-            faceting: Some(faceting.clone()),
+            faceting: Setting::Set(faceting.clone()),
             ..self
         }
     }
-}
 
-impl Index {
-    /// Get [Settings] of the [Index].
+    pub fn with_typo_tolerance(self, typo_tolerance: &TypoToleranceSettings) -> Settings {
+        Settings {
+            typo_tolerance: Setting::Set(typo_tolerance.clone()),
+            ..self
+        }
+    }
+
+    /// Disable the typo tolerance, with [TypoToleranceSettings::disabled].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// # use serde_json::json;
+    /// let settings = Settings::new().with_typo_tolerance_disabled();
+    ///
+    /// assert_eq!(settings.to_request_body(), json!({ "typoTolerance": { "enabled": false } }));
+    /// ```
+    pub fn with_typo_tolerance_disabled(self) -> Settings {
+        self.with_typo_tolerance(&TypoToleranceSettings::disabled())
+    }
+
+    pub fn with_proximity_precision(self, proximity_precision: ProximityPrecision) -> Settings {
+        Settings {
+            proximity_precision: Setting::Set(proximity_precision),
+            ..self
+        }
+    }
+
+    pub fn with_search_cutoff_ms(self, search_cutoff_ms: u64) -> Settings {
+        Settings {
+            search_cutoff_ms: Setting::Set(search_cutoff_ms),
+            ..self
+        }
+    }
+
+    pub fn with_embedders<S>(self, embedders: HashMap<S, Embedder>) -> Settings
+    where
+        S: AsRef<str>,
+    {
+        Settings {
+            embedders: Setting::Set(
+                embedders
+                    .into_iter()
+                    .map(|(name, embedder)| (name.as_ref().to_string(), embedder))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn with_facet_search(self, facet_search: bool) -> Settings {
+        Settings {
+            facet_search: Setting::Set(facet_search),
+            ..self
+        }
+    }
+
+    pub fn with_localized_attributes(
+        self,
+        localized_attributes: impl IntoIterator<Item = LocalizedAttribute>,
+    ) -> Settings {
+        Settings {
+            localized_attributes: Setting::Set(localized_attributes.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Reset the synonyms to their default value.
+    pub fn reset_synonyms(self) -> Settings {
+        Settings {
+            synonyms: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the stop words to their default value.
+    pub fn reset_stop_words(self) -> Settings {
+        Settings {
+            stop_words: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the dictionary to its default value.
+    pub fn reset_dictionary(self) -> Settings {
+        Settings {
+            dictionary: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the separator tokens to their default value.
+    pub fn reset_separator_tokens(self) -> Settings {
+        Settings {
+            separator_tokens: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the non separator tokens to their default value.
+    pub fn reset_non_separator_tokens(self) -> Settings {
+        Settings {
+            non_separator_tokens: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the ranking rules to their default value.
+    pub fn reset_ranking_rules(self) -> Settings {
+        Settings {
+            ranking_rules: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the filterable attributes to their default value.
+    pub fn reset_filterable_attributes(self) -> Settings {
+        Settings {
+            filterable_attributes: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the sortable attributes to their default value.
+    pub fn reset_sortable_attributes(self) -> Settings {
+        Settings {
+            sortable_attributes: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the distinct attribute to its default value.
+    pub fn reset_distinct_attribute(self) -> Settings {
+        Settings {
+            distinct_attribute: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the searchable attributes to their default value.
+    pub fn reset_searchable_attributes(self) -> Settings {
+        Settings {
+            searchable_attributes: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the displayed attributes to their default value.
+    pub fn reset_displayed_attributes(self) -> Settings {
+        Settings {
+            displayed_attributes: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the pagination settings to their default value.
+    pub fn reset_pagination(self) -> Settings {
+        Settings {
+            pagination: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the faceting settings to their default value.
+    pub fn reset_faceting(self) -> Settings {
+        Settings {
+            faceting: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the typo tolerance settings to their default value.
+    pub fn reset_typo_tolerance(self) -> Settings {
+        Settings {
+            typo_tolerance: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the proximity precision to its default value.
+    pub fn reset_proximity_precision(self) -> Settings {
+        Settings {
+            proximity_precision: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the search cutoff to its default value.
+    pub fn reset_search_cutoff_ms(self) -> Settings {
+        Settings {
+            search_cutoff_ms: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the embedders to their default value.
+    pub fn reset_embedders(self) -> Settings {
+        Settings {
+            embedders: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the facet search to its default value.
+    pub fn reset_facet_search(self) -> Settings {
+        Settings {
+            facet_search: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Reset the localized attributes to their default value.
+    pub fn reset_localized_attributes(self) -> Settings {
+        Settings {
+            localized_attributes: Setting::Reset,
+            ..self
+        }
+    }
+
+    /// Like [Settings::with_synonyms], but through a mutable reference, so the settings can be built conditionally.
+    ///
+    /// All the `with_*` builders have a `set_*` counterpart, which can be chained too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// # use serde_json::json;
+    /// # let sortable = true;
+    /// let mut settings = Settings::new();
+    /// settings.set_stop_words(["the", "a"]).set_distinct_attribute("id");
+    /// if sortable {
+    ///     settings.set_sortable_attributes(["year"]);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     settings.to_request_body(),
+    ///     json!({ "stopWords": ["the", "a"], "sortableAttributes": ["year"], "distinctAttribute": "id" })
+    /// );
+    /// ```
+    pub fn set_synonyms<S, U, V>(&mut self, synonyms: HashMap<S, U>) -> &mut Settings
+    where
+        S: AsRef<str>,
+        V: AsRef<str>,
+        U: IntoIterator<Item = V>,
+    {
+        *self = std::mem::take(self).with_synonyms(synonyms);
+        self
+    }
+
+    /// Like [Settings::with_stop_words], but through a mutable reference.
+    pub fn set_stop_words(
+        &mut self,
+        stop_words: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_stop_words(stop_words);
+        self
+    }
+
+    /// Like [Settings::with_dictionary], but through a mutable reference.
+    pub fn set_dictionary(
+        &mut self,
+        dictionary: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_dictionary(dictionary);
+        self
+    }
+
+    /// Like [Settings::with_separator_tokens], but through a mutable reference.
+    pub fn set_separator_tokens(
+        &mut self,
+        separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_separator_tokens(separator_tokens);
+        self
+    }
+
+    /// Like [Settings::with_non_separator_tokens], but through a mutable reference.
+    pub fn set_non_separator_tokens(
+        &mut self,
+        non_separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_non_separator_tokens(non_separator_tokens);
+        self
+    }
+
+    /// Like [Settings::with_pagination], but through a mutable reference.
+    pub fn set_pagination(&mut self, pagination: PaginationSetting) -> &mut Settings {
+        *self = std::mem::take(self).with_pagination(pagination);
+        self
+    }
+
+    /// Like [Settings::with_ranking_rules], but through a mutable reference.
+    pub fn set_ranking_rules(
+        &mut self,
+        ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_ranking_rules(ranking_rules);
+        self
+    }
+
+    /// Like [Settings::with_ranking_rules_typed], but through a mutable reference.
+    pub fn set_ranking_rules_typed(
+        &mut self,
+        ranking_rules: impl IntoIterator<Item = RankingRule>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_ranking_rules_typed(ranking_rules);
+        self
+    }
+
+    /// Like [Settings::with_filterable_attributes], but through a mutable reference.
+    pub fn set_filterable_attributes(
+        &mut self,
+        filterable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_filterable_attributes(filterable_attributes);
+        self
+    }
+
+    /// Like [Settings::with_sortable_attributes], but through a mutable reference.
+    pub fn set_sortable_attributes(
+        &mut self,
+        sortable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_sortable_attributes(sortable_attributes);
+        self
+    }
+
+    /// Like [Settings::with_distinct_attribute], but through a mutable reference.
+    pub fn set_distinct_attribute(&mut self, distinct_attribute: impl AsRef<str>) -> &mut Settings {
+        *self = std::mem::take(self).with_distinct_attribute(distinct_attribute);
+        self
+    }
+
+    /// Like [Settings::with_searchable_attributes], but through a mutable reference.
+    pub fn set_searchable_attributes(
+        &mut self,
+        searchable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_searchable_attributes(searchable_attributes);
+        self
+    }
+
+    /// Like [Settings::with_displayed_attributes], but through a mutable reference.
+    pub fn set_displayed_attributes(
+        &mut self,
+        displayed_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_displayed_attributes(displayed_attributes);
+        self
+    }
+
+    /// Like [Settings::with_faceting], but through a mutable reference.
+    pub fn set_faceting(&mut self, faceting: &FacetingSettings) -> &mut Settings {
+        *self = std::mem::take(self).with_faceting(faceting);
+        self
+    }
+
+    /// Like [Settings::with_typo_tolerance], but through a mutable reference.
+    pub fn set_typo_tolerance(&mut self, typo_tolerance: &TypoToleranceSettings) -> &mut Settings {
+        *self = std::mem::take(self).with_typo_tolerance(typo_tolerance);
+        self
+    }
+
+    /// Like [Settings::with_typo_tolerance_disabled], but through a mutable reference.
+    pub fn set_typo_tolerance_disabled(&mut self) -> &mut Settings {
+        *self = std::mem::take(self).with_typo_tolerance_disabled();
+        self
+    }
+
+    /// Like [Settings::with_proximity_precision], but through a mutable reference.
+    pub fn set_proximity_precision(
+        &mut self,
+        proximity_precision: ProximityPrecision,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_proximity_precision(proximity_precision);
+        self
+    }
+
+    /// Like [Settings::with_search_cutoff_ms], but through a mutable reference.
+    pub fn set_search_cutoff_ms(&mut self, search_cutoff_ms: u64) -> &mut Settings {
+        *self = std::mem::take(self).with_search_cutoff_ms(search_cutoff_ms);
+        self
+    }
+
+    /// Like [Settings::with_embedders], but through a mutable reference.
+    pub fn set_embedders<S>(&mut self, embedders: HashMap<S, Embedder>) -> &mut Settings
+    where
+        S: AsRef<str>,
+    {
+        *self = std::mem::take(self).with_embedders(embedders);
+        self
+    }
+
+    /// Like [Settings::with_facet_search], but through a mutable reference.
+    pub fn set_facet_search(&mut self, facet_search: bool) -> &mut Settings {
+        *self = std::mem::take(self).with_facet_search(facet_search);
+        self
+    }
+
+    /// Like [Settings::with_localized_attributes], but through a mutable reference.
+    pub fn set_localized_attributes(
+        &mut self,
+        localized_attributes: impl IntoIterator<Item = LocalizedAttribute>,
+    ) -> &mut Settings {
+        *self = std::mem::take(self).with_localized_attributes(localized_attributes);
+        self
+    }
+
+    /// Keep only the `fields` of the settings, the other ones are left [not set](Setting::NotSet).
+    fn select(mut self, fields: &[SettingsField]) -> Settings {
+        let mut selected = Settings::new();
+        for field in fields {
+            match field {
+                SettingsField::Synonyms => selected.synonyms = std::mem::take(&mut self.synonyms),
+                SettingsField::StopWords => {
+                    selected.stop_words = std::mem::take(&mut self.stop_words)
+                }
+                SettingsField::Dictionary => {
+                    selected.dictionary = std::mem::take(&mut self.dictionary)
+                }
+                SettingsField::SeparatorTokens => {
+                    selected.separator_tokens = std::mem::take(&mut self.separator_tokens)
+                }
+                SettingsField::NonSeparatorTokens => {
+                    selected.non_separator_tokens = std::mem::take(&mut self.non_separator_tokens)
+                }
+                SettingsField::RankingRules => {
+                    selected.ranking_rules = std::mem::take(&mut self.ranking_rules)
+                }
+                SettingsField::FilterableAttributes => {
+                    selected.filterable_attributes = std::mem::take(&mut self.filterable_attributes)
+                }
+                SettingsField::SortableAttributes => {
+                    selected.sortable_attributes = std::mem::take(&mut self.sortable_attributes)
+                }
+                SettingsField::DistinctAttribute => {
+                    selected.distinct_attribute = std::mem::take(&mut self.distinct_attribute)
+                }
+                SettingsField::SearchableAttributes => {
+                    selected.searchable_attributes = std::mem::take(&mut self.searchable_attributes)
+                }
+                SettingsField::DisplayedAttributes => {
+                    selected.displayed_attributes = std::mem::take(&mut self.displayed_attributes)
+                }
+                SettingsField::Pagination => {
+                    selected.pagination = std::mem::take(&mut self.pagination)
+                }
+                SettingsField::Faceting => selected.faceting = std::mem::take(&mut self.faceting),
+                SettingsField::TypoTolerance => {
+                    selected.typo_tolerance = std::mem::take(&mut self.typo_tolerance)
+                }
+                SettingsField::ProximityPrecision => {
+                    selected.proximity_precision = std::mem::take(&mut self.proximity_precision)
+                }
+                SettingsField::SearchCutoffMs => {
+                    selected.search_cutoff_ms = std::mem::take(&mut self.search_cutoff_ms)
+                }
+                SettingsField::Embedders => {
+                    selected.embedders = std::mem::take(&mut self.embedders)
+                }
+                SettingsField::FacetSearch => {
+                    selected.facet_search = std::mem::take(&mut self.facet_search)
+                }
+                SettingsField::LocalizedAttributes => {
+                    selected.localized_attributes = std::mem::take(&mut self.localized_attributes)
+                }
+            }
+        }
+        selected
+    }
+
+    /// Get the JSON body sent to Meilisearch by [Index::set_settings], without sending it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// # use serde_json::json;
+    /// let settings = Settings::new().with_distinct_attribute("id");
+    ///
+    /// assert_eq!(settings.to_request_body(), json!({ "distinctAttribute": "id" }));
+    /// ```
+    pub fn to_request_body(&self) -> Value {
+        // serializing the settings can't fail
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// The settings of `self` that `current` doesn't already have, compared field by field.
+    ///
+    /// The settings [not set](Setting::NotSet) in `self` are ignored, and only the fields given in an object,
+    /// ie the [pagination](Settings::pagination), have to match. The lists that Meilisearch stores as sets are compared
+    /// regardless of their order. The [reset](Setting::Reset) settings are kept unless `current` doesn't have a value for them,
+    /// and the API keys of the embedders, which Meilisearch never returns, are always kept.
+    ///
+    /// Sending the diff with [Index::set_settings] only updates what changed, and nothing has to be sent when it is empty,
+    /// which is what [Index::sync_settings] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// # use serde_json::json;
+    /// let current = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_distinct_attribute("id");
+    /// let desired = Settings::new()
+    ///     .with_stop_words(["the", "a"])
+    ///     .with_distinct_attribute("title");
+    ///
+    /// assert_eq!(desired.diff(&current).to_request_body(), json!({ "distinctAttribute": "title" }));
+    /// ```
+    pub fn diff(&self, current: &Settings) -> Settings {
+        const UNORDERED: [&str; 6] = [
+            "stopWords",
+            "dictionary",
+            "separatorTokens",
+            "nonSeparatorTokens",
+            "filterableAttributes",
+            "sortableAttributes",
+        ];
+
+        fn normalize(name: &str, value: &Value) -> Value {
+            let sorted = |values: &Vec<Value>| {
+                let mut values = values.clone();
+                values.sort_by_key(|value| value.to_string());
+                values.dedup();
+                Value::Array(values)
+            };
+            match value {
+                Value::Array(values) if UNORDERED.contains(&name) => sorted(values),
+                Value::Object(synonyms) if name == "synonyms" => synonyms
+                    .iter()
+                    .map(|(word, values)| match values {
+                        Value::Array(values) => (word.clone(), sorted(values)),
+                        values => (word.clone(), values.clone()),
+                    })
+                    .collect(),
+                value => value.clone(),
+            }
+        }
+
+        fn is_included(desired: &Value, current: &Value) -> bool {
+            match (desired, current) {
+                (Value::Object(desired), Value::Object(current)) if !desired.is_empty() => {
+                    desired.iter().all(|(name, value)| {
+                        is_included(value, current.get(name).unwrap_or(&Value::Null))
+                    })
+                }
+                (desired, current) => desired == current,
+            }
+        }
+
+        let current = current.to_request_body();
+        let changes = match self.to_request_body() {
+            Value::Object(desired) => desired
+                .into_iter()
+                .filter(|(name, value)| {
+                    let current = current.get(name).unwrap_or(&Value::Null);
+                    match value {
+                        // a reset setting only changes something if it has a value
+                        Value::Null => !current.is_null(),
+                        value => !is_included(&normalize(name, value), &normalize(name, current)),
+                    }
+                })
+                .collect(),
+            _ => serde_json::Map::new(),
+        };
+        // the changes are fields of valid settings
+        serde_json::from_value(Value::Object(changes)).unwrap_or_default()
+    }
+
+    /// Flatten the defined settings into a map of dotted paths, ie `pagination.maxTotalHits`, to their values.
+    /// The settings [not set](Setting::NotSet) are omitted, the [reset](Setting::Reset) ones are `null`, and the lists are kept as a single value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Settings, PaginationSetting};
+    /// # use serde_json::json;
+    /// let settings = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_pagination(PaginationSetting::new().with_max_total_hits(100));
+    ///
+    /// let flat = settings.to_flat_map();
+    /// assert_eq!(flat["stopWords"], json!(["a", "the"]));
+    /// assert_eq!(flat["pagination.maxTotalHits"], json!(100));
+    /// ```
+    pub fn to_flat_map(&self) -> BTreeMap<String, Value> {
+        fn flatten(prefix: String, value: Value, map: &mut BTreeMap<String, Value>) {
+            match value {
+                Value::Object(object) if !object.is_empty() || prefix.is_empty() => {
+                    for (key, value) in object {
+                        let path = if prefix.is_empty() {
+                            key
+                        } else {
+                            format!("{}.{}", prefix, key)
+                        };
+                        flatten(path, value, map);
+                    }
+                }
+                value => {
+                    map.insert(prefix, value);
+                }
+            }
+        }
+
+        let mut map = BTreeMap::new();
+        // serializing the settings can't fail
+        flatten(
+            String::new(),
+            serde_json::to_value(self).unwrap_or_default(),
+            &mut map,
+        );
+        map
+    }
+
+    /// The names of the settings that are defined, as named by Meilisearch, in the order of the fields.
+    /// The other settings are left to their current value, or to their default value on a new index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let settings = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_distinct_attribute("id");
+    ///
+    /// assert_eq!(settings.configured_fields(), ["stopWords", "distinctAttribute"]);
+    /// ```
+    pub fn configured_fields(&self) -> Vec<&'static str> {
+        [
+            ("synonyms", !self.synonyms.is_not_set()),
+            ("stopWords", !self.stop_words.is_not_set()),
+            ("dictionary", !self.dictionary.is_not_set()),
+            ("separatorTokens", !self.separator_tokens.is_not_set()),
+            (
+                "nonSeparatorTokens",
+                !self.non_separator_tokens.is_not_set(),
+            ),
+            ("rankingRules", !self.ranking_rules.is_not_set()),
+            (
+                "filterableAttributes",
+                !self.filterable_attributes.is_not_set(),
+            ),
+            ("sortableAttributes", !self.sortable_attributes.is_not_set()),
+            ("distinctAttribute", !self.distinct_attribute.is_not_set()),
+            (
+                "searchableAttributes",
+                !self.searchable_attributes.is_not_set(),
+            ),
+            (
+                "displayedAttributes",
+                !self.displayed_attributes.is_not_set(),
+            ),
+            ("pagination", !self.pagination.is_not_set()),
+            ("faceting", !self.faceting.is_not_set()),
+            ("proximityPrecision", !self.proximity_precision.is_not_set()),
+            ("searchCutoffMs", !self.search_cutoff_ms.is_not_set()),
+            ("embedders", !self.embedders.is_not_set()),
+            ("facetSearch", !self.facet_search.is_not_set()),
+            (
+                "localizedAttributes",
+                !self.localized_attributes.is_not_set(),
+            ),
+            ("typoTolerance", !self.typo_tolerance.is_not_set()),
+        ]
+        .iter()
+        .filter(|(_, configured)| *configured)
+        .map(|(name, _)| *name)
+        .collect()
+    }
+
+    /// Read the settings of a snapshot exported by any version of Meilisearch or by any SDK, ie for a migration tool.
+    ///
+    /// Unlike deserializing [Settings], it accepts the `snake_case` spelling of the keys and the legacy shapes:
+    /// - `attributesForFaceting`, replaced by `filterableAttributes` in Meilisearch v0.21,
+    /// - the `asc(attribute)` and `desc(attribute)` ranking rules, now written `attribute:asc` and `attribute:desc`,
+    /// - the `wordsPosition` ranking rule, removed in Meilisearch v0.21, which is dropped.
+    ///
+    /// The keys unknown to this version of the SDK, or whose value can't be read, are dropped instead of failing,
+    /// and listed in the returned [SnapshotReport] along with the conversions made.
+    /// It only fails when the snapshot isn't a JSON object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::*;
+    /// # use serde_json::json;
+    /// let (settings, report) = Settings::from_index_snapshot(json!({
+    ///     "stop_words": ["the"],
+    ///     "rankingRules": ["typo", "words", "desc(release_date)"],
+    ///     "acceptNewFields": true,
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(settings.stop_words, Setting::Set(vec!["the".to_string()]));
+    /// assert_eq!(
+    ///     settings.ranking_rules,
+    ///     Setting::Set(vec!["typo".to_string(), "words".to_string(), "release_date:desc".to_string()])
+    /// );
+    /// assert_eq!(report.dropped_keys, ["acceptNewFields"]);
+    /// ```
+    pub fn from_index_snapshot(snapshot: Value) -> Result<(Settings, SnapshotReport), Error> {
+        let snapshot = match snapshot {
+            Value::Object(snapshot) => snapshot,
+            other => {
+                let error = serde::de::Error::custom("the snapshot must be a JSON object");
+                return Err(Error::parse_error::<Settings>(other.to_string(), error));
+            }
+        };
+
+        let mut report = SnapshotReport::default();
+        let mut keys: Vec<(String, String)> = snapshot
+            .keys()
+            .map(|key| (key.clone(), snapshot_key(key)))
+            .collect();
+        // the keys spelled like Meilisearch does take precedence over their snake_case spelling,
+        // which takes precedence over the legacy names
+        keys.sort_by_key(|(key, name)| (key != name, camel_case(key) != *name));
+
+        let mut snapshot = snapshot;
+        let mut settings = Map::new();
+        for (key, name) in keys {
+            let value = snapshot.remove(&key).unwrap_or_default();
+            if settings.contains_key(&name) {
+                report.dropped_keys.push(key);
+                continue;
+            }
+            let value = snapshot_value(&name, value, &mut report);
+
+            let mut single = Map::new();
+            single.insert(name.clone(), value);
+            match serde_json::from_value::<Settings>(Value::Object(single.clone())) {
+                Err(error) => report.invalid_keys.push((key, error.to_string())),
+                Ok(parsed) if parsed.configured_fields().is_empty() => {
+                    report.dropped_keys.push(key)
+                }
+                Ok(_) => {
+                    if key != name {
+                        report.renamed_keys.push((key, name));
+                    }
+                    settings.extend(single);
+                }
+            }
+        }
+        report.dropped_keys.sort();
+        report.invalid_keys.sort();
+        report.renamed_keys.sort();
+
+        let body = Value::Object(settings);
+        let settings = serde_json::from_value(body.clone())
+            .map_err(|error| Error::parse_error::<Settings>(body.to_string(), error))?;
+        Ok((settings, report))
+    }
+
+    /// Write the settings as pretty-printed JSON, ie to a file applied later with [Index::apply_settings_file].
+    ///
+    /// All the defined settings are written, including the nested ones like the [typo tolerance](Settings::typo_tolerance),
+    /// and the [reset](Setting::Reset) ones are written as `null`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Settings, PaginationSetting};
+    /// let settings = Settings::new()
+    ///     .with_stop_words(["a", "the"])
+    ///     .with_pagination(PaginationSetting::new().with_max_total_hits(100));
+    ///
+    /// let mut file = Vec::new();
+    /// settings.to_writer(&mut file).unwrap();
+    ///
+    /// let read = Settings::from_reader(file.as_slice()).unwrap();
+    /// assert_eq!(read.to_request_body(), settings.to_request_body());
+    /// ```
+    pub fn to_writer(&self, writer: impl Write) -> Result<(), Error> {
+        // serializing the settings can only fail because of the writer
+        serde_json::to_writer_pretty(writer, self).map_err(|error| Error::Io(error.into()))
+    }
+
+    /// Like [Settings::to_writer], but into a [String].
+    pub fn to_json_string(&self) -> String {
+        // serializing the settings can't fail
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read settings written as JSON, ie by [Settings::to_writer].
+    ///
+    /// Unlike deserializing [Settings], which ignores the keys it doesn't know, it fails with [Error::UnknownSettingsKey]
+    /// naming the first key that isn't a setting, so a typo in a file doesn't go unnoticed.
+    /// The nested keys are named with their path, ie `typoTolerance.enabeld`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{errors::Error, settings::{Settings, Setting}};
+    /// let settings = Settings::from_reader(r#"{ "stopWords": ["the"] }"#.as_bytes()).unwrap();
+    /// assert_eq!(settings.stop_words, Setting::Set(vec!["the".to_string()]));
+    ///
+    /// let error = Settings::from_reader(r#"{ "typoTolerance": { "enabeld": false } }"#.as_bytes()).unwrap_err();
+    /// assert!(matches!(error, Error::UnknownSettingsKey(key) if key == "typoTolerance.enabeld"));
+    /// ```
+    pub fn from_reader(mut reader: impl Read) -> Result<Settings, Error> {
+        let mut json = String::new();
+        reader.read_to_string(&mut json).map_err(Error::Io)?;
+        Settings::from_json_str(&json)
+    }
+
+    /// Like [Settings::from_reader], but from a [str].
+    pub fn from_json_str(json: &str) -> Result<Settings, Error> {
+        /// The first key of `given` missing from `parsed`, the same JSON once deserialized and serialized back.
+        fn unknown_key(path: &str, given: &Value, parsed: &Value) -> Option<String> {
+            let (given, parsed) = match (given, parsed) {
+                (Value::Object(given), Value::Object(parsed)) => (given, parsed),
+                _ => return None,
+            };
+            given.iter().find_map(|(key, value)| {
+                let path = match path {
+                    "" => key.clone(),
+                    _ => format!("{}.{}", path, key),
+                };
+                match parsed.get(key) {
+                    Some(parsed) => unknown_key(&path, value, parsed),
+                    None if is_known_but_skipped(&path, value) => None,
+                    None => Some(path),
+                }
+            })
+        }
+
+        /// Whether the field at `path` is known but not serialized back:
+        /// the optional fields when they are `null`, and the API keys of the embedders.
+        fn is_known_but_skipped(path: &str, value: &Value) -> bool {
+            const OPTIONAL_FIELDS: &[&str] = &[
+                "typoTolerance.minWordSizeForTypos.oneTypo",
+                "typoTolerance.minWordSizeForTypos.twoTypos",
+            ];
+            const OPTIONAL_EMBEDDER_FIELDS: &[&str] = &["model", "documentTemplate", "dimensions"];
+
+            let embedder_field = path
+                .strip_prefix("embedders.")
+                .and_then(|path| path.rsplit_once('.'))
+                .map(|(_, field)| field);
+            match embedder_field {
+                Some("apiKey") => true,
+                Some(field) => value.is_null() && OPTIONAL_EMBEDDER_FIELDS.contains(&field),
+                None => value.is_null() && OPTIONAL_FIELDS.contains(&path),
+            }
+        }
+
+        let given: Value = serde_json::from_str(json)
+            .map_err(|error| Error::parse_error::<Settings>(json, error))?;
+        if !given.is_object() {
+            let error = serde::de::Error::custom("the settings must be a JSON object");
+            return Err(Error::parse_error::<Settings>(json, error));
+        }
+        let settings: Settings = serde_json::from_value(given.clone())
+            .map_err(|error| Error::parse_error::<Settings>(json, error))?;
+        match unknown_key("", &given, &settings.to_request_body()) {
+            Some(key) => Err(Error::UnknownSettingsKey(key)),
+            None => Ok(settings),
+        }
+    }
+
+    /// Check that the [proximity precision](Settings::proximity_precision) has an effect:
+    /// it only changes how the `proximity` ranking rule ranks the documents, so it's useless without it.
+    ///
+    /// The check is done locally, so the ranking rules of the index aren't known if they are not part of the settings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::{Settings, ProximityPrecision};
+    /// let settings = Settings::new()
+    ///     .with_ranking_rules(["words", "typo", "attribute"])
+    ///     .with_proximity_precision(ProximityPrecision::ByAttribute);
+    ///
+    /// assert!(settings.validate_proximity_config().is_err());
+    /// ```
+    pub fn validate_proximity_config(&self) -> Result<(), String> {
+        match (&self.proximity_precision, &self.ranking_rules) {
+            (Setting::Set(precision), Setting::Set(ranking_rules))
+                if !ranking_rules.iter().any(|rule| rule == "proximity") =>
+            {
+                Err(format!(
+                    "The proximity precision is set to `{}` but the `proximity` ranking rule isn't part of the ranking rules {:?}, so it has no effect.",
+                    precision,
+                    ranking_rules
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that the settings don't contradict each other, and return all the problems found:
+    /// - the [distinct attribute](Settings::distinct_attribute) or a [sortable attribute](Settings::sortable_attributes)
+    ///   is hidden by the [displayed attributes](Settings::displayed_attributes), so the results can't be checked,
+    /// - the sortable attributes are useless without the `sort` ranking rule,
+    /// - a custom ranking rule, ie `release_date:desc`, uses an attribute that isn't sortable,
+    /// - the [proximity precision](Settings::validate_proximity_config) has no effect.
+    ///
+    /// Like [Settings::validate_proximity_config], only the settings that are [set](Setting::Set) are compared.
+    /// Use [Settings::validate_for_primary_key] to check the displayed attributes against the primary key as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let settings = Settings::new()
+    ///     .with_sortable_attributes(["year"])
+    ///     .with_ranking_rules(["words", "sort", "rating:desc"]);
+    ///
+    /// assert_eq!(
+    ///     settings.validate(),
+    ///     Err(vec!["The ranking rule `rating:desc` uses `rating`, which isn't a sortable attribute.".to_string()])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        let sortable = self.sortable_attributes.as_option();
+        let ranking_rules = self.ranking_rules.as_option();
+
+        if let Setting::Set(displayed) = &self.displayed_attributes {
+            let is_displayed = |attribute: &String| {
+                displayed
+                    .iter()
+                    .any(|displayed| displayed == "*" || displayed == attribute)
+            };
+            if let Setting::Set(distinct) = &self.distinct_attribute {
+                if !is_displayed(distinct) {
+                    problems.push(format!(
+                        "The distinct attribute `{}` isn't part of the displayed attributes.",
+                        distinct
+                    ));
+                }
+            }
+            for attribute in sortable.into_iter().flatten() {
+                if !is_displayed(attribute) {
+                    problems.push(format!(
+                        "The sortable attribute `{}` isn't part of the displayed attributes.",
+                        attribute
+                    ));
+                }
+            }
+        }
+
+        if let (Some(sortable), Some(ranking_rules)) = (sortable, ranking_rules) {
+            if !sortable.is_empty() && !ranking_rules.iter().any(|rule| rule == "sort") {
+                problems.push(format!(
+                    "The sortable attributes {:?} have no effect without the `sort` ranking rule.",
+                    sortable
+                ));
+            }
+        }
+
+        for rule in ranking_rules.into_iter().flatten() {
+            let attribute = rule
+                .strip_suffix(":asc")
+                .or_else(|| rule.strip_suffix(":desc"));
+            if let (Some(attribute), Some(sortable)) = (attribute, sortable) {
+                if !sortable.iter().any(|sortable| sortable == attribute) {
+                    problems.push(format!(
+                        "The ranking rule `{}` uses `{}`, which isn't a sortable attribute.",
+                        rule, attribute
+                    ));
+                }
+            }
+        }
+
+        if let Err(problem) = self.validate_proximity_config() {
+            problems.push(problem);
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// [Validate](Settings::validate) the settings of an index whose primary key is `primary_key`,
+    /// checking as well that the [displayed attributes](Settings::displayed_attributes) don't hide it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::settings::Settings;
+    /// let settings = Settings::new().with_displayed_attributes(["title"]);
+    ///
+    /// assert!(settings.validate().is_ok());
+    /// assert!(settings.validate_for_primary_key("id").is_err());
+    /// ```
+    pub fn validate_for_primary_key(&self, primary_key: &str) -> Result<(), Vec<String>> {
+        let mut problems = self.validate().err().unwrap_or_default();
+        if let Setting::Set(displayed) = &self.displayed_attributes {
+            if !displayed
+                .iter()
+                .any(|displayed| displayed == "*" || displayed == primary_key)
+            {
+                problems.push(format!(
+                    "The primary key `{}` isn't part of the displayed attributes.",
+                    primary_key
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+/// What [Index::set_settings] would send to Meilisearch, built by [Index::set_settings_dry_run].
+///
+/// It is displayed as a human readable summary, ie for CI logs, and can be serialized to be kept as an artifact.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsPlan {
+    pub index_uid: String,
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The route of the request, relative to the host.
+    pub route: String,
+    /// The JSON body of the request.
+    pub body: Value,
+    /// The type of the task Meilisearch would enqueue.
+    pub task_type: String,
+    /// The settings that would change, when the current settings have been fetched
+    /// with [Index::set_settings_dry_run_with_diff].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Vec<SettingChange>>,
+}
+
+/// A setting that would be modified by a [SettingsPlan], identified by its dotted path, ie `pagination.maxTotalHits`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingChange {
+    pub path: String,
+    pub current: Option<Value>,
+    pub new: Value,
+}
+
+impl SettingsPlan {
+    fn with_current(self, current: &Settings) -> SettingsPlan {
+        let current = current.to_flat_map();
+        let changes = serde_json::from_value::<Settings>(self.body.clone())
+            .unwrap_or_default()
+            .to_flat_map()
+            .into_iter()
+            .filter(|(path, new)| current.get(path) != Some(new))
+            .map(|(path, new)| SettingChange {
+                current: current.get(&path).cloned(),
+                path,
+                new,
+            })
+            .collect();
+
+        SettingsPlan {
+            changes: Some(changes),
+            ..self
+        }
+    }
+}
+
+impl std::fmt::Display for SettingsPlan {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(fmt, "{} {} ({})", self.method, self.route, self.task_type)?;
+        // serializing a json value can't fail
+        writeln!(
+            fmt,
+            "{}",
+            serde_json::to_string_pretty(&self.body).unwrap_or_default()
+        )?;
+        match &self.changes {
+            Some(changes) if changes.is_empty() => writeln!(fmt, "No setting changes.")?,
+            Some(changes) => {
+                writeln!(fmt, "{} setting(s) change:", changes.len())?;
+                for change in changes {
+                    match &change.current {
+                        Some(current) => {
+                            writeln!(fmt, "  {}: {} -> {}", change.path, current, change.new)?
+                        }
+                        None => writeln!(fmt, "  {}: (unset) -> {}", change.path, change.new)?,
+                    }
+                }
+            }
+            None => (),
+        }
+        Ok(())
+    }
+}
+
+/// What [Settings::from_index_snapshot] converted or dropped to read a settings snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotReport {
+    /// The keys that were dropped: unknown to this version of the SDK, or already given with their current spelling.
+    pub dropped_keys: Vec<String>,
+    /// The keys whose value couldn't be read into their setting, which were dropped, with the reason.
+    pub invalid_keys: Vec<(String, String)>,
+    /// The legacy spellings of the keys, with the name they were read as, ie `stop_words` read as `stopWords`.
+    /// The nested keys are given with their path, ie `faceting.max_values_per_facet`.
+    pub renamed_keys: Vec<(String, String)>,
+    /// The ranking rules removed from Meilisearch, which were dropped.
+    pub dropped_ranking_rules: Vec<String>,
+}
+
+impl SnapshotReport {
+    /// Whether every setting of the snapshot was kept, possibly converted.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped_keys.is_empty()
+            && self.invalid_keys.is_empty()
+            && self.dropped_ranking_rules.is_empty()
+    }
+}
+
+/// The name of the setting a key of a snapshot is read as, ie `stopWords` for `stop_words`.
+fn snapshot_key(key: &str) -> String {
+    let name = camel_case(key);
+    match name.as_str() {
+        "attributesForFaceting" => "filterableAttributes".to_string(),
+        _ => name,
+    }
+}
+
+fn camel_case(key: &str) -> String {
+    let mut parts = key.split('_');
+    let mut name = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+/// Convert the legacy shapes of the value of the setting `name` of a snapshot.
+fn snapshot_value(name: &str, value: Value, report: &mut SnapshotReport) -> Value {
+    fn rename_keys(
+        prefix: &str,
+        object: Map<String, Value>,
+        report: &mut SnapshotReport,
+    ) -> Map<String, Value> {
+        object
+            .into_iter()
+            .map(|(key, value)| {
+                let name = snapshot_key(&key);
+                if name != key {
+                    report.renamed_keys.push((
+                        format!("{}.{}", prefix, key),
+                        format!("{}.{}", prefix, name),
+                    ));
+                }
+                (name, value)
+            })
+            .collect()
+    }
+
+    match (name, value) {
+        ("rankingRules", Value::Array(rules)) => rules
+            .into_iter()
+            .filter_map(|rule| {
+                let rule = match rule {
+                    Value::String(rule) => rule,
+                    other => return Some(other),
+                };
+                if rule == "wordsPosition" {
+                    report.dropped_ranking_rules.push(rule);
+                    return None;
+                }
+                let legacy = ["asc", "desc"].iter().find_map(|order| {
+                    rule.strip_prefix(order)?
+                        .strip_prefix('(')?
+                        .strip_suffix(')')
+                        .map(|field| format!("{}:{}", field, order))
+                });
+                Some(Value::String(legacy.unwrap_or(rule)))
+            })
+            .collect(),
+        ("pagination" | "faceting", Value::Object(object)) => {
+            Value::Object(rename_keys(name, object, report))
+        }
+        ("typoTolerance", Value::Object(object)) => rename_keys(name, object, report)
+            .into_iter()
+            .map(|(key, value)| match value {
+                Value::Object(sizes) if key == "minWordSizeForTypos" => {
+                    let prefix = format!("{}.{}", name, key);
+                    let sizes = rename_keys(&prefix, sizes, report);
+                    (key, Value::Object(sizes))
+                }
+                value => (key, value),
+            })
+            .collect(),
+        ("embedders", Value::Object(embedders)) => embedders
+            .into_iter()
+            .map(|(embedder, value)| match value {
+                Value::Object(object) => {
+                    let prefix = format!("embedders.{}", embedder);
+                    let object = rename_keys(&prefix, object, report);
+                    (embedder, Value::Object(object))
+                }
+                value => (embedder, value),
+            })
+            .collect(),
+        (_, value) => value,
+    }
+}
+
+/// The differences between the settings of two indexes, built by [Client::compare_settings](crate::client::Client::compare_settings).
+///
+/// It is displayed as a human readable summary, ie for CI logs, and can be serialized to be kept as an artifact.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsDiff {
+    pub uid_a: String,
+    pub uid_b: String,
+    /// The settings whose values differ, sorted by path.
+    pub differences: Vec<SettingDifference>,
+}
+
+/// A setting whose value differs between the two indexes of a [SettingsDiff],
+/// identified by its dotted path, ie `pagination.maxTotalHits`.
+///
+/// The value is `None` when the setting is missing from the settings of an index.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingDifference {
+    pub path: String,
+    pub a: Option<Value>,
+    pub b: Option<Value>,
+}
+
+impl SettingsDiff {
+    pub(crate) fn new(
+        uid_a: impl Into<String>,
+        a: &Settings,
+        uid_b: impl Into<String>,
+        b: &Settings,
+    ) -> SettingsDiff {
+        let mut a = a.to_flat_map();
+        let mut b = b.to_flat_map();
+        let paths = a.keys().chain(b.keys()).cloned().collect::<BTreeSet<_>>();
+        let differences = paths
+            .into_iter()
+            .filter_map(|path| {
+                let a = a.remove(&path);
+                let b = b.remove(&path);
+                (a != b).then_some(SettingDifference { path, a, b })
+            })
+            .collect();
+
+        SettingsDiff {
+            uid_a: uid_a.into(),
+            uid_b: uid_b.into(),
+            differences,
+        }
+    }
+
+    /// Whether the two indexes have the same settings.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl std::fmt::Display for SettingsDiff {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(
+                fmt,
+                "{} and {} have the same settings.",
+                self.uid_a, self.uid_b
+            );
+        }
+        writeln!(
+            fmt,
+            "{} setting(s) differ between {} and {}:",
+            self.differences.len(),
+            self.uid_a,
+            self.uid_b
+        )?;
+        for difference in &self.differences {
+            let value = |value: &Option<Value>| {
+                value
+                    .as_ref()
+                    .map_or_else(|| "(unset)".to_string(), Value::to_string)
+            };
+            writeln!(
+                fmt,
+                "  {}: {} / {}",
+                difference.path,
+                value(&difference.a),
+                value(&difference.b)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A field of [Settings], to [get only some of them](Index::get_settings_fields).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingsField {
+    /// [Settings::synonyms]
+    Synonyms,
+    /// [Settings::stop_words]
+    StopWords,
+    /// [Settings::dictionary]
+    Dictionary,
+    /// [Settings::separator_tokens]
+    SeparatorTokens,
+    /// [Settings::non_separator_tokens]
+    NonSeparatorTokens,
+    /// [Settings::ranking_rules]
+    RankingRules,
+    /// [Settings::filterable_attributes]
+    FilterableAttributes,
+    /// [Settings::sortable_attributes]
+    SortableAttributes,
+    /// [Settings::distinct_attribute]
+    DistinctAttribute,
+    /// [Settings::searchable_attributes]
+    SearchableAttributes,
+    /// [Settings::displayed_attributes]
+    DisplayedAttributes,
+    /// [Settings::pagination]
+    Pagination,
+    /// [Settings::faceting]
+    Faceting,
+    /// [Settings::typo_tolerance]
+    TypoTolerance,
+    /// [Settings::proximity_precision]
+    ProximityPrecision,
+    /// [Settings::search_cutoff_ms]
+    SearchCutoffMs,
+    /// [Settings::embedders]
+    Embedders,
+    /// [Settings::facet_search]
+    FacetSearch,
+    /// [Settings::localized_attributes]
+    LocalizedAttributes,
+}
+
+/// The type of the values of a field, see [FieldSuggestion].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    String,
+    Number,
+    Boolean,
+}
+
+/// A field that could be added to the [filterable attributes](Settings::filterable_attributes),
+/// returned by [Index::suggest_filterable_attributes].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSuggestion {
+    /// The name of the field, with the nested fields separated by dots, ie `release.year`.
+    pub field: String,
+    /// The number of distinct values of the field in the sampled documents.
+    pub cardinality_estimate: usize,
+    /// The type of the values, the values of the arrays being counted one by one.
+    pub value_kind: ValueKind,
+    /// Why the field is suggested, ie `4 distinct values in 6 documents`.
+    pub reason: String,
+}
+
+/// The strings longer than this, in characters or in words, are considered as text rather than as values to filter on.
+const MAX_VALUE_CHARS: usize = 64;
+const MAX_VALUE_WORDS: usize = 4;
+
+/// What was seen of a field in the sampled documents.
+#[derive(Debug, Default)]
+struct FieldStats {
+    documents: usize,
+    occurrences: usize,
+    values: HashSet<String>,
+    kinds: Vec<ValueKind>,
+    long_text: bool,
+}
+
+impl FieldStats {
+    fn record(&mut self, kind: ValueKind, value: String) {
+        if !self.kinds.contains(&kind) {
+            self.kinds.push(kind);
+        }
+        self.long_text |= kind == ValueKind::String
+            && (value.chars().count() > MAX_VALUE_CHARS
+                || value.split_whitespace().count() > MAX_VALUE_WORDS);
+        self.occurrences += 1;
+        self.values.insert(value);
+    }
+
+    /// The suggestion for this field, `None` if it doesn't look worth filtering on.
+    fn suggestion(self, field: String) -> Option<FieldSuggestion> {
+        let value_kind = match self.kinds[..] {
+            [kind] => kind,
+            // the values of several types can't be filtered consistently
+            _ => return None,
+        };
+        let cardinality_estimate = self.values.len();
+        let reason = match value_kind {
+            ValueKind::Boolean => format!("boolean present in {} documents", self.documents),
+            // a long text is searched rather than filtered
+            ValueKind::String if self.long_text => return None,
+            // a value never repeated identifies the documents, filtering on it matches a single one
+            _ if cardinality_estimate == self.occurrences && self.occurrences > 1 => return None,
+            _ => format!(
+                "{} distinct values in {} documents",
+                cardinality_estimate, self.documents
+            ),
+        };
+
+        Some(FieldSuggestion {
+            field,
+            cardinality_estimate,
+            value_kind,
+            reason,
+        })
+    }
+}
+
+/// Record the scalar values of `value` in the stats of `field`, and of its nested fields.
+fn collect_field_stats(
+    field: &str,
+    value: &Value,
+    stats: &mut BTreeMap<String, FieldStats>,
+    seen: &mut HashSet<String>,
+) {
+    let (kind, value) = match value {
+        Value::Null => return,
+        Value::Object(object) => {
+            for (key, value) in object {
+                let nested = match field {
+                    "" => key.clone(),
+                    _ => format!("{}.{}", field, key),
+                };
+                collect_field_stats(&nested, value, stats, seen);
+            }
+            return;
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_field_stats(field, value, stats, seen);
+            }
+            return;
+        }
+        Value::String(string) => (ValueKind::String, string.clone()),
+        Value::Number(number) => (ValueKind::Number, number.to_string()),
+        Value::Bool(boolean) => (ValueKind::Boolean, boolean.to_string()),
+    };
+
+    let field_stats = stats.entry(field.to_string()).or_default();
+    if seen.insert(field.to_string()) {
+        field_stats.documents += 1;
+    }
+    field_stats.record(kind, value);
+}
+
+/// Suggest the scalar fields of `documents` with a low or medium cardinality, sorted by cardinality.
+fn suggest_filterable_attributes(documents: &[Map<String, Value>]) -> Vec<FieldSuggestion> {
+    let mut stats = BTreeMap::new();
+    for document in documents {
+        let mut seen = HashSet::new();
+        for (field, value) in document {
+            collect_field_stats(field, value, &mut stats, &mut seen);
+        }
+    }
+
+    let mut suggestions: Vec<FieldSuggestion> = stats
+        .into_iter()
+        .filter_map(|(field, stats)| stats.suggestion(field))
+        .collect();
+    suggestions.sort_by_key(|suggestion| suggestion.cardinality_estimate);
+    suggestions
+}
+
+/// The settings holding a single value, whose routes are built by [Index::single_value_setting].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingsRoute {
+    DistinctAttribute,
+    Pagination,
+    Faceting,
+    ProximityPrecision,
+    SearchCutoffMs,
+    Embedders,
+    FacetSearch,
+    LocalizedAttributes,
+    TypoTolerance,
+}
+
+impl SettingsRoute {
+    fn path(self) -> &'static str {
+        match self {
+            SettingsRoute::DistinctAttribute => "distinct-attribute",
+            SettingsRoute::Pagination => "pagination",
+            SettingsRoute::Faceting => "faceting",
+            SettingsRoute::ProximityPrecision => "proximity-precision",
+            SettingsRoute::SearchCutoffMs => "search-cutoff-ms",
+            SettingsRoute::Embedders => "embedders",
+            SettingsRoute::FacetSearch => "facet-search",
+            SettingsRoute::LocalizedAttributes => "localized-attributes",
+            SettingsRoute::TypoTolerance => "typo-tolerance",
+        }
+    }
+
+    /// The objects are updated partially with a `PATCH`, the other values are replaced with a `PUT`.
+    fn is_partial(self) -> bool {
+        match self {
+            SettingsRoute::DistinctAttribute
+            | SettingsRoute::ProximityPrecision
+            | SettingsRoute::SearchCutoffMs
+            | SettingsRoute::FacetSearch
+            | SettingsRoute::LocalizedAttributes => false,
+            SettingsRoute::Pagination
+            | SettingsRoute::Faceting
+            | SettingsRoute::Embedders
+            | SettingsRoute::TypoTolerance => true,
+        }
+    }
+}
+
+/// The get, set and reset requests of a setting holding a single value of type `T`.
+struct SingleValueSetting<'a, T> {
+    index: &'a Index,
+    route: SettingsRoute,
+    value: PhantomData<T>,
+}
+
+impl<'a, T: Serialize + DeserializeOwned + 'static> SingleValueSetting<'a, T> {
+    fn url(&self) -> String {
+        format!(
+            "{}/indexes/{}/settings/{}",
+            self.index.client.host,
+            self.index.uid,
+            self.route.path()
+        )
+    }
+
+    async fn get(&self) -> Result<T, Error> {
+        request::<(), T>(&self.url(), &self.index.client, Method::Get(()), 200).await
+    }
+
+    async fn set(&self, value: &T) -> Result<TaskInfo, Error> {
+        let method = if self.route.is_partial() {
+            Method::Patch(value)
+        } else {
+            Method::Put(value)
+        };
+        request::<&T, TaskInfo>(&self.url(), &self.index.client, method, 202).await
+    }
+
+    async fn reset(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(&self.url(), &self.index.client, Method::Delete, 202).await
+    }
+}
+
+impl Index {
+    fn single_value_setting<T>(&self, route: SettingsRoute) -> SingleValueSetting<'_, T> {
+        SingleValueSetting {
+            index: self,
+            route,
+            value: PhantomData,
+        }
+    }
+
+    /// Get [Settings] of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_settings");
+    /// let settings = index.get_settings().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_settings(&self) -> Result<Settings, Error> {
+        request::<(), Settings>(
+            &format!("{}/indexes/{}/settings", self.client.host, self.uid),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get only the `fields` of the [Settings] of the [Index], the other ones are [not set](Setting::NotSet).
+    ///
+    /// All the settings are still fetched with a single request.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_settings_fields", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_settings_fields");
+    /// let settings = index
+    ///     .get_settings_fields(&[SettingsField::StopWords, SettingsField::RankingRules])
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(settings.configured_fields(), ["stopWords", "rankingRules"]);
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_settings_fields(&self, fields: &[SettingsField]) -> Result<Settings, Error> {
+        Ok(self.get_settings().await?.select(fields))
+    }
+
+    /// Read the settings of a JSON file, ie written by [Settings::to_writer], and [apply](Index::set_settings) them to the [Index].
+    ///
+    /// The file is read with [Settings::from_reader], so it fails with [Error::UnknownSettingsKey] if the file contains a key
+    /// that isn't a setting, without sending anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("apply_settings_file", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("apply_settings_file");
+    /// # let path = std::env::temp_dir().join("apply_settings_file.json");
+    /// # let file = std::fs::File::create(&path).unwrap();
+    /// # Settings::new().with_stop_words(["the"]).to_writer(file).unwrap();
+    ///
+    /// let task = index.apply_settings_file(&path).await.unwrap();
+    /// # task.wait_for_completion(&client, None, None).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # std::fs::remove_file(path).unwrap();
+    /// # });
+    /// ```
+    pub async fn apply_settings_file(&self, path: impl AsRef<Path>) -> Result<TaskInfo, Error> {
+        let file = std::fs::File::open(path).map_err(Error::Io)?;
+        let settings = Settings::from_reader(std::io::BufReader::new(file))?;
+        self.set_settings(&settings).await
+    }
+
+    /// Suggest the fields that could be made [filterable](Settings::filterable_attributes), from a sample of the documents of the [Index].
+    ///
+    /// The first `sample_size` documents are fetched and their fields analyzed client-side: the fields holding
+    /// values of a single type, whose values repeat across the documents, are suggested. The long texts and the values
+    /// unique to each document, like ids, are left out. Nothing is changed on the index.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::*};
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let movies = client.index("suggest_filterable_attributes");
+    /// # let documents = [
+    /// #     json!({ "id": 1, "genre": "drama", "overview": "A lawyer fights for his rights." }),
+    /// #     json!({ "id": 2, "genre": "drama", "overview": "A love story in the fifties." }),
+    /// # ];
+    /// # movies.add_documents(&documents, Some("id")).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// let suggestions = movies.suggest_filterable_attributes(100).await.unwrap();
+    /// let fields: Vec<&str> = suggestions.iter().map(|suggestion| suggestion.field.as_str()).collect();
+    /// assert_eq!(fields, ["genre"]);
+    /// # movies.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn suggest_filterable_attributes(
+        &self,
+        sample_size: usize,
+    ) -> Result<Vec<FieldSuggestion>, Error> {
+        let documents = DocumentsQuery::new(self)
+            .with_limit(sample_size)
+            .execute::<Map<String, Value>>()
+            .await?;
+        Ok(suggest_filterable_attributes(&documents.results))
+    }
+
+    /// Get [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_synonyms");
+    /// let synonyms = index.get_synonyms().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_synonyms(&self) -> Result<HashMap<String, Vec<String>>, Error> {
+        request::<(), HashMap<String, Vec<String>>>(
+            &format!(
+                "{}/indexes/{}/settings/synonyms",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get the [dictionary](https://www.meilisearch.com/docs/reference/api/settings#dictionary) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_dictionary", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_dictionary");
+    /// let dictionary = index.get_dictionary().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_dictionary(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/dictionary",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get the [separator tokens](https://www.meilisearch.com/docs/reference/api/settings#separator-tokens) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_separator_tokens");
+    /// let separator_tokens = index.get_separator_tokens().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_separator_tokens(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/separator-tokens",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get the [non-separator tokens](https://www.meilisearch.com/docs/reference/api/settings#non-separator-tokens) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_non_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_non_separator_tokens");
+    /// let non_separator_tokens = index.get_non_separator_tokens().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_non_separator_tokens(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/non-separator-tokens",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_pagination");
+    /// let pagination = index.get_pagination().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_pagination(&self) -> Result<PaginationSetting, Error> {
+        self.single_value_setting(SettingsRoute::Pagination)
+            .get()
+            .await
+    }
+
+    /// Get [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_stop_words");
+    /// let stop_words = index.get_stop_words().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_stop_words(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/stop-words",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_ranking_rules");
+    /// let ranking_rules = index.get_ranking_rules().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_ranking_rules(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/ranking-rules",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_filterable_attributes");
+    /// let filterable_attributes = index.get_filterable_attributes().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_filterable_attributes(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/filterable-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_sortable_attributes");
+    /// let sortable_attributes = index.get_sortable_attributes().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_sortable_attributes(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/sortable-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_distinct_attribute");
+    /// let distinct_attribute = index.get_distinct_attribute().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_distinct_attribute(&self) -> Result<Option<String>, Error> {
+        self.single_value_setting(SettingsRoute::DistinctAttribute)
+            .get()
+            .await
+    }
+
+    /// Get [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_searchable_attributes");
+    /// let searchable_attributes = index.get_searchable_attributes().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_searchable_attributes(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/searchable-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_displayed_attributes");
+    /// let displayed_attributes = index.get_displayed_attributes().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_displayed_attributes(&self) -> Result<Vec<String>, Error> {
+        request::<(), Vec<String>>(
+            &format!(
+                "{}/indexes/{}/settings/displayed-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Get(()),
+            200,
+        )
+        .await
+    }
+
+    /// Get [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_faceting");
+    /// let faceting = index.get_faceting().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_faceting(&self) -> Result<FacetingSettings, Error> {
+        self.single_value_setting(SettingsRoute::Faceting)
+            .get()
+            .await
+    }
+
+    /// Get the [typo tolerance](https://www.meilisearch.com/docs/reference/api/settings#typo-tolerance) settings of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_typo_tolerance", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_typo_tolerance");
+    ///
+    /// let typo_tolerance = index.get_typo_tolerance().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_typo_tolerance(&self) -> Result<TypoToleranceSettings, Error> {
+        self.single_value_setting(SettingsRoute::TypoTolerance)
+            .get()
+            .await
+    }
+
+    /// Get the [proximity precision](https://www.meilisearch.com/docs/reference/api/settings#proximity-precision) of the [Index].
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_proximity_precision", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_proximity_precision");
+    /// let proximity_precision = index.get_proximity_precision().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_proximity_precision(&self) -> Result<ProximityPrecision, Error> {
+        self.single_value_setting(SettingsRoute::ProximityPrecision)
+            .get()
+            .await
+    }
+
+    /// Get the [search cutoff](https://www.meilisearch.com/docs/reference/api/settings#search-cutoff) of the [Index], in milliseconds.
+    /// It's `None` when the default cutoff of Meilisearch, 1500ms, is used.
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_search_cutoff_ms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_search_cutoff_ms");
+    /// let search_cutoff_ms = index.get_search_cutoff_ms().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_search_cutoff_ms(&self) -> Result<Option<u64>, Error> {
+        self.single_value_setting(SettingsRoute::SearchCutoffMs)
+            .get()
+            .await
+    }
+
+    /// Get the [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index], by name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_embedders");
+    /// let embedders = index.get_embedders().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_embedders(&self) -> Result<HashMap<String, Embedder>, Error> {
+        self.single_value_setting(SettingsRoute::Embedders)
+            .get()
+            .await
+    }
+
+    /// Get whether the [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) is enabled on the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_facet_search");
+    ///
+    /// let facet_search = index.get_facet_search().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_facet_search(&self) -> Result<bool, Error> {
+        self.single_value_setting(SettingsRoute::FacetSearch)
+            .get()
+            .await
+    }
+
+    /// Get the [localized attributes](https://www.meilisearch.com/docs/reference/api/settings#localized-attributes) of the [Index],
+    /// `None` when the languages are detected by Meilisearch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::LocalizedAttribute};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("get_localized_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("get_localized_attributes");
+    ///
+    /// let localized_attributes = index.get_localized_attributes().await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn get_localized_attributes(&self) -> Result<Option<Vec<LocalizedAttribute>>, Error> {
+        self.single_value_setting(SettingsRoute::LocalizedAttributes)
+            .get()
+            .await
+    }
+
+    /// Update [settings](../settings/struct.Settings.html) of the [Index].
+    /// Updates in the settings are partial: the fields [not set](Setting::NotSet) are left unchanged, and the [reset](Setting::Reset) ones go back to their default value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{Settings, PaginationSetting}};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_settings");
+    ///
+    /// let stop_words = vec![String::from("a"), String::from("the"), String::from("of")];
+    /// let settings = Settings::new()
+    ///     .with_stop_words(stop_words.clone())
+    ///     .with_pagination(PaginationSetting::new().with_max_total_hits(100));
+    ///
+    /// let task = index.set_settings(&settings).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_settings(&self, settings: &Settings) -> Result<TaskInfo, Error> {
+        request::<&Settings, TaskInfo>(
+            &format!("{}/indexes/{}/settings", self.client.host, self.uid),
+            &self.client,
+            Method::Patch(settings),
+            202,
+        )
+        .await
+    }
+
+    /// Update the settings of the [Index] like [Index::set_settings], unless the index was modified after `last_seen_updated_at`,
+    /// ie by another job updating its settings. [Error::ConcurrentModification] is returned instead.
+    ///
+    /// It's best-effort and not an atomic compare-and-swap: the index is fetched before the settings are sent,
+    /// so a modification made between the two requests, or a task still enqueued, is not detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, errors::Error, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_settings_if_unchanged_since", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.get_index("set_settings_if_unchanged_since").await.unwrap();
+    ///
+    /// let settings = Settings::new().with_stop_words(["a", "the"]);
+    /// match index.set_settings_if_unchanged_since(&settings, index.updated_at.unwrap()).await {
+    ///     Ok(task) => { task.wait_for_completion(&client, None, None).await.unwrap(); },
+    ///     Err(Error::ConcurrentModification { .. }) => println!("the settings changed, read them again"),
+    ///     Err(error) => panic!("{}", error),
+    /// }
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_settings_if_unchanged_since(
+        &self,
+        settings: &Settings,
+        last_seen_updated_at: OffsetDateTime,
+    ) -> Result<TaskInfo, Error> {
+        let index = self.client.get_index(&self.uid).await?;
+        match index.updated_at {
+            Some(server_updated_at) if server_updated_at > last_seen_updated_at => {
+                Err(Error::ConcurrentModification { server_updated_at })
+            }
+            _ => self.set_settings(settings).await,
+        }
+    }
+
+    /// Update the settings of the [Index] that differ from its current settings, so syncing the settings
+    /// from the code on every deploy only enqueues a task when something changed.
+    ///
+    /// The current settings are fetched, then only the settings of `desired` that are different are sent.
+    /// The settings [not set](Setting::NotSet) are left as they are, and the [reset](Setting::Reset) ones are always sent.
+    /// `None` is returned when the index is already in sync.
+    ///
+    /// The comparison is made with the settings as they are *processed*, so a settings update still enqueued
+    /// isn't taken into account: wait for it before syncing again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("sync_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("sync_settings");
+    /// let settings = Settings::new().with_filterable_attributes(["genres", "year"]);
+    ///
+    /// if let Some(task) = index.sync_settings(&settings).await.unwrap() {
+    ///     task.wait_for_completion(&client, None, None).await.unwrap();
+    /// }
+    /// assert!(index.sync_settings(&settings).await.unwrap().is_none());
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn sync_settings(&self, desired: &Settings) -> Result<Option<TaskInfo>, Error> {
+        let current = self.get_settings().await?;
+        let changes = desired.diff(&current);
+        if changes.configured_fields().is_empty() {
+            return Ok(None);
+        }
+
+        self.set_settings(&changes).await.map(Some)
+    }
+
+    /// Get what [Index::set_settings] would send to Meilisearch for these settings, without sending anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use serde_json::json;
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let index = client.index("movies");
+    ///
+    /// let plan = index.set_settings_dry_run(&Settings::new().with_stop_words(["the"]));
+    /// assert_eq!(plan.method, "PATCH");
+    /// assert_eq!(plan.route, "/indexes/movies/settings");
+    /// assert_eq!(plan.body, json!({ "stopWords": ["the"] }));
+    /// println!("{}", plan);
+    /// ```
+    pub fn set_settings_dry_run(&self, settings: &Settings) -> SettingsPlan {
+        SettingsPlan {
+            index_uid: self.uid.clone(),
+            method: "PATCH".to_string(),
+            route: format!("/indexes/{}/settings", self.uid),
+            body: settings.to_request_body(),
+            task_type: "settingsUpdate".to_string(),
+            changes: None,
+        }
+    }
+
+    /// Like [Index::set_settings_dry_run], but also fetch the current settings of the [Index]
+    /// to list the [changes](SettingsPlan::changes) the update would make.
+    ///
+    /// Nothing is modified on the server.
+    pub async fn set_settings_dry_run_with_diff(
+        &self,
+        settings: &Settings,
+    ) -> Result<SettingsPlan, Error> {
+        let current = self.get_settings().await?;
+
+        Ok(self.set_settings_dry_run(settings).with_current(&current))
+    }
+
+    /// Update the settings of the [Index] like [Index::set_settings], and get the settings as they were before the update.
+    ///
+    /// The returned snapshot contains all the settings of the index and can be given to [Index::rollback_settings] to
+    /// restore them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_settings_with_rollback", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_settings_with_rollback");
+    ///
+    /// let experiment = Settings::new().with_ranking_rules(["sort", "words", "typo"]);
+    /// let (task, previous) = index.set_settings_with_rollback(&experiment).await.unwrap();
+    /// task.wait_for_completion(&client, None, None).await.unwrap();
+    ///
+    /// // the experiment didn't go well
+    /// index.rollback_settings(&previous).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_settings_with_rollback(
+        &self,
+        settings: &Settings,
+    ) -> Result<(TaskInfo, Settings), Error> {
+        let previous = self.get_settings().await?;
+        let task = self.set_settings(settings).await?;
+
+        Ok((task, previous))
+    }
+
+    /// Restore the settings returned by [Index::set_settings_with_rollback].
+    ///
+    /// Since the snapshot contains every setting, all of them are overwritten by their previous value.
+    pub async fn rollback_settings(&self, previous: &Settings) -> Result<TaskInfo, Error> {
+        self.set_settings(previous).await
+    }
+
+    /// Update [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_synonyms");
+    ///
+    /// let mut synonyms = std::collections::HashMap::new();
+    /// synonyms.insert(String::from("wolverine"), vec![String::from("xmen"), String::from("logan")]);
+    /// synonyms.insert(String::from("logan"), vec![String::from("xmen"), String::from("wolverine")]);
+    /// synonyms.insert(String::from("wow"), vec![String::from("world of warcraft")]);
+    ///
+    /// let task = index.set_synonyms(&synonyms).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_synonyms(
+        &self,
+        synonyms: &HashMap<String, Vec<String>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<&HashMap<String, Vec<String>>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/synonyms",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(synonyms),
+            202,
+        )
+        .await
+    }
+
+    /// Update [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{Settings, PaginationSetting}};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_pagination");
+    /// let pagination = PaginationSetting::new().with_max_total_hits(100);
+    /// let task = index.set_pagination(pagination).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_pagination(&self, pagination: PaginationSetting) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::Pagination)
+            .set(&pagination)
+            .await
+    }
+
+    /// Update [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_stop_words");
+    ///
+    /// let stop_words = ["the", "of", "to"];
+    /// let task = index.set_stop_words(&stop_words).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_stop_words(
+        &self,
+        stop_words: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/stop-words",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                stop_words
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
+        )
+        .await
+    }
+
+    /// Update the [dictionary](https://www.meilisearch.com/docs/reference/api/settings#dictionary) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_dictionary", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_dictionary");
+    ///
+    /// let dictionary = ["J. R. R. Tolkien", "W. E. B. Du Bois"];
+    /// let task = index.set_dictionary(&dictionary).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_dictionary(
+        &self,
+        dictionary: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/dictionary",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                dictionary
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
+        )
+        .await
+    }
+
+    /// Update the [separator tokens](https://www.meilisearch.com/docs/reference/api/settings#separator-tokens) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_separator_tokens");
+    ///
+    /// let separator_tokens = ["@", "#"];
+    /// let task = index.set_separator_tokens(&separator_tokens).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_separator_tokens(
+        &self,
+        separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/separator-tokens",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                separator_tokens
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
+        )
+        .await
+    }
+
+    /// Update the [non-separator tokens](https://www.meilisearch.com/docs/reference/api/settings#non-separator-tokens) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_non_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_non_separator_tokens");
+    ///
+    /// let non_separator_tokens = ["@", "#"];
+    /// let task = index.set_non_separator_tokens(&non_separator_tokens).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_non_separator_tokens(
+        &self,
+        non_separator_tokens: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/non-separator-tokens",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                non_separator_tokens
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
+        )
+        .await
+    }
+
+    /// Update [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_ranking_rules");
+    ///
+    /// let ranking_rules = [
+    ///     "words",
+    ///     "typo",
+    ///     "proximity",
+    ///     "attribute",
+    ///     "sort",
+    ///     "exactness",
+    ///     "release_date:asc",
+    ///     "rank:desc",
+    /// ];
+    /// let task = index.set_ranking_rules(ranking_rules).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_ranking_rules(
+        &self,
+        ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/ranking-rules",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                ranking_rules
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
+        )
+        .await
+    }
+
+    /// Like [Index::set_ranking_rules], with [typed ranking rules](RankingRule) so a typo is caught by the compiler.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::RankingRule};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_ranking_rules_typed", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_ranking_rules_typed");
+    ///
+    /// let ranking_rules = [
+    ///     RankingRule::Words,
+    ///     RankingRule::Typo,
+    ///     RankingRule::Proximity,
+    ///     RankingRule::Attribute,
+    ///     RankingRule::Sort,
+    ///     RankingRule::Exactness,
+    ///     RankingRule::Asc("release_date".to_string()),
+    ///     RankingRule::Desc("rank".to_string()),
+    /// ];
+    /// let task = index.set_ranking_rules_typed(ranking_rules).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_ranking_rules_typed(
+        &self,
+        ranking_rules: impl IntoIterator<Item = RankingRule>,
+    ) -> Result<TaskInfo, Error> {
+        self.set_ranking_rules(ranking_rules.into_iter().map(|rule| rule.to_string()))
+            .await
+    }
+
+    /// Update [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_settings");
-    /// let settings = index.get_settings().await.unwrap();
+    /// # client.create_index("set_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_filterable_attributes");
+    ///
+    /// let filterable_attributes = ["genre", "director"];
+    /// let task = index.set_filterable_attributes(&filterable_attributes).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_settings(&self) -> Result<Settings, Error> {
-        request::<(), Settings>(
-            &format!("{}/indexes/{}/settings", self.client.host, self.uid),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
+    pub async fn set_filterable_attributes(
+        &self,
+        filterable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
+            &format!(
+                "{}/indexes/{}/settings/filterable-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
+            Method::Put(
+                filterable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
         )
         .await
     }
 
-    /// Get [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
+    /// Update [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_synonyms");
-    /// let synonyms = index.get_synonyms().await.unwrap();
+    /// # client.create_index("set_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_sortable_attributes");
+    ///
+    /// let sortable_attributes = ["genre", "director"];
+    /// let task = index.set_sortable_attributes(&sortable_attributes).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_synonyms(&self) -> Result<HashMap<String, Vec<String>>, Error> {
-        request::<(), HashMap<String, Vec<String>>>(
+    pub async fn set_sortable_attributes(
+        &self,
+        sortable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/synonyms",
+                "{}/indexes/{}/settings/sortable-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
+            &self.client,
+            Method::Put(
+                sortable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
         )
         .await
     }
 
-    /// Get [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
+    /// Update the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_pagination");
-    /// let pagination = index.get_pagination().await.unwrap();
+    /// # client.create_index("set_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_distinct_attribute");
+    ///
+    /// let task = index.set_distinct_attribute("movie_id").await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_pagination(&self) -> Result<PaginationSetting, Error> {
-        request::<(), PaginationSetting>(
-            &format!(
-                "{}/indexes/{}/settings/pagination",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_distinct_attribute(
+        &self,
+        distinct_attribute: impl AsRef<str>,
+    ) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::DistinctAttribute)
+            .set(&Some(distinct_attribute.as_ref().to_string()))
+            .await
     }
 
-    /// Get [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
+    /// Update [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_stop_words");
-    /// let stop_words = index.get_stop_words().await.unwrap();
+    /// # client.create_index("set_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_searchable_attributes");
+    ///
+    /// let task = index.set_searchable_attributes(["title", "description", "uid"]).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_stop_words(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
+    pub async fn set_searchable_attributes(
+        &self,
+        searchable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/stop-words",
+                "{}/indexes/{}/settings/searchable-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
+            &self.client,
+            Method::Put(
+                searchable_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
         )
         .await
     }
 
-    /// Get [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index].
+    /// Update [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_ranking_rules");
-    /// let ranking_rules = index.get_ranking_rules().await.unwrap();
+    /// # client.create_index("set_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_displayed_attributes");
+    ///
+    /// let task = index.set_displayed_attributes(["title", "description", "release_date", "rank", "poster"]).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_ranking_rules(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
+    pub async fn set_displayed_attributes(
+        &self,
+        displayed_attributes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<TaskInfo, Error> {
+        request::<Vec<String>, TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/ranking-rules",
+                "{}/indexes/{}/settings/displayed-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
+            &self.client,
+            Method::Put(
+                displayed_attributes
+                    .into_iter()
+                    .map(|v| v.as_ref().to_string())
+                    .collect(),
+            ),
+            202,
         )
         .await
     }
 
-    /// Get [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
+    /// Update [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
+    ///
+    /// The settings are [validated](FacetingSettings::validate) first, and not sent if they're invalid.
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{FacetSortBy, FacetingSettings}};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_filterable_attributes");
-    /// let filterable_attributes = index.get_filterable_attributes().await.unwrap();
+    /// # client.create_index("set_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_faceting");
+    ///
+    /// let faceting = FacetingSettings::new()
+    ///     .with_max_values_per_facet(12)
+    ///     .with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
+    ///
+    /// let task = index.set_faceting(&faceting).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_filterable_attributes(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
-            &format!(
-                "{}/indexes/{}/settings/filterable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_faceting(&self, faceting: &FacetingSettings) -> Result<TaskInfo, Error> {
+        faceting.validate()?;
+        self.single_value_setting(SettingsRoute::Faceting)
+            .set(faceting)
+            .await
     }
 
-    /// Get [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
+    /// Update the [typo tolerance](https://www.meilisearch.com/docs/reference/api/settings#typo-tolerance) settings of the [Index].
+    /// The update is partial, the fields [not set](Setting::NotSet) keep their current value.
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::TypoToleranceSettings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_sortable_attributes");
-    /// let sortable_attributes = index.get_sortable_attributes().await.unwrap();
+    /// # client.create_index("set_typo_tolerance", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_typo_tolerance");
+    ///
+    /// // the words and attributes typo tolerance is disabled on are kept
+    /// let typo_tolerance = TypoToleranceSettings::new().with_enabled(false);
+    ///
+    /// let task = index.set_typo_tolerance(&typo_tolerance).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_sortable_attributes(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
-            &format!(
-                "{}/indexes/{}/settings/sortable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_typo_tolerance(
+        &self,
+        typo_tolerance: &TypoToleranceSettings,
+    ) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::TypoTolerance)
+            .set(typo_tolerance)
+            .await
     }
 
-    /// Get the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
+    /// Update the [proximity precision](https://www.meilisearch.com/docs/reference/api/settings#proximity-precision) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::ProximityPrecision};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_distinct_attribute");
-    /// let distinct_attribute = index.get_distinct_attribute().await.unwrap();
+    /// # client.create_index("set_proximity_precision", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_proximity_precision");
+    ///
+    /// let task = index.set_proximity_precision(ProximityPrecision::ByAttribute).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_distinct_attribute(&self) -> Result<Option<String>, Error> {
-        request::<(), Option<String>>(
-            &format!(
-                "{}/indexes/{}/settings/distinct-attribute",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_proximity_precision(
+        &self,
+        proximity_precision: ProximityPrecision,
+    ) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::ProximityPrecision)
+            .set(&proximity_precision)
+            .await
     }
 
-    /// Get [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index].
+    /// Update the [search cutoff](https://www.meilisearch.com/docs/reference/api/settings#search-cutoff) of the [Index], in milliseconds.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use meilisearch_sdk::{client::*, indexes::*};
@@ -470,120 +3941,136 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_searchable_attributes");
-    /// let searchable_attributes = index.get_searchable_attributes().await.unwrap();
+    /// # client.create_index("set_search_cutoff_ms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_search_cutoff_ms");
+    ///
+    /// let task = index.set_search_cutoff_ms(150).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_searchable_attributes(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
-            &format!(
-                "{}/indexes/{}/settings/searchable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_search_cutoff_ms(&self, search_cutoff_ms: u64) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::SearchCutoffMs)
+            .set(&Some(search_cutoff_ms))
+            .await
     }
 
-    /// Get [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index].
+    /// Update the [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index].
+    /// The update is partial: the embedders not listed are kept, and the fields left to `None` keep their current value.
     ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Embedder};
+    /// # use std::collections::HashMap;
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_displayed_attributes");
-    /// let displayed_attributes = index.get_displayed_attributes().await.unwrap();
+    /// # client.create_index("set_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("set_embedders");
+    ///
+    /// let embedders = HashMap::from([("default".to_string(), Embedder::user_provided(512))]);
+    /// let task = index.set_embedders(&embedders).await.unwrap();
+    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn set_embedders(
+        &self,
+        embedders: &HashMap<String, Embedder>,
+    ) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::Embedders)
+            .set(embedders)
+            .await
+    }
+
+    /// Enable or disable the [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) on the [Index].
+    /// Disabling it makes the indexing faster when the values of the facets are never searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// # client.create_index("set_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_facet_search");
+    ///
+    /// let task = index.set_facet_search(false).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_displayed_attributes(&self) -> Result<Vec<String>, Error> {
-        request::<(), Vec<String>>(
-            &format!(
-                "{}/indexes/{}/settings/displayed-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_facet_search(&self, facet_search: bool) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::FacetSearch)
+            .set(&facet_search)
+            .await
     }
 
-    /// Get [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
+    /// Update the [localized attributes](https://www.meilisearch.com/docs/reference/api/settings#localized-attributes) of the [Index].
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::LocalizedAttribute};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("get_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let index = client.index("get_faceting");
-    /// let faceting = index.get_faceting().await.unwrap();
+    /// # client.create_index("set_localized_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("set_localized_attributes");
+    ///
+    /// let french = LocalizedAttribute::new(["fra"], ["title", "description"]);
+    /// let task = index.set_localized_attributes([french]).await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn get_faceting(&self) -> Result<FacetingSettings, Error> {
-        request::<(), FacetingSettings>(
-            &format!(
-                "{}/indexes/{}/settings/faceting",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Get(()),
-            200,
-        )
-        .await
+    pub async fn set_localized_attributes(
+        &self,
+        localized_attributes: impl IntoIterator<Item = LocalizedAttribute>,
+    ) -> Result<TaskInfo, Error> {
+        self.single_value_setting(SettingsRoute::LocalizedAttributes)
+            .set(&localized_attributes.into_iter().collect::<Vec<_>>())
+            .await
     }
 
-    /// Update [settings](../settings/struct.Settings.html) of the [Index].
-    /// Updates in the settings are partial. This means that any parameters corresponding to a `None` value will be left unchanged.
+    /// Reset [Settings] of the [Index].
+    /// All settings will be reset to their [default value](https://docs.meilisearch.com/reference/api/settings.html#reset-settings).
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{Settings, PaginationSetting}};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_settings");
-    ///
-    /// let stop_words = vec![String::from("a"), String::from("the"), String::from("of")];
-    /// let settings = Settings::new()
-    ///     .with_stop_words(stop_words.clone())
-    ///     .with_pagination(PaginationSetting {max_total_hits: 100}
-    /// );
+    /// # client.create_index("reset_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_settings");
     ///
-    /// let task = index.set_settings(&settings).await.unwrap();
+    /// let task = index.reset_settings().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_settings(&self, settings: &Settings) -> Result<TaskInfo, Error> {
-        request::<&Settings, TaskInfo>(
+    pub async fn reset_settings(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!("{}/indexes/{}/settings", self.client.host, self.uid),
-            &self.client.api_key,
-            Method::Patch(settings),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
+    /// Reset [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
     ///
     /// # Example
     ///
@@ -595,67 +4082,51 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_synonyms");
-    ///
-    /// let mut synonyms = std::collections::HashMap::new();
-    /// synonyms.insert(String::from("wolverine"), vec![String::from("xmen"), String::from("logan")]);
-    /// synonyms.insert(String::from("logan"), vec![String::from("xmen"), String::from("wolverine")]);
-    /// synonyms.insert(String::from("wow"), vec![String::from("world of warcraft")]);
+    /// # client.create_index("reset_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_synonyms");
     ///
-    /// let task = index.set_synonyms(&synonyms).await.unwrap();
+    /// let task = index.reset_synonyms().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_synonyms(
-        &self,
-        synonyms: &HashMap<String, Vec<String>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<&HashMap<String, Vec<String>>, TaskInfo>(
+    pub async fn reset_synonyms(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
                 "{}/indexes/{}/settings/synonyms",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(synonyms),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
+    /// Reset [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::{Settings, PaginationSetting}};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_pagination");
-    /// let pagination = PaginationSetting {max_total_hits:100};
-    /// let task = index.set_pagination(pagination).await.unwrap();
+    /// # client.create_index("reset_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_pagination");
+    ///
+    /// let task = index.reset_pagination().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_pagination(&self, pagination: PaginationSetting) -> Result<TaskInfo, Error> {
-        request::<&PaginationSetting, TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/pagination",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Patch(&pagination),
-            202,
-        )
-        .await
+    pub async fn reset_pagination(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<PaginationSetting>(SettingsRoute::Pagination)
+            .reset()
+            .await
     }
-
-    /// Update [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
+    /// Reset [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
     ///
     /// # Example
     ///
@@ -667,36 +4138,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_stop_words");
+    /// # client.create_index("reset_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_stop_words");
     ///
-    /// let stop_words = ["the", "of", "to"];
-    /// let task = index.set_stop_words(&stop_words).await.unwrap();
+    /// let task = index.reset_stop_words().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_stop_words(
-        &self,
-        stop_words: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_stop_words(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
                 "{}/indexes/{}/settings/stop-words",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                stop_words
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index].
+    /// Reset the [dictionary](https://www.meilisearch.com/docs/reference/api/settings#dictionary) of the [Index].
     ///
     /// # Example
     ///
@@ -708,45 +4170,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_ranking_rules");
+    /// # client.create_index("reset_dictionary", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_dictionary");
     ///
-    /// let ranking_rules = [
-    ///     "words",
-    ///     "typo",
-    ///     "proximity",
-    ///     "attribute",
-    ///     "sort",
-    ///     "exactness",
-    ///     "release_date:asc",
-    ///     "rank:desc",
-    /// ];
-    /// let task = index.set_ranking_rules(ranking_rules).await.unwrap();
+    /// let task = index.reset_dictionary().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_ranking_rules(
-        &self,
-        ranking_rules: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_dictionary(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/ranking-rules",
+                "{}/indexes/{}/settings/dictionary",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                ranking_rules
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
+    /// Reset the [separator tokens](https://www.meilisearch.com/docs/reference/api/settings#separator-tokens) of the [Index].
     ///
     /// # Example
     ///
@@ -758,36 +4202,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_filterable_attributes");
+    /// # client.create_index("reset_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_separator_tokens");
     ///
-    /// let filterable_attributes = ["genre", "director"];
-    /// let task = index.set_filterable_attributes(&filterable_attributes).await.unwrap();
+    /// let task = index.reset_separator_tokens().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_filterable_attributes(
-        &self,
-        filterable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_separator_tokens(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/filterable-attributes",
+                "{}/indexes/{}/settings/separator-tokens",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                filterable_attributes
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
+    /// Reset the [non-separator tokens](https://www.meilisearch.com/docs/reference/api/settings#non-separator-tokens) of the [Index].
     ///
     /// # Example
     ///
@@ -799,36 +4234,28 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_sortable_attributes");
+    /// # client.create_index("reset_non_separator_tokens", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_non_separator_tokens");
     ///
-    /// let sortable_attributes = ["genre", "director"];
-    /// let task = index.set_sortable_attributes(&sortable_attributes).await.unwrap();
+    /// let task = index.reset_non_separator_tokens().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_sortable_attributes(
-        &self,
-        sortable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_non_separator_tokens(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/sortable-attributes",
+                "{}/indexes/{}/settings/non-separator-tokens",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                sortable_attributes
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
+    /// Reset [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index] to default value.
+    /// Default value: `["words", "typo", "proximity", "attribute", "sort", "exactness"]`.
     ///
     /// # Example
     ///
@@ -840,30 +4267,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_distinct_attribute");
+    /// # client.create_index("reset_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_ranking_rules");
     ///
-    /// let task = index.set_distinct_attribute("movie_id").await.unwrap();
+    /// let task = index.reset_ranking_rules().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_distinct_attribute(
-        &self,
-        distinct_attribute: impl AsRef<str>,
-    ) -> Result<TaskInfo, Error> {
-        request::<String, TaskInfo>(
+    pub async fn reset_ranking_rules(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/distinct-attribute",
+                "{}/indexes/{}/settings/ranking-rules",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(distinct_attribute.as_ref().to_string()),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index].
+    /// Reset [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
     ///
     /// # Example
     ///
@@ -875,35 +4299,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_searchable_attributes");
+    /// # client.create_index("reset_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_filterable_attributes");
     ///
-    /// let task = index.set_searchable_attributes(["title", "description", "uid"]).await.unwrap();
+    /// let task = index.reset_filterable_attributes().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_searchable_attributes(
-        &self,
-        searchable_attributes: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_filterable_attributes(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/searchable-attributes",
+                "{}/indexes/{}/settings/filterable-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                searchable_attributes
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index].
+    /// Reset [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
     ///
     /// # Example
     ///
@@ -915,72 +4331,52 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_displayed_attributes");
+    /// # client.create_index("reset_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_sortable_attributes");
     ///
-    /// let task = index.set_displayed_attributes(["title", "description", "release_date", "rank", "poster"]).await.unwrap();
+    /// let task = index.reset_sortable_attributes().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_displayed_attributes(
-        &self,
-        displayed_attributes: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<TaskInfo, Error> {
-        request::<Vec<String>, TaskInfo>(
+    pub async fn reset_sortable_attributes(&self) -> Result<TaskInfo, Error> {
+        request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/displayed-attributes",
+                "{}/indexes/{}/settings/sortable-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
-            Method::Put(
-                displayed_attributes
-                    .into_iter()
-                    .map(|v| v.as_ref().to_string())
-                    .collect(),
-            ),
+            &self.client,
+            Method::Delete,
             202,
         )
         .await
     }
 
-    /// Update [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
+    /// Reset the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings, settings::FacetingSettings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("set_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("set_faceting");
-    ///
-    /// let mut faceting = FacetingSettings {
-    ///     max_values_per_facet: 12,
-    /// };
+    /// # client.create_index("reset_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_distinct_attribute");
     ///
-    /// let task = index.set_faceting(&faceting).await.unwrap();
+    /// let task = index.reset_distinct_attribute().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn set_faceting(&self, faceting: &FacetingSettings) -> Result<TaskInfo, Error> {
-        request::<&FacetingSettings, TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/faceting",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Patch(faceting),
-            202,
-        )
-        .await
+    pub async fn reset_distinct_attribute(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<Option<String>>(SettingsRoute::DistinctAttribute)
+            .reset()
+            .await
     }
 
-    /// Reset [Settings] of the [Index].
-    /// All settings will be reset to their [default value](https://docs.meilisearch.com/reference/api/settings.html#reset-settings).
+    /// Reset [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index] (enable all attributes).
     ///
     /// # Example
     ///
@@ -992,24 +4388,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_settings", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_settings");
+    /// # client.create_index("reset_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_searchable_attributes");
     ///
-    /// let task = index.reset_settings().await.unwrap();
+    /// let task = index.reset_searchable_attributes().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_settings(&self) -> Result<TaskInfo, Error> {
+    pub async fn reset_searchable_attributes(&self) -> Result<TaskInfo, Error> {
         request::<(), TaskInfo>(
-            &format!("{}/indexes/{}/settings", self.client.host, self.uid),
-            &self.client.api_key,
+            &format!(
+                "{}/indexes/{}/settings/searchable-attributes",
+                self.client.host, self.uid
+            ),
+            &self.client,
             Method::Delete,
             202,
         )
         .await
     }
 
-    /// Reset [synonyms](https://docs.meilisearch.com/reference/features/synonyms.html) of the [Index].
+    /// Reset [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index] (enable all attributes).
     ///
     /// # Example
     ///
@@ -1021,27 +4420,27 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_synonyms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_synonyms");
+    /// # client.create_index("reset_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_displayed_attributes");
     ///
-    /// let task = index.reset_synonyms().await.unwrap();
+    /// let task = index.reset_displayed_attributes().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_synonyms(&self) -> Result<TaskInfo, Error> {
+    pub async fn reset_displayed_attributes(&self) -> Result<TaskInfo, Error> {
         request::<(), TaskInfo>(
             &format!(
-                "{}/indexes/{}/settings/synonyms",
+                "{}/indexes/{}/settings/displayed-attributes",
                 self.client.host, self.uid
             ),
-            &self.client.api_key,
+            &self.client,
             Method::Delete,
             202,
         )
         .await
     }
 
-    /// Reset [pagination](https://docs.meilisearch.com/learn/configuration/settings.html#pagination) of the [Index].
+    /// Reset [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
     ///
     /// # Example
     ///
@@ -1053,295 +4452,1288 @@ impl Index {
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_pagination", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_pagination");
+    /// # client.create_index("reset_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_faceting");
     ///
-    /// let task = index.reset_pagination().await.unwrap();
+    /// let task = index.reset_faceting().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_pagination(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/pagination",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_faceting(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<FacetingSettings>(SettingsRoute::Faceting)
+            .reset()
+            .await
     }
-    /// Reset [stop-words](https://docs.meilisearch.com/reference/features/stop_words.html) of the [Index].
+
+    /// Reset the [typo tolerance](https://www.meilisearch.com/docs/reference/api/settings#typo-tolerance) settings of the [Index].
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_stop_words", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_stop_words");
+    /// # client.create_index("reset_typo_tolerance", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_typo_tolerance");
     ///
-    /// let task = index.reset_stop_words().await.unwrap();
+    /// let task = index.reset_typo_tolerance().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_stop_words(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/stop-words",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_typo_tolerance(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<TypoToleranceSettings>(SettingsRoute::TypoTolerance)
+            .reset()
+            .await
     }
 
-    /// Reset [ranking rules](https://docs.meilisearch.com/learn/core_concepts/relevancy.html#ranking-rules) of the [Index] to default value.
-    /// Default value: `["words", "typo", "proximity", "attribute", "sort", "exactness"]`.
+    /// Reset the [proximity precision](https://www.meilisearch.com/docs/reference/api/settings#proximity-precision) of the [Index] to `ByWord`.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_ranking_rules", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_ranking_rules");
+    /// # client.create_index("reset_proximity_precision", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_proximity_precision");
     ///
-    /// let task = index.reset_ranking_rules().await.unwrap();
+    /// let task = index.reset_proximity_precision().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_ranking_rules(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/ranking-rules",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_proximity_precision(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<ProximityPrecision>(SettingsRoute::ProximityPrecision)
+            .reset()
+            .await
     }
 
-    /// Reset [filterable attributes](https://docs.meilisearch.com/reference/features/filtering_and_faceted_search.html) of the [Index].
+    /// Reset the [search cutoff](https://www.meilisearch.com/docs/reference/api/settings#search-cutoff) of the [Index] to the default of Meilisearch.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_filterable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_filterable_attributes");
+    /// # client.create_index("reset_search_cutoff_ms", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_search_cutoff_ms");
     ///
-    /// let task = index.reset_filterable_attributes().await.unwrap();
+    /// let task = index.reset_search_cutoff_ms().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_filterable_attributes(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/filterable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_search_cutoff_ms(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<Option<u64>>(SettingsRoute::SearchCutoffMs)
+            .reset()
+            .await
     }
 
-    /// Reset [sortable attributes](https://docs.meilisearch.com/reference/features/sorting.html) of the [Index].
+    /// Remove all the [embedders](https://www.meilisearch.com/docs/reference/api/settings#embedders) of the [Index].
     ///
     /// # Example
     ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_sortable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_sortable_attributes");
+    /// # client.create_index("reset_embedders", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let mut index = client.index("reset_embedders");
     ///
-    /// let task = index.reset_sortable_attributes().await.unwrap();
+    /// let task = index.reset_embedders().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_sortable_attributes(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/sortable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_embedders(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<HashMap<String, Embedder>>(SettingsRoute::Embedders)
+            .reset()
+            .await
     }
 
-    /// Reset the [distinct attribute](https://docs.meilisearch.com/reference/features/settings.html#distinct-attribute) of the [Index].
+    /// Reset the [facet search](https://www.meilisearch.com/docs/reference/api/settings#facet-search) of the [Index], enabling it.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_distinct_attribute", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_distinct_attribute");
+    /// # client.create_index("reset_facet_search", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_facet_search");
     ///
-    /// let task = index.reset_distinct_attribute().await.unwrap();
+    /// let task = index.reset_facet_search().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_distinct_attribute(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/distinct-attribute",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    pub async fn reset_facet_search(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<bool>(SettingsRoute::FacetSearch)
+            .reset()
+            .await
     }
 
-    /// Reset [searchable attributes](https://docs.meilisearch.com/reference/features/field_properties.html#searchable-fields) of the [Index] (enable all attributes).
+    /// Reset the [localized attributes](https://www.meilisearch.com/docs/reference/api/settings#localized-attributes) of the [Index],
+    /// so the languages are detected by Meilisearch.
     ///
     /// # Example
     ///
     /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
+    /// # use meilisearch_sdk::{client::*, indexes::*, settings::LocalizedAttribute};
     /// #
     /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
     /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
     /// #
     /// # futures::executor::block_on(async move {
     /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_searchable_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_searchable_attributes");
+    /// # client.create_index("reset_localized_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
+    /// let index = client.index("reset_localized_attributes");
     ///
-    /// let task = index.reset_searchable_attributes().await.unwrap();
+    /// let task = index.reset_localized_attributes().await.unwrap();
     /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
     /// # });
     /// ```
-    pub async fn reset_searchable_attributes(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/searchable-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
+    pub async fn reset_localized_attributes(&self) -> Result<TaskInfo, Error> {
+        self.single_value_setting::<Option<Vec<LocalizedAttribute>>>(
+            SettingsRoute::LocalizedAttributes,
         )
+        .reset()
         .await
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::client::*;
+    use meilisearch_test_macro::meilisearch_test;
+    use mockito::mock;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_flat_map() {
+        let settings = Settings::new()
+            .with_synonyms(HashMap::from([("wolverine", ["logan", "xmen"])]))
+            .with_ranking_rules(["words", "typo"])
+            .with_distinct_attribute("movie_id")
+            .with_pagination(PaginationSetting { max_total_hits: 42 })
+            .with_faceting(&FacetingSettings::new().with_max_values_per_facet(5));
+
+        let flat = settings.to_flat_map();
+
+        assert_eq!(
+            flat.keys().collect::<Vec<_>>(),
+            vec![
+                "distinctAttribute",
+                "faceting.maxValuesPerFacet",
+                "pagination.maxTotalHits",
+                "rankingRules",
+                "synonyms.wolverine",
+            ]
+        );
+        assert_eq!(flat["faceting.maxValuesPerFacet"], json!(5));
+        assert_eq!(flat["pagination.maxTotalHits"], json!(42));
+        assert_eq!(flat["rankingRules"], json!(["words", "typo"]));
+        assert_eq!(flat["synonyms.wolverine"], json!(["logan", "xmen"]));
+        assert_eq!(flat["distinctAttribute"], json!("movie_id"));
+
+        assert!(Settings::new().to_flat_map().is_empty());
+    }
+
+    #[test]
+    fn test_mixed_setting_states() {
+        let settings = Settings {
+            stop_words: Setting::Set(vec!["the".to_string()]),
+            distinct_attribute: Setting::Reset,
+            ranking_rules: Setting::NotSet,
+            ..Settings::new()
+        };
+
+        assert_eq!(
+            settings.to_request_body(),
+            json!({ "stopWords": ["the"], "distinctAttribute": null })
+        );
+        assert_eq!(
+            settings.configured_fields(),
+            ["stopWords", "distinctAttribute"]
+        );
+
+        let settings: Settings =
+            serde_json::from_value(json!({ "stopWords": ["the"], "distinctAttribute": null }))
+                .unwrap();
+        assert_eq!(settings.stop_words, Setting::Set(vec!["the".to_string()]));
+        assert_eq!(settings.distinct_attribute, Setting::Reset);
+        assert_eq!(settings.ranking_rules, Setting::NotSet);
+    }
+
+    #[test]
+    fn test_reset_builders() {
+        let settings = Settings::new()
+            .with_stop_words(["the"])
+            .reset_synonyms()
+            .reset_typo_tolerance()
+            .reset_localized_attributes();
+
+        assert_eq!(
+            settings.to_request_body(),
+            json!({ "synonyms": null, "stopWords": ["the"], "typoTolerance": null, "localizedAttributes": null })
+        );
+
+        // a reset overrides a previous value, and the other way around
+        let settings = Settings::new()
+            .with_search_cutoff_ms(150)
+            .reset_search_cutoff_ms()
+            .reset_facet_search()
+            .with_facet_search(false);
+        assert_eq!(settings.search_cutoff_ms, Setting::Reset);
+        assert_eq!(settings.facet_search, Setting::Set(false));
+    }
+
+    #[test]
+    fn test_set_builders_match_with_builders() {
+        let faceting = FacetingSettings::new().with_max_values_per_facet(10);
+        let typo_tolerance = TypoToleranceSettings::new().with_enabled(false);
+        let french = LocalizedAttribute::new(["fra"], ["title"]);
+        let consumed = Settings::new()
+            .with_synonyms(HashMap::from([("film", ["movie"])]))
+            .with_stop_words(["the"])
+            .with_dictionary(["J. R. R."])
+            .with_separator_tokens(["|"])
+            .with_non_separator_tokens(["@"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 100,
+            })
+            .with_ranking_rules(["words", "typo"])
+            .with_filterable_attributes(["genre"])
+            .with_sortable_attributes(["year"])
+            .with_distinct_attribute("id")
+            .with_searchable_attributes(["title"])
+            .with_displayed_attributes(["title", "year"])
+            .with_faceting(&faceting)
+            .with_typo_tolerance(&typo_tolerance)
+            .with_proximity_precision(ProximityPrecision::ByAttribute)
+            .with_search_cutoff_ms(150)
+            .with_embedders(HashMap::from([(
+                "default",
+                Embedder::new(EmbedderSource::UserProvided).with_dimensions(3),
+            )]))
+            .with_facet_search(false)
+            .with_localized_attributes([french.clone()]);
+
+        let mut mutated = Settings::new();
+        mutated
+            .set_synonyms(HashMap::from([("film", ["movie"])]))
+            .set_stop_words(["the"])
+            .set_dictionary(["J. R. R."])
+            .set_separator_tokens(["|"])
+            .set_non_separator_tokens(["@"])
+            .set_pagination(PaginationSetting {
+                max_total_hits: 100,
+            })
+            .set_ranking_rules(["words", "typo"])
+            .set_filterable_attributes(["genre"])
+            .set_sortable_attributes(["year"])
+            .set_distinct_attribute("id")
+            .set_searchable_attributes(["title"])
+            .set_displayed_attributes(["title", "year"])
+            .set_faceting(&faceting)
+            .set_typo_tolerance(&typo_tolerance)
+            .set_proximity_precision(ProximityPrecision::ByAttribute)
+            .set_search_cutoff_ms(150)
+            .set_embedders(HashMap::from([(
+                "default",
+                Embedder::new(EmbedderSource::UserProvided).with_dimensions(3),
+            )]))
+            .set_facet_search(false)
+            .set_localized_attributes([french]);
+
+        assert_eq!(mutated.to_request_body(), consumed.to_request_body());
+        assert_eq!(mutated.configured_fields().len(), 19);
+
+        let mut typed = Settings::new();
+        typed.set_ranking_rules_typed([RankingRule::Words, RankingRule::Typo]);
+        assert_eq!(
+            typed.to_request_body(),
+            Settings::new()
+                .with_ranking_rules(["words", "typo"])
+                .to_request_body()
+        );
+    }
+
+    #[test]
+    fn test_configured_fields() {
+        assert!(Settings::new().configured_fields().is_empty());
+
+        let settings = Settings::new()
+            .with_faceting(&FacetingSettings::new().with_max_values_per_facet(10))
+            .with_filterable_attributes(["genre"])
+            .with_synonyms(HashMap::from([("film", ["movie"])]))
+            .with_distinct_attribute("id");
+        assert_eq!(
+            settings.configured_fields(),
+            [
+                "synonyms",
+                "filterableAttributes",
+                "distinctAttribute",
+                "faceting"
+            ]
+        );
+
+        // every field is listed under the name it is serialized with
+        let settings = Settings::new()
+            .with_synonyms(HashMap::<&str, Vec<&str>>::new())
+            .with_stop_words(["the"])
+            .with_ranking_rules(["words"])
+            .with_filterable_attributes(["genre"])
+            .with_sortable_attributes(["year"])
+            .with_distinct_attribute("id")
+            .with_searchable_attributes(["title"])
+            .with_displayed_attributes(["title"])
+            .with_pagination(PaginationSetting { max_total_hits: 10 })
+            .with_faceting(&FacetingSettings::new().with_max_values_per_facet(10))
+            .with_embedders(HashMap::from([("default", Embedder::user_provided(3))]));
+        let mut serialized: Vec<String> = settings
+            .to_request_body()
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        let mut configured = settings.configured_fields();
+        serialized.sort();
+        configured.sort_unstable();
+        assert_eq!(serialized, configured);
+    }
+
+    #[test]
+    fn test_validate_proximity_config() {
+        assert_eq!(Settings::new().validate_proximity_config(), Ok(()));
+
+        let settings = Settings::new().with_proximity_precision(ProximityPrecision::ByAttribute);
+        // the ranking rules of the index are unknown
+        assert_eq!(settings.validate_proximity_config(), Ok(()));
+
+        let settings = settings.with_ranking_rules(["words", "typo", "proximity", "attribute"]);
+        assert_eq!(settings.validate_proximity_config(), Ok(()));
+
+        let settings = settings.with_ranking_rules(["words", "typo", "attribute"]);
+        assert_eq!(
+            settings.validate_proximity_config(),
+            Err("The proximity precision is set to `byAttribute` but the `proximity` ranking rule isn't part of the ranking rules [\"words\", \"typo\", \"attribute\"], so it has no effect.".to_string())
+        );
+
+        // without a proximity precision, the ranking rules are not checked
+        let settings = Settings::new().with_ranking_rules(["words"]);
+        assert_eq!(settings.validate_proximity_config(), Ok(()));
+    }
+
+    #[test]
+    fn test_ranking_rules() {
+        assert_eq!(
+            serde_json::to_value(RankingRule::Desc("rank".into())).unwrap(),
+            json!("rank:desc")
+        );
+
+        let rules = [
+            RankingRule::Words,
+            RankingRule::Typo,
+            RankingRule::Proximity,
+            RankingRule::Attribute,
+            RankingRule::Sort,
+            RankingRule::Exactness,
+            RankingRule::Asc("release_date".into()),
+            RankingRule::Desc("rank".into()),
+        ];
+        let strings = json!([
+            "words",
+            "typo",
+            "proximity",
+            "attribute",
+            "sort",
+            "exactness",
+            "release_date:asc",
+            "rank:desc"
+        ]);
+        assert_eq!(serde_json::to_value(&rules).unwrap(), strings);
+        assert_eq!(
+            serde_json::from_value::<Vec<RankingRule>>(strings).unwrap(),
+            rules
+        );
+        assert_eq!(
+            Settings::new()
+                .with_ranking_rules_typed(rules)
+                .to_request_body()["rankingRules"][6],
+            json!("release_date:asc")
+        );
+
+        for invalid in ["proximty", ":asc", "rank:up", ""] {
+            assert!(matches!(
+                invalid.parse::<RankingRule>(),
+                Err(Error::InvalidRankingRule(rule)) if rule == invalid
+            ));
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let current: Settings = serde_json::from_value(json!({
+            "stopWords": ["a", "the"],
+            "filterableAttributes": ["genres", "year"],
+            "rankingRules": ["words", "typo", "proximity"],
+            "synonyms": { "sf": ["science fiction", "sci-fi"] },
+            "pagination": { "maxTotalHits": 1000 },
+            "faceting": { "maxValuesPerFacet": 100, "sortFacetValuesBy": { "*": "alpha" } },
+            "distinctAttribute": null
+        }))
+        .unwrap();
+
+        // the sets are compared regardless of their order, and the objects partially
+        let in_sync = Settings::new()
+            .with_stop_words(["the", "a", "the"])
+            .with_filterable_attributes(["year", "genres"])
+            .with_synonyms(HashMap::from([("sf", vec!["sci-fi", "science fiction"])]))
+            .with_faceting(&FacetingSettings::new().with_max_values_per_facet(100));
+        assert!(in_sync.diff(&current).configured_fields().is_empty());
+
+        let desired = Settings {
+            distinct_attribute: Setting::Reset,
+            ..in_sync
+                .with_ranking_rules(["typo", "words", "proximity"])
+                .with_pagination(PaginationSetting {
+                    max_total_hits: 1000,
+                })
+                .with_search_cutoff_ms(150)
+        };
+        assert_eq!(
+            desired.diff(&current).to_request_body(),
+            json!({
+                "rankingRules": ["typo", "words", "proximity"],
+                "searchCutoffMs": 150
+            })
+        );
+
+        // a reset setting is only kept when it has a value
+        let reset = Settings {
+            distinct_attribute: Setting::Reset,
+            stop_words: Setting::Reset,
+            ..Settings::new()
+        };
+        assert_eq!(
+            reset.diff(&current).to_request_body(),
+            json!({ "stopWords": null })
+        );
+    }
+
+    #[test]
+    fn test_diff_of_identical_settings_is_empty() {
+        let settings = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_filterable_attributes(["genres", "year"])
+            .with_ranking_rules(["words", "typo"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 100,
+            })
+            .with_distinct_attribute("id");
+        let reordered = settings
+            .clone()
+            .with_stop_words(["the", "a"])
+            .with_filterable_attributes(["year", "genres"]);
+
+        assert_eq!(settings.diff(&settings).to_request_body(), json!({}));
+        assert_eq!(reordered.diff(&settings).to_request_body(), json!({}));
+        assert_eq!(Settings::new().diff(&settings).to_request_body(), json!({}));
+
+        // the settings returned by Meilisearch contain null values
+        let fetched: Settings = serde_json::from_value(json!({
+            "displayedAttributes": ["*"],
+            "searchableAttributes": ["*"],
+            "filterableAttributes": ["genre"],
+            "sortableAttributes": [],
+            "rankingRules": ["words", "typo"],
+            "stopWords": ["the"],
+            "synonyms": {},
+            "distinctAttribute": null,
+            "pagination": { "maxTotalHits": 1000 },
+            "faceting": { "maxValuesPerFacet": 100 },
+            "searchCutoffMs": null
+        }))
+        .unwrap();
+        assert_eq!(fetched.diff(&fetched).to_request_body(), json!({}));
+    }
+
+    #[meilisearch_test]
+    async fn test_sync_settings_mocked() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("sync_settings");
+        let current = |body: Value| {
+            mock("GET", "/indexes/sync_settings/settings")
+                .with_status(200)
+                .with_body(body.to_string())
+                .expect(1)
+                .create()
+        };
+        let update = mock("PATCH", "/indexes/sync_settings/settings")
+            .match_body(mockito::Matcher::Json(
+                json!({ "sortableAttributes": ["year"] }),
+            ))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "sync_settings", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#)
+            .expect(1)
+            .create();
+        let settings = Settings::new()
+            .with_filterable_attributes(["kind"])
+            .with_sortable_attributes(["year"]);
+
+        let before = current(json!({ "filterableAttributes": ["kind"], "sortableAttributes": [] }));
+        let task = index.sync_settings(&settings).await.unwrap();
+        assert_eq!(task.map(|task| task.task_uid), Some(1));
+        before.assert();
+
+        let after =
+            current(json!({ "filterableAttributes": ["kind"], "sortableAttributes": ["year"] }));
+        assert!(index.sync_settings(&settings).await.unwrap().is_none());
+        after.assert();
+        update.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_sync_settings(client: Client, index: Index) {
+        let settings = Settings::new()
+            .with_filterable_attributes(["year", "genres"])
+            .with_stop_words(["the", "a"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 500,
+            });
+
+        let task = index.sync_settings(&settings).await.unwrap().unwrap();
+        client.wait_for_task(task, None, None).await.unwrap();
+
+        assert!(index.sync_settings(&settings).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_settings_diff() {
+        let staging = Settings::new()
+            .with_filterable_attributes(["genres"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 1000,
+            })
+            .with_stop_words(["the"]);
+        let production = Settings::new()
+            .with_filterable_attributes(["genres", "year"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 1000,
+            })
+            .with_distinct_attribute("id")
+            .with_stop_words(["the"]);
+
+        let diff = SettingsDiff::new("staging", &staging, "production", &production);
+        assert_eq!(
+            diff.differences,
+            vec![
+                SettingDifference {
+                    path: "distinctAttribute".to_string(),
+                    a: None,
+                    b: Some(json!("id")),
+                },
+                SettingDifference {
+                    path: "filterableAttributes".to_string(),
+                    a: Some(json!(["genres"])),
+                    b: Some(json!(["genres", "year"])),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "2 setting(s) differ between staging and production:\n  distinctAttribute: (unset) / \"id\"\n  filterableAttributes: [\"genres\"] / [\"genres\",\"year\"]\n"
+        );
+        assert!(SettingsDiff::new("a", &staging, "b", &staging).is_empty());
+    }
+
+    #[test]
+    fn test_validate_hidden_attributes() {
+        let settings = Settings::new()
+            .with_displayed_attributes(["title", "year"])
+            .with_distinct_attribute("movie_id")
+            .with_sortable_attributes(["year", "rating"]);
+
+        assert_eq!(
+            settings.validate(),
+            Err(vec![
+                "The distinct attribute `movie_id` isn't part of the displayed attributes."
+                    .to_string(),
+                "The sortable attribute `rating` isn't part of the displayed attributes."
+                    .to_string(),
+            ])
+        );
+
+        // everything is displayed
+        let settings = settings.with_displayed_attributes(["*"]);
+        assert_eq!(settings.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_sortable_attributes_without_sort_rule() {
+        let settings = Settings::new()
+            .with_sortable_attributes(["year"])
+            .with_ranking_rules(["words", "typo", "proximity"]);
+
+        assert_eq!(
+            settings.validate(),
+            Err(vec![
+                "The sortable attributes [\"year\"] have no effect without the `sort` ranking rule."
+                    .to_string()
+            ])
+        );
+        // the ranking rules of the index are unknown
+        assert_eq!(
+            Settings::new()
+                .with_sortable_attributes(["year"])
+                .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_custom_ranking_rules() {
+        let settings = Settings::new()
+            .with_sortable_attributes(["year"])
+            .with_ranking_rules(["words", "sort", "year:asc", "rating:desc"]);
+
+        assert_eq!(
+            settings.validate(),
+            Err(vec![
+                "The ranking rule `rating:desc` uses `rating`, which isn't a sortable attribute."
+                    .to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_returns_all_the_problems() {
+        let settings = Settings::new()
+            .with_displayed_attributes(["title"])
+            .with_sortable_attributes(["year"])
+            .with_ranking_rules(["words", "rating:desc"])
+            .with_proximity_precision(ProximityPrecision::ByAttribute);
+
+        assert_eq!(settings.validate().unwrap_err().len(), 4);
+        assert_eq!(
+            settings.validate_for_primary_key("id").unwrap_err().last(),
+            Some(&"The primary key `id` isn't part of the displayed attributes.".to_string())
+        );
+        assert_eq!(Settings::new().validate_for_primary_key("id"), Ok(()));
+    }
+
+    #[test]
+    fn test_set_settings_dry_run() {
+        let index = Client::new("http://localhost:7700", "masterKey").index("movies");
+        let settings = Settings::new()
+            .with_stop_words(["the"])
+            .with_filterable_attributes(["genre", "year"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 500,
+            });
+
+        let plan = index.set_settings_dry_run(&settings);
+        assert_eq!(
+            serde_json::to_value(&plan).unwrap(),
+            json!({
+                "indexUid": "movies",
+                "method": "PATCH",
+                "route": "/indexes/movies/settings",
+                "body": {
+                    "stopWords": ["the"],
+                    "filterableAttributes": ["genre", "year"],
+                    "pagination": { "maxTotalHits": 500 },
+                },
+                "taskType": "settingsUpdate",
+            })
+        );
+
+        let current = Settings::new()
+            .with_stop_words(["the"])
+            .with_filterable_attributes(["genre"])
+            .with_pagination(PaginationSetting {
+                max_total_hits: 1000,
+            });
+        let plan = plan.with_current(&current);
+        assert_eq!(
+            plan.to_string(),
+            r#"PATCH /indexes/movies/settings (settingsUpdate)
+{
+  "filterableAttributes": [
+    "genre",
+    "year"
+  ],
+  "pagination": {
+    "maxTotalHits": 500
+  },
+  "stopWords": [
+    "the"
+  ]
+}
+2 setting(s) change:
+  filterableAttributes: ["genre"] -> ["genre","year"]
+  pagination.maxTotalHits: 1000 -> 500
+"#
+        );
+
+        let unchanged = index.set_settings_dry_run(&current).with_current(&current);
+        assert_eq!(unchanged.changes, Some(vec![]));
+        assert!(unchanged.to_string().ends_with("No setting changes.\n"));
+    }
+
+    #[meilisearch_test]
+    async fn test_set_settings_with_rollback(client: Client, index: Index) {
+        let original = index.get_settings().await.unwrap();
+        let experiment = Settings::new()
+            .with_ranking_rules(["sort", "words"])
+            .with_stop_words(["the"])
+            .with_pagination(PaginationSetting { max_total_hits: 7 });
+
+        let (task, previous) = index.set_settings_with_rollback(&experiment).await.unwrap();
+        client.wait_for_task(task, None, None).await.unwrap();
+        assert_eq!(previous.to_request_body(), original.to_request_body());
+        let changed = index.get_settings().await.unwrap();
+        assert_eq!(changed.stop_words, Setting::Set(vec!["the".to_string()]));
+
+        let task = index.rollback_settings(&previous).await.unwrap();
+        client.wait_for_task(task, None, None).await.unwrap();
+
+        let restored = index.get_settings().await.unwrap();
+        assert_eq!(restored.to_request_body(), original.to_request_body());
+    }
+
+    #[test]
+    fn test_from_index_snapshot() {
+        // exported by Meilisearch v0.20
+        let (settings, report) = Settings::from_index_snapshot(json!({
+            "rankingRules": ["typo", "words", "proximity", "attribute", "wordsPosition", "exactness", "desc(release_date)", "asc(price)"],
+            "distinctAttribute": null,
+            "searchableAttributes": ["*"],
+            "displayedAttributes": ["*"],
+            "stopWords": [],
+            "synonyms": { "wolverine": ["xmen", "logan"] },
+            "attributesForFaceting": ["genres"],
+            "acceptNewFields": true,
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.ranking_rules,
+            Setting::Set(
+                [
+                    "typo",
+                    "words",
+                    "proximity",
+                    "attribute",
+                    "exactness",
+                    "release_date:desc",
+                    "price:asc"
+                ]
+                .iter()
+                .map(|rule| rule.to_string())
+                .collect()
+            )
+        );
+        assert_eq!(settings.distinct_attribute, Setting::Reset);
+        assert_eq!(
+            settings.filterable_attributes,
+            Setting::Set(vec!["genres".to_string()])
+        );
+        assert_eq!(
+            settings.synonyms.as_option().unwrap()["wolverine"],
+            ["xmen", "logan"]
+        );
+        assert_eq!(
+            report,
+            SnapshotReport {
+                dropped_keys: vec!["acceptNewFields".to_string()],
+                invalid_keys: vec![],
+                renamed_keys: vec![(
+                    "attributesForFaceting".to_string(),
+                    "filterableAttributes".to_string()
+                )],
+                dropped_ranking_rules: vec!["wordsPosition".to_string()],
+            }
+        );
+        assert!(!report.is_lossless());
+
+        // written in snake_case by another SDK
+        let (settings, report) = Settings::from_index_snapshot(json!({
+            "stop_words": ["the", "a"],
+            "sortable_attributes": ["price"],
+            "filterable_attributes": ["genres"],
+            "attributes_for_faceting": ["director"],
+            "pagination": { "max_total_hits": 500 },
+            "faceting": { "max_values_per_facet": 10, "sort_facet_values_by": { "*": "count" } },
+            "search_cutoff_ms": 150,
+            "typo_tolerance": { "disable_on_words": ["kafka"], "min_word_size_for_typos": { "one_typo": 4 } },
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.to_request_body(),
+            json!({
+                "stopWords": ["the", "a"],
+                "sortableAttributes": ["price"],
+                "filterableAttributes": ["genres"],
+                "pagination": { "maxTotalHits": 500 },
+                "faceting": { "maxValuesPerFacet": 10, "sortFacetValuesBy": { "*": "count" } },
+                "searchCutoffMs": 150,
+                "typoTolerance": { "disableOnWords": ["kafka"], "minWordSizeForTypos": { "oneTypo": 4 } },
+            })
+        );
+        // the legacy key doesn't override the one with the current name
+        assert_eq!(report.dropped_keys, ["attributes_for_faceting"]);
+        assert_eq!(
+            report.renamed_keys,
+            [
+                (
+                    "faceting.max_values_per_facet",
+                    "faceting.maxValuesPerFacet"
+                ),
+                (
+                    "faceting.sort_facet_values_by",
+                    "faceting.sortFacetValuesBy"
+                ),
+                ("filterable_attributes", "filterableAttributes"),
+                ("pagination.max_total_hits", "pagination.maxTotalHits"),
+                ("search_cutoff_ms", "searchCutoffMs"),
+                ("sortable_attributes", "sortableAttributes"),
+                ("stop_words", "stopWords"),
+                (
+                    "typoTolerance.disable_on_words",
+                    "typoTolerance.disableOnWords"
+                ),
+                (
+                    "typoTolerance.minWordSizeForTypos.one_typo",
+                    "typoTolerance.minWordSizeForTypos.oneTypo"
+                ),
+                (
+                    "typoTolerance.min_word_size_for_typos",
+                    "typoTolerance.minWordSizeForTypos"
+                ),
+                ("typo_tolerance", "typoTolerance"),
+            ]
+            .iter()
+            .map(|(key, name)| (key.to_string(), name.to_string()))
+            .collect::<Vec<_>>()
+        );
+
+        // exported by a Meilisearch more recent than the SDK
+        let (settings, report) = Settings::from_index_snapshot(json!({
+            "rankingRules": ["words", "typo", "proximity", "attribute", "sort", "exactness"],
+            "proximityPrecision": "byWord",
+            "searchCutoffMs": "fast",
+            "facetSearch": true,
+            "embedders": { "default": { "source": "userProvided", "dimensions": 3 } },
+            "typoTolerance": { "enabled": true, "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 } },
+            "prefixSearch": "indexingTime",
+        }))
+        .unwrap();
+        assert_eq!(
+            settings.configured_fields(),
+            [
+                "rankingRules",
+                "proximityPrecision",
+                "embedders",
+                "facetSearch",
+                "typoTolerance"
+            ]
+        );
+        assert_eq!(report.dropped_keys, ["prefixSearch"]);
+        assert_eq!(report.invalid_keys.len(), 1);
+        assert_eq!(report.invalid_keys[0].0, "searchCutoffMs");
+        assert!(report.renamed_keys.is_empty());
+
+        assert!(matches!(
+            Settings::from_index_snapshot(json!(["stopWords"])),
+            Err(Error::ParseError { .. })
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_get_settings_unexpected_value() {
+        let client = Client::new(
+            format!("{}/unexpected_settings", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let body = r#"{ "rankingRules": ["words"], "proximityPrecision": "byFrequency" }"#;
+        let _get = mock("GET", "/unexpected_settings/indexes/movies/settings")
+            .with_status(200)
+            .with_body(body)
+            .create();
+
+        let error = index.get_settings().await.unwrap_err();
+
+        assert!(error.to_string().contains("byFrequency"));
+        assert!(matches!(
+            error,
+            Error::ParseError { body: ref response, type_name, .. }
+                if response == body && type_name.ends_with("Settings")
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_get_settings_fields() {
+        let client = Client::new(
+            format!("{}/settings_fields", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let get = mock("GET", "/settings_fields/indexes/movies/settings")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "displayedAttributes": ["*"],
+                    "searchableAttributes": ["*"],
+                    "filterableAttributes": ["genre"],
+                    "sortableAttributes": [],
+                    "rankingRules": ["words", "typo"],
+                    "stopWords": ["the"],
+                    "synonyms": {},
+                    "distinctAttribute": null,
+                    "pagination": { "maxTotalHits": 1000 },
+                    "faceting": { "maxValuesPerFacet": 100 },
+                    "searchCutoffMs": null
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let settings = index
+            .get_settings_fields(&[SettingsField::StopWords, SettingsField::RankingRules])
+            .await
+            .unwrap();
+
+        get.assert();
+        assert_eq!(settings.stop_words, Setting::Set(vec!["the".to_string()]));
+        assert_eq!(
+            settings.ranking_rules,
+            Setting::Set(vec!["words".to_string(), "typo".to_string()])
+        );
+        assert_eq!(settings.configured_fields(), ["stopWords", "rankingRules"]);
+        assert_eq!(settings.filterable_attributes, Setting::NotSet);
+        assert_eq!(settings.pagination, Setting::NotSet);
+        assert_eq!(settings.distinct_attribute, Setting::NotSet);
+    }
 
-    /// Reset [displayed attributes](https://docs.meilisearch.com/reference/features/settings.html#displayed-attributes) of the [Index] (enable all attributes).
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
-    /// #
-    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
-    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
-    /// #
-    /// # futures::executor::block_on(async move {
-    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_displayed_attributes", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_displayed_attributes");
-    ///
-    /// let task = index.reset_displayed_attributes().await.unwrap();
-    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// # });
-    /// ```
-    pub async fn reset_displayed_attributes(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/displayed-attributes",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    #[test]
+    fn test_suggest_filterable_attributes() {
+        let documents: Vec<Map<String, Value>> = serde_json::from_value(json!([
+            { "id": 1, "title": "Carol", "genre": "romance", "year": 2015, "overview": "A love story in the fifties.", "colour": true, "release": { "country": "US" } },
+            { "id": 2, "title": "Wonder Woman", "genre": "action", "year": 2017, "overview": "An amazon princess goes to war.", "colour": true, "release": { "country": "US" } },
+            { "id": 3, "title": "Life of Pi", "genre": "adventure", "year": 2012, "overview": "A boy and a tiger lost at sea.", "colour": true, "release": { "country": ["US", "TW"] } },
+            { "id": 4, "title": "Mad Max", "genre": "action", "year": 2015, "overview": "A road war in the desert.", "colour": true, "release": null },
+            { "id": 5, "title": "Moana", "genre": "adventure", "year": 2016, "overview": "A princess sails across the sea.", "colour": "yes" },
+            { "id": 6, "title": "Philadelphia", "genre": "drama", "year": 1993, "overview": "A lawyer fights for his rights." },
+        ]))
+        .unwrap();
+
+        let suggestions = suggest_filterable_attributes(&documents);
+
+        assert_eq!(
+            suggestions,
+            [
+                FieldSuggestion {
+                    field: "release.country".to_string(),
+                    cardinality_estimate: 2,
+                    value_kind: ValueKind::String,
+                    reason: "2 distinct values in 3 documents".to_string(),
+                },
+                FieldSuggestion {
+                    field: "genre".to_string(),
+                    cardinality_estimate: 4,
+                    value_kind: ValueKind::String,
+                    reason: "4 distinct values in 6 documents".to_string(),
+                },
+                FieldSuggestion {
+                    field: "year".to_string(),
+                    cardinality_estimate: 5,
+                    value_kind: ValueKind::Number,
+                    reason: "5 distinct values in 6 documents".to_string(),
+                },
+            ]
+        );
+        assert!(suggest_filterable_attributes(&[]).is_empty());
     }
 
-    /// Reset [faceting](https://docs.meilisearch.com/reference/api/settings.html#faceting) settings of the [Index].
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use meilisearch_sdk::{client::*, indexes::*, settings::Settings};
-    /// #
-    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
-    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
-    /// #
-    /// # futures::executor::block_on(async move {
-    /// let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
-    /// # client.create_index("reset_faceting", None).await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// let mut index = client.index("reset_faceting");
-    ///
-    /// let task = index.reset_faceting().await.unwrap();
-    /// # index.delete().await.unwrap().wait_for_completion(&client, None, None).await.unwrap();
-    /// # });
-    /// ```
-    pub async fn reset_faceting(&self) -> Result<TaskInfo, Error> {
-        request::<(), TaskInfo>(
-            &format!(
-                "{}/indexes/{}/settings/faceting",
-                self.client.host, self.uid
-            ),
-            &self.client.api_key,
-            Method::Delete,
-            202,
-        )
-        .await
+    #[meilisearch_test]
+    async fn test_single_value_setting_requests() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("single_value_setting");
+        let task = r#"{ "taskUid": 1, "indexUid": "single_value_setting", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#;
+        let route = |setting: &str| format!("/indexes/single_value_setting/settings/{}", setting);
+
+        let mocks = [
+            mock("GET", route("pagination").as_str())
+                .with_status(200)
+                .with_body(r#"{ "maxTotalHits": 42 }"#)
+                .create(),
+            mock("PATCH", route("pagination").as_str())
+                .match_body(mockito::Matcher::Exact(
+                    r#"{"maxTotalHits":42}"#.to_string(),
+                ))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("pagination").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("GET", route("faceting").as_str())
+                .with_status(200)
+                .with_body(r#"{ "maxValuesPerFacet": 5, "sortFacetValuesBy": { "*": "count" } }"#)
+                .create(),
+            mock("PATCH", route("faceting").as_str())
+                .match_body(mockito::Matcher::Exact(
+                    r#"{"maxValuesPerFacet":5,"sortFacetValuesBy":{"*":"count"}}"#.to_string(),
+                ))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("faceting").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("GET", route("distinct-attribute").as_str())
+                .with_status(200)
+                .with_body("null")
+                .create(),
+            mock("PUT", route("distinct-attribute").as_str())
+                .match_body(mockito::Matcher::Exact(r#""movie_id""#.to_string()))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("distinct-attribute").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("PUT", route("proximity-precision").as_str())
+                .match_body(mockito::Matcher::Exact(r#""byAttribute""#.to_string()))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            // unset, the cutoff of Meilisearch applies
+            mock("GET", route("search-cutoff-ms").as_str())
+                .with_status(200)
+                .with_body("null")
+                .create(),
+            // the fields unknown to the SDK are ignored
+            mock("GET", route("embedders").as_str())
+                .with_status(200)
+                .with_body(r#"{ "default": { "source": "userProvided", "dimensions": 3, "distribution": null } }"#)
+                .create(),
+            mock("PATCH", route("embedders").as_str())
+                .match_body(mockito::Matcher::Exact(
+                    r#"{"default":{"source":"userProvided","dimensions":3}}"#.to_string(),
+                ))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("embedders").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("PUT", route("search-cutoff-ms").as_str())
+                .match_body(mockito::Matcher::Exact("150".to_string()))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("GET", route("facet-search").as_str())
+                .with_status(200)
+                .with_body("true")
+                .create(),
+            mock("PUT", route("facet-search").as_str())
+                .match_body(mockito::Matcher::Exact("false".to_string()))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("facet-search").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("GET", route("localized-attributes").as_str())
+                .with_status(200)
+                .with_body(r#"[{ "locales": ["fra"], "attributePatterns": ["title", "description"] }]"#)
+                .create(),
+            mock("PUT", route("localized-attributes").as_str())
+                .match_body(mockito::Matcher::Exact(
+                    r#"[{"locales":["fra"],"attributePatterns":["title","description"]}]"#.to_string(),
+                ))
+                .with_status(202)
+                .with_body(task)
+                .create(),
+            mock("DELETE", route("localized-attributes").as_str())
+                .with_status(202)
+                .with_body(task)
+                .create(),
+        ];
+
+        let pagination = index.get_pagination().await.unwrap();
+        assert_eq!(pagination, PaginationSetting { max_total_hits: 42 });
+        assert_eq!(index.set_pagination(pagination).await.unwrap().task_uid, 1);
+        assert_eq!(index.reset_pagination().await.unwrap().task_uid, 1);
+
+        let faceting = index.get_faceting().await.unwrap();
+        assert_eq!(
+            faceting,
+            FacetingSettings::new()
+                .with_max_values_per_facet(5)
+                .with_sort_facet_values_by([("*", FacetSortBy::Count)])
+        );
+        assert_eq!(index.set_faceting(&faceting).await.unwrap().task_uid, 1);
+        assert_eq!(index.reset_faceting().await.unwrap().task_uid, 1);
+
+        assert_eq!(index.get_distinct_attribute().await.unwrap(), None);
+        assert_eq!(
+            index
+                .set_distinct_attribute("movie_id")
+                .await
+                .unwrap()
+                .task_uid,
+            1
+        );
+        assert_eq!(index.reset_distinct_attribute().await.unwrap().task_uid, 1);
+
+        let task = index
+            .set_proximity_precision(ProximityPrecision::ByAttribute)
+            .await
+            .unwrap();
+        assert_eq!(task.task_uid, 1);
+
+        let embedders = index.get_embedders().await.unwrap();
+        assert_eq!(
+            embedders,
+            HashMap::from([("default".to_string(), Embedder::user_provided(3))])
+        );
+        assert_eq!(index.set_embedders(&embedders).await.unwrap().task_uid, 1);
+        assert_eq!(index.reset_embedders().await.unwrap().task_uid, 1);
+
+        assert_eq!(index.get_search_cutoff_ms().await.unwrap(), None);
+        assert_eq!(index.set_search_cutoff_ms(150).await.unwrap().task_uid, 1);
+
+        assert!(index.get_facet_search().await.unwrap());
+        let task = index.set_facet_search(false).await.unwrap();
+        assert_eq!(task.task_uid, 1);
+        assert_eq!(index.reset_facet_search().await.unwrap().task_uid, 1);
+
+        let french = LocalizedAttribute::new(["fra"], ["title", "description"]);
+        assert_eq!(
+            index.get_localized_attributes().await.unwrap(),
+            Some(vec![french.clone()])
+        );
+        assert_eq!(
+            index
+                .set_localized_attributes([french])
+                .await
+                .unwrap()
+                .task_uid,
+            1
+        );
+        assert_eq!(
+            index.reset_localized_attributes().await.unwrap().task_uid,
+            1
+        );
+
+        for mock in mocks {
+            mock.assert();
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[meilisearch_test]
+    async fn test_set_settings_if_unchanged_since_mocked() {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let index = client.index("unchanged_since");
+        let info = mock("GET", "/indexes/unchanged_since")
+            .with_status(200)
+            .with_body(
+                r#"{ "uid": "unchanged_since", "createdAt": "2023-01-01T00:00:00Z", "updatedAt": "2023-01-02T00:00:00Z", "primaryKey": null }"#,
+            )
+            .expect(2)
+            .create();
+        let update = mock("PATCH", "/indexes/unchanged_since/settings")
+            .match_body(mockito::Matcher::Exact(
+                r#"{"stopWords":["the"]}"#.to_string(),
+            ))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "unchanged_since", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-02T00:00:00Z" }"#)
+            .expect(1)
+            .create();
+        let settings = Settings::new().with_stop_words(["the"]);
+        // 2023-01-02T00:00:00Z
+        let updated_at = OffsetDateTime::from_unix_timestamp(1672617600).unwrap();
 
-    use crate::client::*;
-    use meilisearch_test_macro::meilisearch_test;
+        let task = index
+            .set_settings_if_unchanged_since(&settings, updated_at)
+            .await
+            .unwrap();
+        assert_eq!(task.task_uid, 1);
+        let error = index
+            .set_settings_if_unchanged_since(&settings, updated_at - time::Duration::hours(1))
+            .await
+            .unwrap_err();
+
+        info.assert();
+        update.assert();
+        assert!(matches!(
+            error,
+            Error::ConcurrentModification { server_updated_at } if server_updated_at == updated_at
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_set_settings_if_unchanged_since(client: Client, index: Index) {
+        let read = client.get_index(&index.uid).await.unwrap();
+        let last_seen_updated_at = read.updated_at.unwrap();
+
+        // another job updates the settings in between
+        let task_info = index
+            .set_settings(&Settings::new().with_stop_words(["a"]))
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let settings = Settings::new().with_stop_words(["the"]);
+        let error = index
+            .set_settings_if_unchanged_since(&settings, last_seen_updated_at)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::ConcurrentModification { .. }));
+        assert_eq!(index.get_stop_words().await.unwrap(), ["a"]);
+
+        let read = client.get_index(&index.uid).await.unwrap();
+        let task_info = index
+            .set_settings_if_unchanged_since(&settings, read.updated_at.unwrap())
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        assert_eq!(index.get_stop_words().await.unwrap(), ["the"]);
+    }
 
     #[meilisearch_test]
     async fn test_set_faceting_settings(client: Client, index: Index) {
-        let faceting = FacetingSettings {
-            max_values_per_facet: 5,
-        };
+        let faceting = FacetingSettings::new().with_max_values_per_facet(5);
         let settings = Settings::new().with_faceting(&faceting);
 
         let task_info = index.set_settings(&settings).await.unwrap();
@@ -1349,14 +5741,14 @@ mod tests {
 
         let res = index.get_faceting().await.unwrap();
 
-        assert_eq!(faceting, res);
+        assert_eq!(res.max_values_per_facet, Setting::Set(5));
     }
 
     #[meilisearch_test]
     async fn test_get_faceting(index: Index) {
-        let faceting = FacetingSettings {
-            max_values_per_facet: 100,
-        };
+        let faceting = FacetingSettings::new()
+            .with_max_values_per_facet(100)
+            .with_sort_facet_values_by([("*", FacetSortBy::Alpha)]);
 
         let res = index.get_faceting().await.unwrap();
 
@@ -1365,30 +5757,697 @@ mod tests {
 
     #[meilisearch_test]
     async fn test_set_faceting(client: Client, index: Index) {
-        let faceting = FacetingSettings {
-            max_values_per_facet: 5,
-        };
+        let faceting = FacetingSettings::new()
+            .with_max_values_per_facet(5)
+            .with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
         let task_info = index.set_faceting(&faceting).await.unwrap();
         client.wait_for_task(task_info, None, None).await.unwrap();
 
         let res = index.get_faceting().await.unwrap();
 
-        assert_eq!(faceting, res);
+        // the facets not listed keep the default order
+        assert_eq!(
+            res,
+            FacetingSettings::new()
+                .with_max_values_per_facet(5)
+                .with_sort_facet_values_by([
+                    ("*", FacetSortBy::Alpha),
+                    ("genres", FacetSortBy::Count)
+                ])
+        );
+    }
+
+    #[test]
+    fn test_faceting_partial_update() {
+        let faceting =
+            FacetingSettings::new().with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
+
+        assert_eq!(
+            serde_json::to_value(&faceting).unwrap(),
+            json!({ "sortFacetValuesBy": { "genres": "count" } })
+        );
+        assert_eq!(
+            serde_json::from_value::<FacetingSettings>(
+                json!({ "maxValuesPerFacet": 10, "sortFacetValuesBy": { "*": "alpha", "genres": "count" } })
+            )
+            .unwrap(),
+            FacetingSettings::new()
+                .with_max_values_per_facet(10)
+                .with_sort_facet_values_by([("*", FacetSortBy::Alpha), ("genres", FacetSortBy::Count)])
+        );
+
+        // the undefined fields are not sent with the other settings either
+        assert_eq!(
+            Settings::new()
+                .with_faceting(&FacetingSettings::new().with_max_values_per_facet(5))
+                .to_request_body(),
+            json!({ "faceting": { "maxValuesPerFacet": 5 } })
+        );
+        assert_eq!(
+            Settings::new()
+                .with_faceting(&FacetingSettings::new())
+                .to_request_body(),
+            json!({ "faceting": {} })
+        );
+
+        // a single field can be reset, it's sent as null
+        assert_eq!(
+            serde_json::to_value(
+                FacetingSettings::new()
+                    .with_sort_facet_values_by([("genres", FacetSortBy::Count)])
+                    .reset_max_values_per_facet()
+            )
+            .unwrap(),
+            json!({ "maxValuesPerFacet": null, "sortFacetValuesBy": { "genres": "count" } })
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_set_faceting_partially() {
+        let client = Client::new(
+            format!("{}/faceting_patch", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let task = r#"{ "taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#;
+        let sort_only = mock("PATCH", "/faceting_patch/indexes/movies/settings/faceting")
+            .match_body(mockito::Matcher::Exact(
+                r#"{"sortFacetValuesBy":{"genres":"count"}}"#.to_string(),
+            ))
+            .with_status(202)
+            .with_body(task)
+            .create();
+        let reset_only = mock("PATCH", "/faceting_patch/indexes/movies/settings/faceting")
+            .match_body(mockito::Matcher::Exact(
+                r#"{"maxValuesPerFacet":null}"#.to_string(),
+            ))
+            .with_status(202)
+            .with_body(task)
+            .create();
+
+        let faceting =
+            FacetingSettings::new().with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
+        assert_eq!(index.set_faceting(&faceting).await.unwrap().task_uid, 1);
+        let faceting = FacetingSettings::new().reset_max_values_per_facet();
+        assert_eq!(index.set_faceting(&faceting).await.unwrap().task_uid, 1);
+
+        sort_only.assert();
+        reset_only.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_set_faceting_rejects_zero_values_per_facet() {
+        let client = Client::new(
+            format!("{}/faceting_validation", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let patch = mock(
+            "PATCH",
+            "/faceting_validation/indexes/movies/settings/faceting",
+        )
+        .expect(0)
+        .create();
+
+        let faceting = FacetingSettings::new().with_max_values_per_facet(0);
+        let res = index.set_faceting(&faceting).await;
+
+        patch.assert();
+        assert!(matches!(res, Err(Error::InvalidRequest)));
+        assert!(FacetingSettings::new().validate().is_ok());
+        assert!(FacetingSettings::new()
+            .reset_max_values_per_facet()
+            .validate()
+            .is_ok());
+    }
+
+    #[meilisearch_test]
+    async fn test_set_faceting_keeps_the_fields_not_set(client: Client, index: Index) {
+        let faceting = FacetingSettings::new().with_max_values_per_facet(5);
+        let task_info = index.set_faceting(&faceting).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let faceting =
+            FacetingSettings::new().with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
+        let task_info = index.set_faceting(&faceting).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_faceting().await.unwrap();
+        assert_eq!(res.max_values_per_facet, Setting::Set(5));
+
+        let faceting = FacetingSettings::new().reset_max_values_per_facet();
+        let task_info = index.set_faceting(&faceting).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_faceting().await.unwrap();
+        assert_eq!(res.max_values_per_facet, Setting::Set(100));
+        assert_eq!(
+            res.sort_facet_values_by,
+            FacetingSettings::new()
+                .with_sort_facet_values_by([
+                    ("*", FacetSortBy::Alpha),
+                    ("genres", FacetSortBy::Count)
+                ])
+                .sort_facet_values_by
+        );
     }
 
     #[meilisearch_test]
     async fn test_reset_faceting(client: Client, index: Index) {
         let task_info = index.reset_faceting().await.unwrap();
         client.wait_for_task(task_info, None, None).await.unwrap();
-        let faceting = FacetingSettings {
-            max_values_per_facet: 100,
-        };
+        let faceting = FacetingSettings::new()
+            .with_max_values_per_facet(100)
+            .with_sort_facet_values_by([("*", FacetSortBy::Alpha)]);
 
         let res = index.get_faceting().await.unwrap();
 
         assert_eq!(faceting, res);
     }
 
+    #[test]
+    fn test_typo_tolerance_serialization() {
+        assert_eq!(
+            serde_json::to_value(TypoToleranceSettings::new().with_enabled(false)).unwrap(),
+            json!({ "enabled": false })
+        );
+
+        let typo_tolerance: TypoToleranceSettings = serde_json::from_value(json!({
+            "enabled": true,
+            "minWordSizeForTypos": { "oneTypo": 5, "twoTypos": 9 },
+            "disableOnWords": [],
+            "disableOnAttributes": []
+        }))
+        .unwrap();
+        assert_eq!(
+            typo_tolerance,
+            TypoToleranceSettings::new()
+                .with_enabled(true)
+                .with_min_word_size_for_typos(MinWordSizeForTypos {
+                    one_typo: Some(5),
+                    two_typos: Some(9),
+                })
+                .with_disable_on_words(Vec::<String>::new())
+                .with_disable_on_attributes(Vec::<String>::new())
+        );
+    }
+
+    #[test]
+    fn test_pagination_setting_builder() {
+        assert_eq!(PaginationSetting::new().max_total_hits, 1000);
+        assert_eq!(
+            PaginationSetting::new().with_max_total_hits(50),
+            PaginationSetting { max_total_hits: 50 }
+        );
+        assert_eq!(
+            serde_json::to_value(PaginationSetting::new().with_max_total_hits(50)).unwrap(),
+            json!({ "maxTotalHits": 50 })
+        );
+    }
+
+    #[test]
+    fn test_typo_tolerance_disabled() {
+        let disabled = TypoToleranceSettings::disabled();
+        assert_eq!(disabled.enabled, Setting::Set(false));
+        assert_eq!(disabled, TypoToleranceSettings::new().with_enabled(false));
+
+        let settings = Settings::new().with_typo_tolerance_disabled();
+        match &settings.typo_tolerance {
+            Setting::Set(typo_tolerance) => {
+                assert_eq!(typo_tolerance.enabled, Setting::Set(false))
+            }
+            other => panic!("expected the typo tolerance to be set, got {:?}", other),
+        }
+
+        let mut set = Settings::new();
+        set.set_typo_tolerance_disabled();
+        assert_eq!(set.to_request_body(), settings.to_request_body());
+    }
+
+    #[test]
+    fn test_settings_file_round_trip() {
+        let settings = Settings::new()
+            .with_stop_words(["a", "the"])
+            .with_synonyms(HashMap::from([("sf", vec!["sci-fi"])]))
+            .with_pagination(PaginationSetting {
+                max_total_hits: 100,
+            })
+            .with_faceting(&FacetingSettings::new().with_max_values_per_facet(20))
+            .with_typo_tolerance(
+                &TypoToleranceSettings::new()
+                    .with_enabled(true)
+                    .with_min_word_size_for_typos(MinWordSizeForTypos {
+                        one_typo: Some(4),
+                        two_typos: None,
+                    }),
+            )
+            .reset_distinct_attribute();
+
+        let mut file = Vec::new();
+        settings.to_writer(&mut file).unwrap();
+        let read = Settings::from_reader(file.as_slice()).unwrap();
+        assert_eq!(read.to_request_body(), settings.to_request_body());
+        assert_eq!(read.distinct_attribute, Setting::Reset);
+
+        let read = Settings::from_json_str(&settings.to_json_string()).unwrap();
+        assert_eq!(read.to_request_body(), settings.to_request_body());
+    }
+
+    #[test]
+    fn test_settings_file_rejects_unknown_keys() {
+        let unknown = |json: Value| match Settings::from_json_str(&json.to_string()) {
+            Err(Error::UnknownSettingsKey(key)) => key,
+            other => panic!("expected an unknown key, got {:?}", other),
+        };
+
+        assert_eq!(
+            unknown(json!({ "stopWords": [], "stopWord": [] })),
+            "stopWord"
+        );
+        assert_eq!(
+            unknown(json!({ "typoTolerance": { "enabled": true, "enabeld": false } })),
+            "typoTolerance.enabeld"
+        );
+        assert_eq!(
+            unknown(json!({ "typoTolerance": { "minWordSizeForTypos": { "oneTypos": 4 } } })),
+            "typoTolerance.minWordSizeForTypos.oneTypos"
+        );
+        assert_eq!(
+            unknown(
+                json!({ "embedders": { "default": { "source": "userProvided", "dimension": 3 } } })
+            ),
+            "embedders.default.dimension"
+        );
+        // a misspelled key is unknown even when it is null
+        assert_eq!(
+            unknown(json!({ "distinctAttribute": null, "stopWord": null })),
+            "stopWord"
+        );
+        assert_eq!(
+            unknown(json!({ "typoTolerance": { "minWordSizeForTypos": { "twoTypo": null } } })),
+            "typoTolerance.minWordSizeForTypos.twoTypo"
+        );
+        // only the API keys of the embedders are exempted
+        assert_eq!(
+            unknown(json!({ "faceting": { "apiKey": "sk-…" } })),
+            "faceting.apiKey"
+        );
+
+        // the keys known but not serialized back are accepted
+        let settings = Settings::from_json_str(
+            &json!({
+                "distinctAttribute": null,
+                "typoTolerance": { "minWordSizeForTypos": { "oneTypo": null, "twoTypos": 9 } },
+                "embedders": { "default": { "source": "openAi", "apiKey": "sk-…", "model": null, "dimensions": null } }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        assert_eq!(settings.distinct_attribute, Setting::Reset);
+        assert!(matches!(
+            Settings::from_json_str("[]"),
+            Err(Error::ParseError { .. })
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_apply_settings_file() {
+        let client = Client::new(
+            format!("{}/apply_settings_file", mockito::server_url()),
+            "masterKey",
+        );
+        let index = client.index("movies");
+        let patch = mock("PATCH", "/apply_settings_file/indexes/movies/settings")
+            .match_body(mockito::Matcher::Json(
+                json!({ "stopWords": ["the"], "pagination": { "maxTotalHits": 10 } }),
+            ))
+            .with_status(202)
+            .with_body(r#"{ "taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#)
+            .expect(1)
+            .create();
+
+        let path = std::env::temp_dir().join(format!(
+            "meilisearch_sdk_apply_settings_file_{}.json",
+            std::process::id()
+        ));
+        let settings = Settings::new()
+            .with_stop_words(["the"])
+            .with_pagination(PaginationSetting { max_total_hits: 10 });
+        settings
+            .to_writer(std::fs::File::create(&path).unwrap())
+            .unwrap();
+        let task = index.apply_settings_file(&path).await.unwrap();
+        patch.assert();
+        assert_eq!(task.task_uid, 1);
+
+        // an invalid file isn't sent
+        std::fs::write(&path, r#"{ "stopWords": ["the"], "rankingRule": [] }"#).unwrap();
+        let error = index.apply_settings_file(&path).await.unwrap_err();
+        assert!(matches!(error, Error::UnknownSettingsKey(key) if key == "rankingRule"));
+        std::fs::remove_file(&path).unwrap();
+
+        let error = index.apply_settings_file(&path).await.unwrap_err();
+        assert!(matches!(error, Error::Io(error) if error.kind() == std::io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn test_pagination_setting_default() {
+        assert_eq!(PaginationSetting::default().max_total_hits, 1000);
+        assert_eq!(PaginationSetting::default(), PaginationSetting::new());
+    }
+
+    #[test]
+    fn test_min_word_size_for_typos_builder() {
+        assert_eq!(
+            MinWordSizeForTypos::builder()
+                .one_typo(4)
+                .two_typos(8)
+                .build()
+                .unwrap(),
+            MinWordSizeForTypos {
+                one_typo: Some(4),
+                two_typos: Some(8),
+            }
+        );
+        assert_eq!(
+            MinWordSizeForTypos::builder().two_typos(5).build().unwrap(),
+            MinWordSizeForTypos {
+                one_typo: None,
+                two_typos: Some(5),
+            }
+        );
+        assert_eq!(
+            MinWordSizeForTypos::builder().build().unwrap(),
+            MinWordSizeForTypos::default()
+        );
+
+        assert!(matches!(
+            MinWordSizeForTypos::builder()
+                .one_typo(9)
+                .two_typos(5)
+                .build(),
+            Err(Error::InvalidMinWordSizeForTypos {
+                one_typo: 9,
+                two_typos: 5
+            })
+        ));
+    }
+
+    #[meilisearch_test]
+    async fn test_set_typo_tolerance_partially() {
+        let client = Client::new(format!("{}/typo_patch", mockito::server_url()), "masterKey");
+        let index = client.index("movies");
+        let task = r#"{ "taskUid": 1, "indexUid": "movies", "status": "enqueued", "type": "settingsUpdate", "enqueuedAt": "2023-01-01T00:00:00Z" }"#;
+        let enabled_only = mock(
+            "PATCH",
+            "/typo_patch/indexes/movies/settings/typo-tolerance",
+        )
+        .match_body(mockito::Matcher::Exact(r#"{"enabled":false}"#.to_string()))
+        .with_status(202)
+        .with_body(task)
+        .create();
+
+        let typo_tolerance = TypoToleranceSettings::new().with_enabled(false);
+        assert_eq!(
+            index
+                .set_typo_tolerance(&typo_tolerance)
+                .await
+                .unwrap()
+                .task_uid,
+            1
+        );
+
+        enabled_only.assert();
+    }
+
+    #[meilisearch_test]
+    async fn test_set_typo_tolerance_keeps_the_fields_not_set(client: Client, index: Index) {
+        let typo_tolerance = TypoToleranceSettings::new().with_disable_on_words(["kafka"]);
+        let task_info = index.set_typo_tolerance(&typo_tolerance).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let typo_tolerance = TypoToleranceSettings::new().with_enabled(false);
+        let task_info = index.set_typo_tolerance(&typo_tolerance).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_typo_tolerance().await.unwrap();
+        assert_eq!(res.enabled, Setting::Set(false));
+        assert_eq!(
+            res.disable_on_words,
+            Setting::Set(vec!["kafka".to_string()])
+        );
+
+        let task_info = index.reset_typo_tolerance().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_typo_tolerance().await.unwrap();
+        assert_eq!(res.enabled, Setting::Set(true));
+        assert_eq!(res.disable_on_words, Setting::Set(vec![]));
+    }
+
+    #[meilisearch_test]
+    async fn test_set_dictionary(client: Client, index: Index) {
+        let task_info = index.set_dictionary(["J. R. R. Tolkien"]).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_dictionary().await.unwrap();
+
+        assert_eq!(vec!["J. R. R. Tolkien".to_string()], res);
+    }
+
+    #[meilisearch_test]
+    async fn test_set_settings_dictionary(client: Client, index: Index) {
+        let settings = Settings::new().with_dictionary(["J.R.R.", "C++"]);
+        assert_eq!(
+            settings.to_request_body(),
+            json!({ "dictionary": ["J.R.R.", "C++"] })
+        );
+
+        let task_info = index.set_settings(&settings).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let mut dictionary = index
+            .get_settings()
+            .await
+            .unwrap()
+            .dictionary
+            .into_option()
+            .unwrap();
+        dictionary.sort();
+
+        assert_eq!(dictionary, ["C++", "J.R.R."]);
+    }
+
+    #[meilisearch_test]
+    async fn test_reset_dictionary(client: Client, index: Index) {
+        let task_info = index.set_dictionary(["J. R. R. Tolkien"]).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let task_info = index.reset_dictionary().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let res = index.get_dictionary().await.unwrap();
+
+        assert!(res.is_empty());
+    }
+
+    #[meilisearch_test]
+    async fn test_set_separator_tokens(client: Client, index: Index) {
+        let task_info = index.set_separator_tokens(["@", "#"]).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let mut res = index.get_separator_tokens().await.unwrap();
+        res.sort();
+
+        assert_eq!(res, ["#", "@"]);
+    }
+
+    #[meilisearch_test]
+    async fn test_get_settings_separator_tokens(client: Client, index: Index) {
+        let task_info = index.set_separator_tokens(["|"]).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+
+        let settings = index.get_settings().await.unwrap();
+
+        assert_eq!(
+            settings.separator_tokens,
+            Setting::Set(vec!["|".to_string()])
+        );
+    }
+
+    #[meilisearch_test]
+    async fn test_reset_non_separator_tokens(client: Client, index: Index) {
+        let task_info = index.set_non_separator_tokens(["@", "#"]).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let mut res = index.get_non_separator_tokens().await.unwrap();
+        res.sort();
+        assert_eq!(res, ["#", "@"]);
+
+        let task_info = index.reset_non_separator_tokens().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_non_separator_tokens().await.unwrap();
+
+        assert!(res.is_empty());
+    }
+
+    #[meilisearch_test]
+    async fn test_set_proximity_precision(client: Client, index: Index) {
+        assert_eq!(
+            Settings::new()
+                .with_proximity_precision(ProximityPrecision::ByAttribute)
+                .to_request_body(),
+            json!({ "proximityPrecision": "byAttribute" })
+        );
+
+        let task_info = index
+            .set_proximity_precision(ProximityPrecision::ByAttribute)
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_proximity_precision().await.unwrap();
+        assert_eq!(res, ProximityPrecision::ByAttribute);
+
+        let task_info = index.reset_proximity_precision().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_proximity_precision().await.unwrap();
+        assert_eq!(res, ProximityPrecision::ByWord);
+    }
+
+    #[meilisearch_test]
+    async fn test_search_cutoff_ms(client: Client, index: Index) {
+        let task_info = index.set_search_cutoff_ms(150).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_search_cutoff_ms().await.unwrap();
+        assert_eq!(res, Some(150));
+
+        let task_info = index.reset_search_cutoff_ms().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_search_cutoff_ms().await.unwrap();
+        assert_eq!(res, None);
+    }
+
+    #[meilisearch_test]
+    async fn test_facet_search(client: Client, index: Index) {
+        let task_info = index.set_facet_search(false).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        assert!(!index.get_facet_search().await.unwrap());
+
+        let task_info = index.reset_facet_search().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        assert!(index.get_facet_search().await.unwrap());
+    }
+
+    #[meilisearch_test]
+    async fn test_localized_attributes(client: Client, index: Index) {
+        let french = LocalizedAttribute::new(["fra"], ["title", "description"]);
+        let task_info = index
+            .set_localized_attributes([french.clone()])
+            .await
+            .unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        assert_eq!(
+            index.get_localized_attributes().await.unwrap(),
+            Some(vec![french.clone()])
+        );
+        assert_eq!(
+            index.get_settings().await.unwrap().localized_attributes,
+            Setting::Set(vec![french])
+        );
+
+        let task_info = index.reset_localized_attributes().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        assert_eq!(index.get_localized_attributes().await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_localized_attributes_serialization() {
+        let settings = Settings::new().with_localized_attributes([LocalizedAttribute::new(
+            ["fra"],
+            ["title", "description"],
+        )]);
+        let body = settings.to_request_body();
+
+        assert_eq!(
+            body,
+            json!({ "localizedAttributes": [{ "locales": ["fra"], "attributePatterns": ["title", "description"] }] })
+        );
+        assert_eq!(
+            serde_json::from_value::<Settings>(body)
+                .unwrap()
+                .localized_attributes,
+            settings.localized_attributes
+        );
+        assert_eq!(settings.configured_fields(), ["localizedAttributes"]);
+    }
+
+    #[test]
+    fn test_embedder_skips_undefined_fields() {
+        let embedder = Embedder::new(EmbedderSource::HuggingFace)
+            .with_model("BAAI/bge-base-en-v1.5")
+            .with_document_template("A movie titled {{doc.title}}");
+
+        assert_eq!(
+            serde_json::to_value(&embedder).unwrap(),
+            json!({
+                "source": "huggingFace",
+                "model": "BAAI/bge-base-en-v1.5",
+                "documentTemplate": "A movie titled {{doc.title}}"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Embedder::new(EmbedderSource::OpenAi).with_api_key("sk-key"))
+                .unwrap(),
+            json!({ "source": "openAi", "apiKey": "sk-key" })
+        );
+    }
+
+    #[test]
+    fn test_embedder_redacted_api_key() {
+        let embedder: Embedder = serde_json::from_value(json!({
+            "source": "openAi",
+            "model": "text-embedding-3-small",
+            "apiKey": "sk-pr...",
+            "documentTemplate": "{{doc.title}}",
+            "dimensions": 1536
+        }))
+        .unwrap();
+        assert!(embedder.has_redacted_api_key());
+
+        // the redacted key doesn't replace the real one
+        let embedder = embedder.with_document_template("A movie titled {{doc.title}}");
+        assert_eq!(
+            serde_json::to_value(&embedder).unwrap(),
+            json!({
+                "source": "openAi",
+                "model": "text-embedding-3-small",
+                "documentTemplate": "A movie titled {{doc.title}}",
+                "dimensions": 1536
+            })
+        );
+
+        let embedder = embedder.with_api_key("sk-proj-a-new-key");
+        assert!(!embedder.has_redacted_api_key());
+        assert_eq!(
+            serde_json::to_value(&embedder).unwrap()["apiKey"],
+            json!("sk-proj-a-new-key")
+        );
+    }
+
+    // Needs a Meilisearch instance where the vector store is enabled.
+    #[ignore]
+    #[meilisearch_test]
+    async fn test_embedders(client: Client, index: Index) {
+        let embedders = HashMap::from([("default".to_string(), Embedder::user_provided(3))]);
+        let task_info = index.set_embedders(&embedders).await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_embedders().await.unwrap();
+        assert_eq!(res["default"].source, EmbedderSource::UserProvided);
+        assert_eq!(res["default"].dimensions, Some(3));
+
+        let task_info = index.reset_embedders().await.unwrap();
+        client.wait_for_task(task_info, None, None).await.unwrap();
+        let res = index.get_embedders().await.unwrap();
+        assert!(res.is_empty());
+    }
+
     #[meilisearch_test]
     async fn test_get_pagination(index: Index) {
         let pagination = PaginationSetting {
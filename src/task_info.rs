@@ -4,8 +4,19 @@ use time::OffsetDateTime;
 
 use crate::{client::Client, errors::Error, tasks::*};
 
+/// A task enqueued by Meilisearch. It's processed asynchronously, so the change isn't visible yet:
+/// [wait for it](TaskInfo::wait_for_completion) before reading the data it updates.
+///
+/// ```compile_fail
+/// # #![deny(unused_must_use)]
+/// # use meilisearch_sdk::task_info::TaskInfo;
+/// # fn enqueue() -> TaskInfo { unimplemented!() }
+/// // error: unused `TaskInfo` that must be used
+/// enqueue();
+/// ```
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[must_use = "the task is processed asynchronously, wait for it before reading the data it updates"]
 pub struct TaskInfo {
     #[serde(with = "time::serde::rfc3339")]
     pub enqueued_at: OffsetDateTime,
@@ -27,14 +38,19 @@ impl TaskInfo {
         self.task_uid
     }
 
+    /// When Meilisearch enqueued the task, parsed from its RFC 3339 `enqueuedAt`, ie to measure how long it waited to be processed.
+    pub fn enqueued_at(&self) -> OffsetDateTime {
+        self.enqueued_at
+    }
+
     /// Wait until Meilisearch processes a task provided by [TaskInfo], and get its status.
     ///
-    /// `interval` = The frequency at which the server should be polled. Default = 50ms
-    /// `timeout` = The maximum time to wait for processing to complete. Default = 5000ms
+    /// `interval` = The frequency at which the server should be polled. Default = the [WaitPolicy](crate::client::WaitPolicy) of the client, 50ms
+    /// `timeout` = The maximum time to wait for processing to complete. Default = the [WaitPolicy](crate::client::WaitPolicy) of the client, 5000ms
     ///
     /// If the waited time exceeds `timeout` then an [Error::Timeout] will be returned.
     ///
-    /// See also [Client::wait_for_task, Index::wait_for_task].
+    /// See also [Client::wait_for_task, Index::wait_for_task], which uses the [wait policy of the index](crate::indexes::Index::with_wait_policy).
     ///
     /// # Example
     ///
@@ -76,6 +92,21 @@ impl TaskInfo {
     ) -> Result<Task, Error> {
         client.wait_for_task(self, interval, timeout).await
     }
+
+    /// Wait until Meilisearch processes the task, calling `on_status_change` each time a new [TaskStatus] is observed.
+    ///
+    /// See [Client::wait_for_task_with_status_change].
+    pub async fn wait_for_completion_with_status_change(
+        self,
+        client: &Client,
+        interval: Option<Duration>,
+        timeout: Option<Duration>,
+        on_status_change: impl FnMut(TaskStatus),
+    ) -> Result<Task, Error> {
+        client
+            .wait_for_task_with_status_change(self, interval, timeout, on_status_change)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +160,32 @@ mod test {
         if enqueued_at == datetime && index_uid == "mieli" && status == "enqueued"));
     }
 
+    #[test]
+    fn test_task_info_enqueued_at() {
+        let task_info: TaskInfo = serde_json::from_str(
+            r#"
+{
+  "enqueuedAt": "2023-05-17T09:41:12.250118+02:00",
+  "indexUid": "movies",
+  "status": "enqueued",
+  "type": "settingsUpdate",
+  "taskUid": 42
+}"#,
+        )
+        .unwrap();
+
+        let expected = ::time::Date::from_calendar_date(2023, ::time::Month::May, 17)
+            .unwrap()
+            .with_hms_micro(7, 41, 12, 250_118)
+            .unwrap()
+            .assume_utc();
+        assert_eq!(task_info.enqueued_at(), expected);
+        assert_eq!(
+            task_info.enqueued_at().offset(),
+            ::time::UtcOffset::from_hms(2, 0, 0).unwrap()
+        );
+    }
+
     #[meilisearch_test]
     async fn test_wait_for_task_with_args(client: Client, movies: Index) -> Result<(), Error> {
         let task_info = movies
@@ -3,7 +3,8 @@ use std::time::Duration;
 use time::OffsetDateTime;
 
 use crate::{
-    client::Client, errors::Error, errors::MeilisearchError, indexes::Index, settings::Settings,
+    batches::Batch, client::Client, duration::parse_iso8601_duration, errors::Error,
+    errors::MeilisearchError, indexes::Index, settings::Settings,
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -31,6 +32,9 @@ pub enum TaskType {
     DumpCreation {
         details: Option<DumpCreation>,
     },
+    TaskDeletion {
+        details: Option<TaskDeletion>,
+    },
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -78,6 +82,25 @@ pub struct DumpCreation {
     pub dump_uid: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskDeletion {
+    pub matched_tasks: Option<usize>,
+    pub deleted_tasks: Option<usize>,
+    pub original_filter: Option<String>,
+}
+
+/// The status of a [Task].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskStatus {
+    Enqueued,
+    Processing,
+    Succeeded,
+    Failed,
+    Canceled,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FailedTask {
@@ -92,13 +115,40 @@ impl AsRef<u32> for FailedTask {
     }
 }
 
+impl std::fmt::Display for FailedTask {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "Task {}", self.task.uid)?;
+        if let Some(batch_uid) = self.task.batch_uid {
+            write!(fmt, " of batch {}", batch_uid)?;
+        }
+        write!(
+            fmt,
+            " failed: {}: {}",
+            self.error.error_code, self.error.error_message
+        )
+    }
+}
+
 fn deserialize_duration<'de, D>(deserializer: D) -> Result<std::time::Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
-    let iso_duration = iso8601_duration::Duration::parse(&s).map_err(serde::de::Error::custom)?;
-    Ok(iso_duration.to_std())
+    parse_iso8601_duration(&s).map_err(serde::de::Error::custom)
+}
+
+pub(crate) fn deserialize_optional_duration<'de, D>(
+    deserializer: D,
+) -> Result<Option<std::time::Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) => parse_iso8601_duration(&s)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -116,6 +166,10 @@ pub struct SucceededTask {
     #[serde(flatten)]
     pub update_type: TaskType,
     pub uid: u32,
+    /// The uid of the [batch](crate::batches::Batch) that processed the task.
+    /// Only sent by Meilisearch v1.13 and later.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
 }
 
 impl AsRef<u32> for SucceededTask {
@@ -133,6 +187,9 @@ pub struct EnqueuedTask {
     #[serde(flatten)]
     pub update_type: TaskType,
     pub uid: u32,
+    /// The uid of the [batch](crate::batches::Batch) processing the task, once it is processing.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
 }
 
 impl AsRef<u32> for EnqueuedTask {
@@ -141,6 +198,41 @@ impl AsRef<u32> for EnqueuedTask {
     }
 }
 
+/// A task whose `details` are deserialized into `D`, returned by [Client::get_task_with_details].
+///
+/// It's an escape hatch for the details the [TaskType] doesn't know yet, ie the fields added by a fork of Meilisearch.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskWith<D> {
+    pub uid: u32,
+    pub index_uid: Option<String>,
+    pub status: TaskStatus,
+    /// The type of the task, as named by Meilisearch, ie `documentAdditionOrUpdate`.
+    #[serde(rename = "type")]
+    pub task_type: String,
+    pub details: Option<D>,
+    /// Why the task failed, when its status is [TaskStatus::Failed].
+    pub error: Option<MeilisearchError>,
+    /// `None` until the task is processed.
+    #[serde(default, deserialize_with = "deserialize_optional_duration")]
+    pub duration: Option<Duration>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub enqueued_at: OffsetDateTime,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub started_at: Option<OffsetDateTime>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub finished_at: Option<OffsetDateTime>,
+    /// The uid of the [batch](crate::batches::Batch) processing the task, once it is processing.
+    #[serde(default)]
+    pub batch_uid: Option<u32>,
+}
+
+impl<D> AsRef<u32> for TaskWith<D> {
+    fn as_ref(&self) -> &u32 {
+        &self.uid
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "status")]
 pub enum Task {
@@ -171,6 +263,90 @@ impl Task {
         }
     }
 
+    /// Get the uid of the [Batch] that processed the [Task].
+    ///
+    /// It's `None` while the task is enqueued, or when Meilisearch is older than v1.13.
+    pub fn batch_uid(&self) -> Option<u32> {
+        match self {
+            Self::Enqueued { content } | Self::Processing { content } => content.batch_uid,
+            Self::Failed { content } => content.task.batch_uid,
+            Self::Succeeded { content } => content.batch_uid,
+        }
+    }
+
+    /// Get how long Meilisearch took to process the [Task].
+    ///
+    /// It's `None` until the task is processed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use meilisearch_sdk::tasks::Task;
+    /// # use std::time::Duration;
+    /// let task: Task = serde_json::from_str(r#"{
+    ///     "uid": 1,
+    ///     "indexUid": "movies",
+    ///     "status": "succeeded",
+    ///     "type": "indexCreation",
+    ///     "duration": "PT0.023S",
+    ///     "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+    ///     "startedAt": "2022-02-03T15:17:02.812338Z",
+    ///     "finishedAt": "2022-02-03T15:17:02.835338Z"
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(task.duration(), Some(Duration::from_millis(23)));
+    /// ```
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::Enqueued { .. } | Self::Processing { .. } => None,
+            Self::Failed { content } => Some(content.task.duration),
+            Self::Succeeded { content } => Some(content.duration),
+        }
+    }
+
+    /// Fetch the [Batch] that processed the [Task], ie to follow its progress.
+    ///
+    /// Returns `None` when the task isn't part of a batch yet, see [Task::batch_uid].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use meilisearch_sdk::{client::*, indexes::*};
+    /// #
+    /// # let MEILISEARCH_URL = option_env!("MEILISEARCH_URL").unwrap_or("http://localhost:7700");
+    /// # let MEILISEARCH_API_KEY = option_env!("MEILISEARCH_API_KEY").unwrap_or("masterKey");
+    /// #
+    /// # futures::executor::block_on(async move {
+    /// # let client = Client::new(MEILISEARCH_URL, MEILISEARCH_API_KEY);
+    /// let task = client
+    ///   .create_index("fetch_batch", None)
+    ///   .await
+    ///   .unwrap()
+    ///   .wait_for_completion(&client, None, None)
+    ///   .await
+    ///   .unwrap();
+    ///
+    /// let batch = task.fetch_batch(&client).await.unwrap().unwrap();
+    /// assert_eq!(batch.stats.total_nb_tasks, 1);
+    /// # });
+    /// ```
+    pub async fn fetch_batch(&self, client: &Client) -> Result<Option<Batch>, Error> {
+        match self.batch_uid() {
+            Some(batch_uid) => client.get_batch(batch_uid).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the [TaskStatus] of the [Task].
+    pub fn status(&self) -> TaskStatus {
+        match self {
+            Self::Enqueued { .. } => TaskStatus::Enqueued,
+            Self::Processing { .. } => TaskStatus::Processing,
+            Self::Failed { .. } => TaskStatus::Failed,
+            Self::Succeeded { .. } => TaskStatus::Succeeded,
+        }
+    }
+
     /// Wait until Meilisearch processes a [Task], and get its status.
     ///
     /// `interval` = The frequency at which the server should be polled. Default = 50ms
@@ -227,6 +403,7 @@ impl Task {
     /// Extract the [Index] from a successful `IndexCreation` task.
     ///
     /// If the task failed or was not an `IndexCreation` task it return itself.
+    /// The [Index] knows the primary key given at its creation.
     ///
     /// # Example
     ///
@@ -254,10 +431,14 @@ impl Task {
                 content:
                     SucceededTask {
                         index_uid,
-                        update_type: TaskType::IndexCreation { .. },
+                        update_type: TaskType::IndexCreation { details },
                         ..
                     },
-            } => Ok(client.index(index_uid.unwrap())),
+            } => {
+                let mut index = client.index(index_uid.unwrap());
+                index.primary_key = details.and_then(|details| details.primary_key);
+                Ok(index)
+            }
             _ => Err(self),
         }
     }
@@ -515,6 +696,7 @@ mod test {
                     index_uid: Some(index_uid),
                     update_type: TaskType::DocumentAdditionOrUpdate { details: None },
                     uid: 12,
+                    batch_uid: None,
                 }
             }
         if enqueued_at == datetime && index_uid == "mieli"));
@@ -588,10 +770,194 @@ mod test {
                     ..
                 }
             }
-            if duration == Duration::from_secs_f32(10.848957061)
+            if duration == Duration::new(10, 848_957_000)
         ));
     }
 
+    #[test]
+    fn test_task_duration() {
+        let task = |status: &str, duration: &str| -> Task {
+            serde_json::from_value(serde_json::json!({
+                "uid": 14,
+                "indexUid": "mieli",
+                "status": status,
+                "type": "indexCreation",
+                "duration": duration,
+                "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+                "startedAt": "2022-02-03T15:17:02.812338Z",
+                "finishedAt": "2022-02-03T15:17:02.901341Z",
+                "error": {
+                    "message": "Index `mieli` already exists.",
+                    "code": "index_already_exists",
+                    "type": "invalid_request",
+                    "link": "https://docs.meilisearch.com/errors#index_already_exists"
+                }
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            task("succeeded", "PT0.023S").duration(),
+            Some(Duration::from_millis(23))
+        );
+        assert_eq!(
+            task("succeeded", "PT0.000412S").duration(),
+            Some(Duration::from_micros(412))
+        );
+        assert_eq!(
+            task("succeeded", "PT10.848957S").duration(),
+            Some(Duration::new(10, 848_957_000))
+        );
+        assert_eq!(
+            task("succeeded", "PT1M2S").duration(),
+            Some(Duration::from_secs(62))
+        );
+        // Meilisearch writes the durations of a day or more with days
+        assert_eq!(
+            task("succeeded", "P1DT2H3.5S").duration(),
+            Some(Duration::from_millis(93_603_500))
+        );
+        assert_eq!(
+            task("failed", "PT0.5S").duration(),
+            Some(Duration::from_millis(500))
+        );
+
+        let enqueued: Task = serde_json::from_value(serde_json::json!({
+            "uid": 14,
+            "indexUid": "mieli",
+            "status": "enqueued",
+            "type": "indexCreation",
+            "duration": null,
+            "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+            "startedAt": null,
+            "finishedAt": null
+        }))
+        .unwrap();
+        assert_eq!(enqueued.duration(), None);
+    }
+
+    #[test]
+    fn test_deserialize_task_batch_uid() {
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "details": { "indexedDocuments": 2, "receivedDocuments": 2 },
+  "duration": "PT0.1S",
+  "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+  "finishedAt": "2022-02-03T15:17:02.901341Z",
+  "indexUid": "mieli",
+  "startedAt": "2022-02-03T15:17:02.812338Z",
+  "status": "succeeded",
+  "type": "documentAdditionOrUpdate",
+  "uid": 14,
+  "batchUid": 3
+}"#,
+        )
+        .unwrap();
+        assert_eq!(task.batch_uid(), Some(3));
+
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "enqueuedAt": "2022-02-03T13:02:38.369634Z",
+  "indexUid": "mieli",
+  "status": "enqueued",
+  "type": "documentAdditionOrUpdate",
+  "uid": 12,
+  "batchUid": null
+}"#,
+        )
+        .unwrap();
+        assert_eq!(task.batch_uid(), None);
+
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "error": {
+    "message": "Document doesn't have a `id` attribute.",
+    "code": "missing_document_id",
+    "type": "invalid_request",
+    "link": "https://docs.meilisearch.com/errors#missing_document_id"
+  },
+  "details": { "indexedDocuments": 0, "receivedDocuments": 1 },
+  "duration": "PT0.1S",
+  "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+  "finishedAt": "2022-02-03T15:17:02.901341Z",
+  "indexUid": "mieli",
+  "startedAt": "2022-02-03T15:17:02.812338Z",
+  "status": "failed",
+  "type": "documentAdditionOrUpdate",
+  "uid": 15,
+  "batchUid": 4
+}"#,
+        )
+        .unwrap();
+        assert_eq!(task.batch_uid(), Some(4));
+        match task {
+            Task::Failed { content } => assert_eq!(
+                content.to_string(),
+                "Task 15 of batch 4 failed: missing_document_id: Document doesn't have a `id` attribute."
+            ),
+            _ => panic!("the task should have failed"),
+        }
+    }
+
+    #[meilisearch_test]
+    async fn test_fetch_batch() -> Result<(), Error> {
+        let client = Client::new(mockito::server_url(), "masterKey");
+        let _batch = mock("GET", "/batches/7")
+            .with_status(200)
+            .with_body(
+                r#"
+{
+  "uid": 7,
+  "progress": null,
+  "details": { "receivedDocuments": 2, "indexedDocuments": 2 },
+  "stats": {
+    "totalNbTasks": 2,
+    "status": { "succeeded": 2 },
+    "types": { "documentAdditionOrUpdate": 2 },
+    "indexUids": { "movies": 2 }
+  },
+  "duration": "PT0.25S",
+  "startedAt": "2022-02-03T15:17:02.812338Z",
+  "finishedAt": "2022-02-03T15:17:03.062338Z"
+}"#,
+            )
+            .create();
+
+        let task: Task = serde_json::from_str(
+            r#"
+{
+  "duration": "PT0.25S",
+  "enqueuedAt": "2022-02-03T15:17:02.801341Z",
+  "finishedAt": "2022-02-03T15:17:03.062338Z",
+  "indexUid": "movies",
+  "startedAt": "2022-02-03T15:17:02.812338Z",
+  "status": "succeeded",
+  "type": "documentAdditionOrUpdate",
+  "uid": 20,
+  "batchUid": 7
+}"#,
+        )
+        .unwrap();
+        let batch = task.fetch_batch(&client).await?.unwrap();
+
+        assert_eq!(batch.uid, 7);
+        assert_eq!(batch.stats.total_nb_tasks, 2);
+        assert_eq!(batch.stats.index_uids["movies"], 2);
+        assert_eq!(batch.duration, Some(Duration::from_millis(250)));
+        assert!(batch.finished_at.is_some());
+
+        // an enqueued task is not part of a batch yet
+        let task: Task = serde_json::from_str(
+            r#"{ "enqueuedAt": "2022-02-03T13:02:38.369634Z", "indexUid": "movies", "status": "enqueued", "type": "documentAdditionOrUpdate", "uid": 21 }"#,
+        )
+        .unwrap();
+        assert!(task.fetch_batch(&client).await?.is_none());
+        Ok(())
+    }
+
     #[meilisearch_test]
     async fn test_wait_for_task_with_args(client: Client, movies: Index) -> Result<(), Error> {
         let task = movies
@@ -1,31 +1,148 @@
+use async_trait::async_trait;
 use std::time::Duration;
 
+/// A way to wait asynchronously, used by every method polling Meilisearch.
+///
+/// The implementation is selected at compile time by the cargo features, so the waits run on the
+/// timer of the async runtime in use instead of requiring a specific one.
+/// Outside of wasm the sleeps are `Send`, so the futures waiting for tasks can be spawned on any runtime.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub(crate) trait Sleeper {
+    async fn sleep(interval: Duration);
+}
+
+/// Sleeps on a dedicated thread. Works with any executor but spawns a thread per wait.
 #[cfg(not(target_arch = "wasm32"))]
-pub(crate) async fn async_sleep(interval: Duration) {
-    let (sender, receiver) = futures::channel::oneshot::channel::<()>();
-    std::thread::spawn(move || {
-        std::thread::sleep(interval);
-        let _ = sender.send(());
-    });
-    let _ = receiver.await;
+pub(crate) struct ThreadSleeper;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl Sleeper for ThreadSleeper {
+    async fn sleep(interval: Duration) {
+        let (sender, receiver) = futures::channel::oneshot::channel::<()>();
+        std::thread::spawn(move || {
+            std::thread::sleep(interval);
+            let _ = sender.send(());
+        });
+        let _ = receiver.await;
+    }
+}
+
+/// Sleeps with the tokio timer, or on a thread when not running inside a tokio runtime.
+///
+/// Inside a tokio runtime, the runtime must have its timer enabled with `enable_time`, otherwise it panics:
+/// tokio doesn't tell whether the timer of the current runtime is enabled, so it can't fall back to a thread.
+#[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+pub(crate) struct TokioSleeper;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+#[async_trait]
+impl Sleeper for TokioSleeper {
+    async fn sleep(interval: Duration) {
+        if tokio::runtime::Handle::try_current().is_ok() {
+            tokio::time::sleep(interval).await;
+        } else {
+            ThreadSleeper::sleep(interval).await;
+        }
+    }
+}
+
+/// Sleeps with the async-std timer.
+#[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
+// unused when a runtime with a higher priority is enabled as well
+#[allow(dead_code)]
+pub(crate) struct AsyncStdSleeper;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
+#[async_trait]
+impl Sleeper for AsyncStdSleeper {
+    async fn sleep(interval: Duration) {
+        async_std::task::sleep(interval).await;
+    }
 }
 
+/// Sleeps with the runtime-independent timer of futures-timer.
+#[cfg(all(not(target_arch = "wasm32"), feature = "futures-timer"))]
+// unused when a runtime with a higher priority is enabled as well
+#[allow(dead_code)]
+pub(crate) struct FuturesTimerSleeper;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "futures-timer"))]
+#[async_trait]
+impl Sleeper for FuturesTimerSleeper {
+    async fn sleep(interval: Duration) {
+        futures_timer::Delay::new(interval).await;
+    }
+}
+
+/// Sleeps with `setTimeout` on the browser window.
 #[cfg(target_arch = "wasm32")]
-pub(crate) async fn async_sleep(interval: Duration) {
-    use std::convert::TryInto;
-    use wasm_bindgen_futures::JsFuture;
+pub(crate) struct WindowSleeper;
 
-    JsFuture::from(js_sys::Promise::new(&mut |yes, _| {
-        web_sys::window()
-            .unwrap()
-            .set_timeout_with_callback_and_timeout_and_arguments_0(
-                &yes,
-                interval.as_millis().try_into().unwrap(),
-            )
-            .unwrap();
-    }))
-    .await
-    .unwrap();
+#[cfg(target_arch = "wasm32")]
+#[async_trait(?Send)]
+impl Sleeper for WindowSleeper {
+    async fn sleep(interval: Duration) {
+        use std::convert::TryInto;
+        use wasm_bindgen_futures::JsFuture;
+
+        JsFuture::from(js_sys::Promise::new(&mut |yes, _| {
+            web_sys::window()
+                .unwrap()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    &yes,
+                    interval.as_millis().try_into().unwrap(),
+                )
+                .unwrap();
+        }))
+        .await
+        .unwrap();
+    }
+}
+
+/// Sleeps with gloo-timers, which also works in web workers where there is no window.
+#[cfg(all(target_arch = "wasm32", feature = "gloo-timers"))]
+pub(crate) struct GlooSleeper;
+
+#[cfg(all(target_arch = "wasm32", feature = "gloo-timers"))]
+#[async_trait(?Send)]
+impl Sleeper for GlooSleeper {
+    async fn sleep(interval: Duration) {
+        gloo_timers::future::sleep(interval).await;
+    }
+}
+
+// When several runtimes are enabled, tokio is preferred over async-std, then futures-timer.
+#[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+type DefaultSleeper = TokioSleeper;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tokio"),
+    feature = "async-std"
+))]
+type DefaultSleeper = AsyncStdSleeper;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tokio"),
+    not(feature = "async-std"),
+    feature = "futures-timer"
+))]
+type DefaultSleeper = FuturesTimerSleeper;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "tokio"),
+    not(feature = "async-std"),
+    not(feature = "futures-timer")
+))]
+type DefaultSleeper = ThreadSleeper;
+#[cfg(all(target_arch = "wasm32", feature = "gloo-timers"))]
+type DefaultSleeper = GlooSleeper;
+#[cfg(all(target_arch = "wasm32", not(feature = "gloo-timers")))]
+type DefaultSleeper = WindowSleeper;
+
+pub(crate) async fn async_sleep(interval: Duration) {
+    DefaultSleeper::sleep(interval).await;
 }
 
 #[cfg(test)]
@@ -42,4 +159,79 @@ mod test {
 
         assert!(now.elapsed() >= sleep_duration);
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_async_sleep_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        assert_send(async_sleep(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn test_thread_sleeper_on_both_executors() {
+        let sleep_duration = Duration::from_millis(10);
+
+        let now = std::time::Instant::now();
+        futures::executor::block_on(ThreadSleeper::sleep(sleep_duration));
+        assert!(now.elapsed() >= sleep_duration);
+
+        let now = std::time::Instant::now();
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(ThreadSleeper::sleep(sleep_duration));
+        assert!(now.elapsed() >= sleep_duration);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_tokio_sleeper_on_both_executors() {
+        let sleep_duration = Duration::from_millis(10);
+
+        let now = std::time::Instant::now();
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(TokioSleeper::sleep(sleep_duration));
+        assert!(now.elapsed() >= sleep_duration);
+
+        // outside of a tokio runtime it falls back to sleeping on a thread
+        let now = std::time::Instant::now();
+        futures::executor::block_on(TokioSleeper::sleep(sleep_duration));
+        assert!(now.elapsed() >= sleep_duration);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    #[should_panic(expected = "timers are disabled")]
+    fn test_tokio_sleeper_requires_the_timer() {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(TokioSleeper::sleep(Duration::from_millis(10)));
+    }
+
+    #[cfg(feature = "async-std")]
+    #[test]
+    fn test_async_std_sleeper() {
+        let sleep_duration = Duration::from_millis(10);
+        let now = std::time::Instant::now();
+
+        futures::executor::block_on(AsyncStdSleeper::sleep(sleep_duration));
+
+        assert!(now.elapsed() >= sleep_duration);
+    }
+
+    #[cfg(feature = "futures-timer")]
+    #[test]
+    fn test_futures_timer_sleeper() {
+        let sleep_duration = Duration::from_millis(10);
+        let now = std::time::Instant::now();
+
+        futures::executor::block_on(FuturesTimerSleeper::sleep(sleep_duration));
+
+        assert!(now.elapsed() >= sleep_duration);
+    }
 }
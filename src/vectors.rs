@@ -0,0 +1,156 @@
+//! Small helpers to prepare the vectors sent to Meilisearch.
+//!
+//! Meilisearch compares vectors using the cosine similarity, which means the vectors
+//! you provide should be finite and, for most embedders, normalized.
+//!
+//! # Example
+//!
+//! ```
+//! # use meilisearch_sdk::vectors;
+//! let mut vector = [3.0, 4.0];
+//! vectors::normalize(&mut vector);
+//! assert_eq!(vector, [0.6, 0.8]);
+//!
+//! let similarity = vectors::cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).unwrap();
+//! assert_eq!(similarity, 0.0);
+//! ```
+
+use crate::errors::Error;
+
+/// Compute the euclidean norm of a vector.
+pub fn norm(vector: &[f32]) -> f32 {
+    vector.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+/// Normalize a vector in place so that its [norm] is `1`.
+///
+/// A vector with a norm of `0` can't be normalized and is left untouched.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::vectors;
+/// let mut vector = vec![0.0, 2.0, 0.0];
+/// vectors::normalize(&mut vector);
+/// assert_eq!(vector, vec![0.0, 1.0, 0.0]);
+/// ```
+pub fn normalize(vector: &mut [f32]) {
+    let norm = norm(vector);
+    if norm == 0.0 || !norm.is_finite() {
+        return;
+    }
+    for x in vector.iter_mut() {
+        *x /= norm;
+    }
+}
+
+/// Compute the cosine similarity between two vectors.
+///
+/// Returns [Error::VectorDimensionMismatch] if the vectors don't have the same dimension
+/// and [Error::InvalidVector] if one of them contains a `NaN` or an infinite value.
+/// The similarity with a vector whose norm is `0` is `0`.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::vectors;
+/// let similarity = vectors::cosine_similarity(&[1.0, 1.0], &[2.0, 2.0]).unwrap();
+/// assert!((similarity - 1.0).abs() < 1e-6);
+///
+/// assert!(vectors::cosine_similarity(&[1.0, 1.0], &[1.0]).is_err());
+/// ```
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> Result<f32, Error> {
+    if a.len() != b.len() {
+        return Err(Error::VectorDimensionMismatch {
+            expected: a.len(),
+            found: b.len(),
+        });
+    }
+    validate(a)?;
+    validate(b)?;
+
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        return Ok(0.0);
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+
+    Ok(dot / norms)
+}
+
+/// Check that a vector only contains finite values.
+///
+/// # Example
+///
+/// ```
+/// # use meilisearch_sdk::vectors;
+/// assert!(vectors::validate(&[0.5, 0.2]).is_ok());
+/// assert!(vectors::validate(&[0.5, f32::NAN]).is_err());
+/// ```
+pub fn validate(vector: &[f32]) -> Result<(), Error> {
+    if vector.iter().all(|x| x.is_finite()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidVector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-6;
+
+    #[test]
+    fn test_normalize() {
+        let mut vector = [3.0, 4.0];
+        normalize(&mut vector);
+
+        assert!((vector[0] - 0.6).abs() < EPSILON);
+        assert!((vector[1] - 0.8).abs() < EPSILON);
+        assert!((norm(&vector) - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_normalize_zero_vector() {
+        let mut vector = [0.0, 0.0, 0.0];
+        normalize(&mut vector);
+
+        assert_eq!(vector, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        let same = cosine_similarity(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap();
+        let orthogonal = cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).unwrap();
+        let opposite = cosine_similarity(&[1.0, -1.0], &[-1.0, 1.0]).unwrap();
+        let other = cosine_similarity(&[1.0, 2.0], &[3.0, 4.0]).unwrap();
+
+        assert!((same - 1.0).abs() < EPSILON);
+        assert!(orthogonal.abs() < EPSILON);
+        assert!((opposite + 1.0).abs() < EPSILON);
+        assert!((other - 0.983_869_9).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_cosine_similarity_errors() {
+        assert!(matches!(
+            cosine_similarity(&[1.0, 2.0], &[1.0]),
+            Err(Error::VectorDimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            cosine_similarity(&[1.0, f32::INFINITY], &[1.0, 2.0]),
+            Err(Error::InvalidVector)
+        ));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&[0.1, -0.2]).is_ok());
+        assert!(validate(&[0.1, f32::NAN]).is_err());
+        assert!(validate(&[f32::NEG_INFINITY]).is_err());
+    }
+}
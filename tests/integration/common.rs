@@ -0,0 +1,197 @@
+//! Helpers shared by every integration test: a client for the server under test, index uids
+//! unique to the run, seeding, waiting for tasks and retrying rate-limited requests.
+
+use lazy_static::lazy_static;
+use meilisearch_sdk::{
+    client::Client,
+    errors::{Error, ErrorCode},
+    indexes::Index,
+    task_info::TaskInfo,
+    tasks::Task,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// How many times a rate-limited request is sent before giving up.
+const MAX_ATTEMPTS: u32 = 5;
+/// How long a single task may take before the test fails.
+const TASK_TIMEOUT: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    /// Identifies this run, so several runs can share a server, e.g. parallel CI jobs.
+    /// It can be set with `MEILISEARCH_TEST_RUN_ID` to find the indexes of a run afterwards.
+    static ref RUN_ID: String = std::env::var("MEILISEARCH_TEST_RUN_ID").unwrap_or_else(|_| {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos();
+        format!("{}-{:x}", std::process::id(), nanos)
+    });
+}
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Build a client for the server under test, configured like the unit tests.
+pub fn client() -> Client {
+    let url = std::env::var("MEILISEARCH_URL").unwrap_or_else(|_| "http://localhost:7700".into());
+    let api_key = std::env::var("MEILISEARCH_API_KEY").unwrap_or_else(|_| "masterKey".into());
+    Client::new(url, api_key)
+}
+
+/// An index uid unique to this run and to this call, so tests never share an index.
+pub fn unique_uid(name: &str) -> String {
+    format!(
+        "it-{}-{}-{}",
+        *RUN_ID,
+        name,
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Whether the request was refused because too many requests were sent or because the server is
+/// temporarily unavailable.
+///
+/// Meilisearch answers a `429` with a JSON error this version of the SDK doesn't know, so it's
+/// recognized by its message. Any other failure, ie a body that isn't JSON, is a real error.
+pub fn is_rate_limited(error: &Error) -> bool {
+    if error.is_retriable() {
+        return true;
+    }
+    match error {
+        Error::Meilisearch(error) => {
+            error.error_code == ErrorCode::Unknown
+                && error.error_message.to_lowercase().contains("too many")
+        }
+        _ => false,
+    }
+}
+
+/// Send a request until it isn't rate limited anymore, waiting longer after each refusal.
+pub async fn retry<T, F, Fut>(mut send: F) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        match send().await {
+            Err(error) if attempt < MAX_ATTEMPTS && is_rate_limited(&error) => {
+                tokio::time::sleep(Duration::from_millis(100 * 2u64.pow(attempt))).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Wait for an enqueued task and fail the test if it didn't succeed.
+pub async fn wait(client: &Client, task: TaskInfo) -> Task {
+    let task = retry(|| client.wait_for_task(task.clone(), None, Some(TASK_TIMEOUT)))
+        .await
+        .unwrap();
+    assert!(task.is_success(), "{:?}", task);
+    task
+}
+
+/// An index created for a single test, deleted with [`TestIndex::delete`].
+pub struct TestIndex {
+    pub client: Client,
+    pub index: Index,
+}
+
+impl TestIndex {
+    /// Create an empty index with a unique uid and `id` as primary key.
+    pub async fn create(name: &str) -> TestIndex {
+        let client = client();
+        let uid = unique_uid(name);
+        let task = retry(|| client.create_index(&uid, Some("id")))
+            .await
+            .unwrap();
+        wait(&client, task).await;
+        let index = client.index(uid);
+
+        TestIndex { client, index }
+    }
+
+    /// Create an index seeded with [`movies`].
+    pub async fn seeded(name: &str) -> TestIndex {
+        let test_index = TestIndex::create(name).await;
+        let documents = movies();
+        let task = retry(|| test_index.index.add_documents(&documents, None))
+            .await
+            .unwrap();
+        wait(&test_index.client, task).await;
+
+        test_index
+    }
+
+    /// Wait for an enqueued task of this index and fail the test if it didn't succeed.
+    pub async fn wait(&self, task: TaskInfo) -> Task {
+        wait(&self.client, task).await
+    }
+
+    pub async fn delete(self) {
+        let task = retry(|| self.client.delete_index(&self.index.uid))
+            .await
+            .unwrap();
+        wait(&self.client, task).await;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Movie {
+    pub id: usize,
+    pub title: String,
+    pub genre: String,
+    pub year: u32,
+    pub overview: String,
+}
+
+/// The documents every seeded index starts with.
+pub fn movies() -> Vec<Movie> {
+    [
+        (1, "Carol", "romance", 2015, "A love story in the fifties."),
+        (
+            2,
+            "Wonder Woman",
+            "action",
+            2017,
+            "An amazon princess goes to war.",
+        ),
+        (
+            3,
+            "Life of Pi",
+            "adventure",
+            2012,
+            "A boy and a tiger lost at sea.",
+        ),
+        (4, "Mad Max", "action", 2015, "A road war in the desert."),
+        (
+            5,
+            "Moana",
+            "adventure",
+            2016,
+            "A princess sails across the sea.",
+        ),
+        (
+            6,
+            "Philadelphia",
+            "drama",
+            1993,
+            "A lawyer fights for his rights.",
+        ),
+    ]
+    .iter()
+    .map(|(id, title, genre, year, overview)| Movie {
+        id: *id,
+        title: title.to_string(),
+        genre: genre.to_string(),
+        year: *year,
+        overview: overview.to_string(),
+    })
+    .collect()
+}
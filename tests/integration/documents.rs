@@ -0,0 +1,161 @@
+use crate::common::{movies, retry, Movie, TestIndex};
+use meilisearch_sdk::{documents::DocumentsQuery, settings::Settings};
+
+#[tokio::test]
+async fn add_and_get_documents() {
+    let test_index = TestIndex::seeded("add_and_get_documents").await;
+    let index = &test_index.index;
+
+    let movie: Movie = retry(|| index.get_document("3")).await.unwrap();
+    assert_eq!(movie, movies()[2]);
+
+    let all = retry(|| index.get_documents::<Movie>()).await.unwrap();
+    assert_eq!(all.total as usize, movies().len());
+
+    let mut query = DocumentsQuery::new(index);
+    query.with_offset(2).with_limit(2);
+    let page = retry(|| query.execute::<Movie>()).await.unwrap();
+    assert_eq!(page.results, movies()[2..4].to_vec());
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn update_documents() {
+    let test_index = TestIndex::seeded("update_documents").await;
+    let index = &test_index.index;
+
+    let mut movie = movies()[0].clone();
+    movie.title = String::from("Carol (2015)");
+    let documents = [movie.clone()];
+    let task = retry(|| index.add_or_update(&documents, Some("id")))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+
+    let updated: Movie = retry(|| index.get_document("1")).await.unwrap();
+    assert_eq!(updated, movie);
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn delete_documents() {
+    let test_index = TestIndex::seeded("delete_documents").await;
+    let index = &test_index.index;
+
+    let task = retry(|| index.delete_document(1)).await.unwrap();
+    test_index.wait(task).await;
+    let all = retry(|| index.get_documents::<Movie>()).await.unwrap();
+    assert_eq!(all.total as usize, movies().len() - 1);
+    assert!(all.results.iter().all(|movie| movie.id != 1));
+
+    let task = retry(|| index.delete_all_documents()).await.unwrap();
+    test_index.wait(task).await;
+    let all = retry(|| index.get_documents::<Movie>()).await.unwrap();
+    assert_eq!(all.total, 0);
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn field_coverage() {
+    let test_index = TestIndex::create("field_coverage").await;
+    let index = &test_index.index;
+
+    let documents = [
+        serde_json::json!({ "id": 1, "title": "Carol", "poster": "carol.jpg" }),
+        serde_json::json!({ "id": 2, "title": "Moana" }),
+        serde_json::json!({ "id": 3, "title": "Mad Max", "poster": "mad_max.jpg" }),
+        serde_json::json!({ "id": 4, "title": "Life of Pi", "director": "Ang Lee" }),
+    ];
+    let task = retry(|| index.add_documents(&documents, None))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+
+    let stats = retry(|| index.get_stats()).await.unwrap();
+    assert_eq!(stats.number_of_documents, 4);
+    assert_eq!(stats.coverage_of("title"), 1.0);
+    assert_eq!(stats.coverage_of("poster"), 0.5);
+    assert_eq!(stats.coverage_of("director"), 0.25);
+    assert_eq!(
+        stats
+            .field_distribution
+            .fields_missing_from(&["id", "title", "overview"]),
+        ["overview"]
+    );
+    assert_eq!(
+        stats
+            .field_distribution
+            .unexpected_fields(&["id", "title", "poster"]),
+        ["director"]
+    );
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn export_ndjson() {
+    let test_index = TestIndex::seeded("export_ndjson").await;
+
+    // a retried export starts again in a new buffer
+    let (exported, ndjson) = retry(|| async {
+        let mut ndjson = Vec::new();
+        let exported = test_index.index.export_ndjson(&mut ndjson).await?;
+        Ok((exported, ndjson))
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(exported, movies().len());
+    let ndjson = String::from_utf8(ndjson).unwrap();
+    assert_eq!(ndjson.lines().count(), movies().len());
+    let exported: Vec<Movie> = ndjson
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(exported, movies());
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn import_ndjson_with_settings() {
+    let source = TestIndex::seeded("import_ndjson_source").await;
+    let target = TestIndex::create("import_ndjson_target").await;
+    let (_, ndjson) = retry(|| async {
+        let mut ndjson = Vec::new();
+        let exported = source.index.export_ndjson(&mut ndjson).await?;
+        Ok((exported, ndjson))
+    })
+    .await
+    .unwrap();
+
+    let settings = Settings::new().with_filterable_attributes(["genre"]);
+    let tasks = retry(|| {
+        target
+            .index
+            .import_ndjson(ndjson.as_slice(), Some(&settings), Some("id"))
+    })
+    .await
+    .unwrap();
+    assert_eq!(tasks.len(), 1);
+    for task in tasks {
+        target.wait(task).await;
+    }
+
+    let filterable = retry(|| target.index.get_filterable_attributes())
+        .await
+        .unwrap();
+    assert_eq!(filterable, ["genre"]);
+    let documents = retry(|| target.index.get_documents::<Movie>())
+        .await
+        .unwrap();
+    let mut imported = documents.results;
+    imported.sort_by_key(|movie| movie.id);
+    assert_eq!(imported, movies());
+
+    source.delete().await;
+    target.delete().await;
+}
@@ -0,0 +1,38 @@
+use crate::common::{client, retry, unique_uid};
+use meilisearch_sdk::{
+    errors::{Error, ErrorCode},
+    key::{Action, KeyBuilder, KeyUpdater},
+};
+
+#[tokio::test]
+async fn key_lifecycle() {
+    let client = client();
+    let index_uid = unique_uid("key_lifecycle");
+
+    let mut builder = KeyBuilder::new();
+    builder
+        .with_name(&index_uid)
+        .with_action(Action::Search)
+        .with_index(&index_uid);
+    let key = retry(|| client.create_key(&builder)).await.unwrap();
+    assert_eq!(key.actions, [Action::Search]);
+    assert_eq!(key.indexes, [index_uid.as_str()]);
+
+    let fetched = retry(|| client.get_key(&key.key)).await.unwrap();
+    assert_eq!(fetched.name.as_deref(), Some(index_uid.as_str()));
+
+    let mut updater = KeyUpdater::new(&key);
+    updater.with_description("updated by the integration tests");
+    let updated = retry(|| client.update_key(&updater)).await.unwrap();
+    assert_eq!(
+        updated.description.as_deref(),
+        Some("updated by the integration tests")
+    );
+
+    retry(|| client.delete_key(&key.key)).await.unwrap();
+    let error = retry(|| client.get_key(&key.key)).await.unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Meilisearch(error) if error.error_code == ErrorCode::ApiKeyNotFound
+    ));
+}
@@ -0,0 +1,18 @@
+//! Integration tests running against a live Meilisearch server.
+//!
+//! They are only built with the `integration-tests` feature:
+//!
+//! ```sh
+//! MEILISEARCH_URL=http://localhost:7700 MEILISEARCH_API_KEY=masterKey \
+//!     cargo test --features integration-tests --test integration
+//! ```
+//!
+//! Every test works on its own indexes, named after the run and the test,
+//! so the tests can run in parallel and several runs can share a server.
+
+mod common;
+mod documents;
+mod keys;
+mod search;
+mod settings;
+mod tasks;
@@ -0,0 +1,123 @@
+use crate::common::{retry, Movie, TestIndex};
+use meilisearch_sdk::{search::Selectors, settings::Settings};
+
+/// A seeded index whose genre and year can be filtered, faceted and sorted on.
+async fn searchable_index(name: &str) -> TestIndex {
+    let test_index = TestIndex::seeded(name).await;
+    let settings = Settings::new()
+        .with_filterable_attributes(["genre", "year"])
+        .with_sortable_attributes(["year"]);
+    let task = retry(|| test_index.index.set_settings(&settings))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+
+    test_index
+}
+
+#[tokio::test]
+async fn search_with_filters_and_sort() {
+    let test_index = searchable_index("search_with_filters_and_sort").await;
+    let index = &test_index.index;
+
+    let mut query = index.search();
+    query
+        .with_filter("genre = action AND year > 2015")
+        .with_sort(&["year:desc"]);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let titles: Vec<_> = results.hits.iter().map(|hit| &hit.result.title).collect();
+    assert_eq!(titles, ["Wonder Woman"]);
+
+    let mut query = index.search();
+    query.with_filter("genre = action").with_sort(&["year:asc"]);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let titles: Vec<_> = results.hits.iter().map(|hit| &hit.result.title).collect();
+    assert_eq!(titles, ["Mad Max", "Wonder Woman"]);
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn search_with_facets() {
+    let test_index = searchable_index("search_with_facets").await;
+    let index = &test_index.index;
+
+    let mut query = index.search();
+    query.with_facets(Selectors::Some(&["genre"])).with_limit(0);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let genres = &results.facet_distribution.unwrap()["genre"];
+    assert_eq!(genres["action"], 2);
+    assert_eq!(genres["adventure"], 2);
+    assert_eq!(genres["romance"], 1);
+    assert!(results.hits.is_empty());
+
+    let mut query = index.search();
+    query.with_facets(Selectors::All).with_limit(0);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let facets = results.facet_distribution.unwrap();
+    assert!(facets.contains_key("genre"));
+    assert!(facets.contains_key("year"));
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn search_with_highlight() {
+    let test_index = TestIndex::seeded("search_with_highlight").await;
+    let index = &test_index.index;
+
+    let mut query = index.search();
+    query
+        .with_query("tiger")
+        .with_attributes_to_highlight(Selectors::Some(&["overview"]))
+        .with_highlight_pre_tag("<b>")
+        .with_highlight_post_tag("</b>");
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let formatted = results.hits[0].formatted_result.as_ref().unwrap();
+    assert_eq!(
+        formatted["overview"],
+        "A boy and a <b>tiger</b> lost at sea."
+    );
+
+    let mut query = index.search();
+    query
+        .with_query("tiger")
+        .with_attributes_to_highlight(Selectors::All);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    let formatted = results.hits[0].formatted_result.as_ref().unwrap();
+    assert_eq!(formatted["title"], "Life of Pi");
+    assert_eq!(
+        formatted["overview"],
+        "A boy and a <em>tiger</em> lost at sea."
+    );
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn search_with_pagination() {
+    let test_index = searchable_index("search_with_pagination").await;
+    let index = &test_index.index;
+
+    let mut pages = Vec::new();
+    for offset in (0..6).step_by(2) {
+        let mut query = index.search();
+        query
+            .with_sort(&["year:asc"])
+            .with_offset(offset)
+            .with_limit(2);
+        let results = retry(|| query.execute::<Movie>()).await.unwrap();
+        assert_eq!(results.offset, offset);
+        assert_eq!(results.limit, 2);
+        assert_eq!(results.estimated_total_hits, 6);
+        pages.extend(results.hits.into_iter().map(|hit| hit.result.year));
+    }
+    assert_eq!(pages, [1993, 2012, 2015, 2015, 2016, 2017]);
+
+    let mut query = index.search();
+    query.with_offset(6).with_limit(2);
+    let results = retry(|| query.execute::<Movie>()).await.unwrap();
+    assert!(results.hits.is_empty());
+
+    test_index.delete().await;
+}
@@ -0,0 +1,355 @@
+use crate::common::{retry, TestIndex};
+use meilisearch_sdk::settings::{
+    FacetSortBy, FacetingSettings, PaginationSetting, ProximityPrecision, Setting,
+    SettingDifference, Settings, ValueKind,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn settings_round_trip() {
+    let test_index = TestIndex::create("settings_round_trip").await;
+    let index = &test_index.index;
+
+    let settings = Settings::new()
+        .with_searchable_attributes(["title", "overview"])
+        .with_filterable_attributes(["genre", "year"])
+        .with_sortable_attributes(["year"])
+        .with_distinct_attribute("title")
+        .with_stop_words(["the", "a"])
+        .with_dictionary(["J.R.R.", "C++"])
+        .with_separator_tokens(["|"])
+        .with_pagination(PaginationSetting {
+            max_total_hits: 500,
+        })
+        .with_faceting(&FacetingSettings::new().with_max_values_per_facet(20));
+    let task = retry(|| index.set_settings(&settings)).await.unwrap();
+    test_index.wait(task).await;
+
+    let current = retry(|| index.get_settings()).await.unwrap();
+    assert_eq!(
+        current.searchable_attributes,
+        settings.searchable_attributes
+    );
+    assert_eq!(
+        current.filterable_attributes,
+        settings.filterable_attributes
+    );
+    assert_eq!(current.sortable_attributes, settings.sortable_attributes);
+    assert_eq!(current.distinct_attribute, settings.distinct_attribute);
+    assert_eq!(current.stop_words, settings.stop_words);
+    let mut dictionary = current.dictionary.into_option().unwrap();
+    dictionary.sort();
+    assert_eq!(dictionary, ["C++", "J.R.R."]);
+    assert_eq!(current.separator_tokens, settings.separator_tokens);
+    assert_eq!(current.pagination, settings.pagination);
+    assert_eq!(
+        current.faceting.into_option().unwrap().max_values_per_facet,
+        Setting::Set(20)
+    );
+
+    // a single update resets some settings and changes another one
+    let mixed = Settings {
+        stop_words: Setting::Reset,
+        distinct_attribute: Setting::Reset,
+        ..Settings::new().with_sortable_attributes(["title"])
+    };
+    let task = retry(|| index.set_settings(&mixed)).await.unwrap();
+    test_index.wait(task).await;
+    let current = retry(|| index.get_settings()).await.unwrap();
+    assert_eq!(current.stop_words, Setting::Set(vec![]));
+    assert_eq!(current.distinct_attribute, Setting::Reset);
+    assert_eq!(current.sortable_attributes, mixed.sortable_attributes);
+    assert_eq!(current.separator_tokens, settings.separator_tokens);
+
+    let task = retry(|| index.reset_settings()).await.unwrap();
+    test_index.wait(task).await;
+    let current = retry(|| index.get_settings()).await.unwrap();
+    assert_eq!(current.filterable_attributes, Setting::Set(vec![]));
+    assert_eq!(current.distinct_attribute, Setting::Reset);
+    assert_eq!(current.dictionary, Setting::Set(vec![]));
+    assert_eq!(current.separator_tokens, Setting::Set(vec![]));
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn settings_sub_routes() {
+    let test_index = TestIndex::create("settings_sub_routes").await;
+    let index = &test_index.index;
+
+    let synonyms = HashMap::from([(
+        String::from("film"),
+        vec![String::from("movie"), String::from("picture")],
+    )]);
+    let task = retry(|| index.set_synonyms(&synonyms)).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(retry(|| index.get_synonyms()).await.unwrap(), synonyms);
+    let task = retry(|| index.reset_synonyms()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_synonyms()).await.unwrap().is_empty());
+
+    let task = retry(|| index.set_stop_words(["the"])).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(retry(|| index.get_stop_words()).await.unwrap(), ["the"]);
+    let task = retry(|| index.reset_stop_words()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_stop_words()).await.unwrap().is_empty());
+
+    let task = retry(|| index.set_dictionary(["J. R. R. Tolkien"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_dictionary()).await.unwrap(),
+        ["J. R. R. Tolkien"]
+    );
+    let task = retry(|| index.reset_dictionary()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_dictionary()).await.unwrap().is_empty());
+
+    let task = retry(|| index.set_separator_tokens(["|"])).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(retry(|| index.get_separator_tokens()).await.unwrap(), ["|"]);
+    let task = retry(|| index.reset_separator_tokens()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_separator_tokens())
+        .await
+        .unwrap()
+        .is_empty());
+
+    let task = retry(|| index.set_non_separator_tokens(["@"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_non_separator_tokens()).await.unwrap(),
+        ["@"]
+    );
+    let task = retry(|| index.reset_non_separator_tokens()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_non_separator_tokens())
+        .await
+        .unwrap()
+        .is_empty());
+
+    let task = retry(|| index.set_proximity_precision(ProximityPrecision::ByAttribute))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_proximity_precision()).await.unwrap(),
+        ProximityPrecision::ByAttribute
+    );
+    let task = retry(|| index.reset_proximity_precision()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_proximity_precision()).await.unwrap(),
+        ProximityPrecision::ByWord
+    );
+
+    let task = retry(|| index.set_search_cutoff_ms(150)).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_search_cutoff_ms()).await.unwrap(),
+        Some(150)
+    );
+    let task = retry(|| index.reset_search_cutoff_ms()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(retry(|| index.get_search_cutoff_ms()).await.unwrap(), None);
+
+    let default_ranking_rules = retry(|| index.get_ranking_rules()).await.unwrap();
+    let task = retry(|| index.set_ranking_rules(["words", "year:desc"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_ranking_rules()).await.unwrap(),
+        ["words", "year:desc"]
+    );
+    let task = retry(|| index.reset_ranking_rules()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_ranking_rules()).await.unwrap(),
+        default_ranking_rules
+    );
+
+    let task = retry(|| index.set_filterable_attributes(["genre"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_filterable_attributes()).await.unwrap(),
+        ["genre"]
+    );
+    let task = retry(|| index.reset_filterable_attributes()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_filterable_attributes())
+        .await
+        .unwrap()
+        .is_empty());
+
+    let task = retry(|| index.set_sortable_attributes(["year"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_sortable_attributes()).await.unwrap(),
+        ["year"]
+    );
+    let task = retry(|| index.reset_sortable_attributes()).await.unwrap();
+    test_index.wait(task).await;
+    assert!(retry(|| index.get_sortable_attributes())
+        .await
+        .unwrap()
+        .is_empty());
+
+    let task = retry(|| index.set_distinct_attribute("title"))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_distinct_attribute()).await.unwrap(),
+        Some(String::from("title"))
+    );
+    let task = retry(|| index.reset_distinct_attribute()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_distinct_attribute()).await.unwrap(),
+        None
+    );
+
+    let task = retry(|| index.set_searchable_attributes(["title"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_searchable_attributes()).await.unwrap(),
+        ["title"]
+    );
+    let task = retry(|| index.reset_searchable_attributes()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_searchable_attributes()).await.unwrap(),
+        ["*"]
+    );
+
+    let task = retry(|| index.set_displayed_attributes(["title"]))
+        .await
+        .unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_displayed_attributes()).await.unwrap(),
+        ["title"]
+    );
+    let task = retry(|| index.reset_displayed_attributes()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_displayed_attributes()).await.unwrap(),
+        ["*"]
+    );
+
+    let pagination = PaginationSetting { max_total_hits: 42 };
+    let task = retry(|| index.set_pagination(pagination)).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(retry(|| index.get_pagination()).await.unwrap(), pagination);
+    let task = retry(|| index.reset_pagination()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_pagination()).await.unwrap(),
+        PaginationSetting {
+            max_total_hits: 1000
+        }
+    );
+
+    let faceting = FacetingSettings::new()
+        .with_max_values_per_facet(5)
+        .with_sort_facet_values_by([("genres", FacetSortBy::Count)]);
+    let task = retry(|| index.set_faceting(&faceting)).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_faceting()).await.unwrap(),
+        FacetingSettings::new()
+            .with_max_values_per_facet(5)
+            .with_sort_facet_values_by([("*", FacetSortBy::Alpha), ("genres", FacetSortBy::Count)])
+    );
+    let task = retry(|| index.reset_faceting()).await.unwrap();
+    test_index.wait(task).await;
+    assert_eq!(
+        retry(|| index.get_faceting()).await.unwrap(),
+        FacetingSettings::new()
+            .with_max_values_per_facet(100)
+            .with_sort_facet_values_by([("*", FacetSortBy::Alpha)])
+    );
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn compare_settings_of_two_indexes() {
+    let staging = TestIndex::create("compare_settings_staging").await;
+    let production = TestIndex::create("compare_settings_production").await;
+    let client = &staging.client;
+
+    let diff = retry(|| client.compare_settings(&staging.index.uid, &production.index.uid))
+        .await
+        .unwrap();
+    assert!(diff.is_empty(), "{}", diff);
+
+    let shared = Settings::new().with_sortable_attributes(["year"]);
+    let staging_settings = shared.clone().with_filterable_attributes(["genre"]);
+    let production_settings = shared
+        .with_filterable_attributes(["genre", "year"])
+        .with_pagination(PaginationSetting { max_total_hits: 50 });
+    let task = retry(|| staging.index.set_settings(&staging_settings))
+        .await
+        .unwrap();
+    staging.wait(task).await;
+    let task = retry(|| production.index.set_settings(&production_settings))
+        .await
+        .unwrap();
+    production.wait(task).await;
+
+    let diff = retry(|| client.compare_settings(&staging.index.uid, &production.index.uid))
+        .await
+        .unwrap();
+    assert_eq!(diff.uid_a, staging.index.uid);
+    assert_eq!(diff.uid_b, production.index.uid);
+    assert_eq!(
+        diff.differences,
+        [
+            SettingDifference {
+                path: "filterableAttributes".to_string(),
+                a: Some(json!(["genre"])),
+                b: Some(json!(["genre", "year"])),
+            },
+            SettingDifference {
+                path: "pagination.maxTotalHits".to_string(),
+                a: Some(json!(1000)),
+                b: Some(json!(50)),
+            },
+        ]
+    );
+
+    staging.delete().await;
+    production.delete().await;
+}
+
+#[tokio::test]
+async fn suggest_filterable_attributes() {
+    let test_index = TestIndex::seeded("suggest_filterable_attributes").await;
+
+    let suggestions = retry(|| test_index.index.suggest_filterable_attributes(20))
+        .await
+        .unwrap();
+    let fields: Vec<&str> = suggestions
+        .iter()
+        .map(|suggestion| suggestion.field.as_str())
+        .collect();
+    assert_eq!(fields, ["genre", "year"]);
+    assert_eq!(suggestions[0].cardinality_estimate, 4);
+    assert_eq!(suggestions[1].value_kind, ValueKind::Number);
+    assert!(!fields.contains(&"overview"));
+
+    test_index.delete().await;
+}
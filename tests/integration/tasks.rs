@@ -0,0 +1,126 @@
+use crate::common::{retry, TestIndex};
+use meilisearch_sdk::tasks::{SucceededTask, Task, TaskStatus, TaskType, TasksQuery};
+use time::OffsetDateTime;
+
+#[tokio::test]
+async fn filter_tasks() {
+    let test_index = TestIndex::seeded("filter_tasks").await;
+    let client = &test_index.client;
+    let uid = test_index.index.uid.as_str();
+
+    let mut query = TasksQuery::new(client);
+    query.with_index_uid([uid]);
+    let tasks = retry(|| query.execute()).await.unwrap();
+    // the creation of the index and the addition of the documents
+    assert_eq!(tasks.results.len(), 2);
+
+    let mut query = TasksQuery::new(client);
+    query
+        .with_index_uid([uid])
+        .with_type(["documentAdditionOrUpdate"])
+        .with_status(["succeeded"]);
+    let tasks = retry(|| query.execute()).await.unwrap();
+    assert_eq!(tasks.results.len(), 1);
+    assert!(tasks.results[0].is_success());
+
+    let mut query = TasksQuery::new(client);
+    query.with_index_uid([uid]).with_status(["failed"]);
+    let tasks = retry(|| query.execute()).await.unwrap();
+    assert!(tasks.results.is_empty());
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn failed_task() {
+    let test_index = TestIndex::create("failed_task").await;
+    let index = &test_index.index;
+
+    // the documents don't have the primary key of the index
+    let documents = [serde_json::json!({ "name": "nope" })];
+    let task = retry(|| index.add_documents(&documents, None))
+        .await
+        .unwrap();
+    let task = retry(|| test_index.client.wait_for_task(task.clone(), None, None))
+        .await
+        .unwrap();
+    assert!(task.is_failure());
+
+    let fetched = retry(|| test_index.client.get_task(&task)).await.unwrap();
+    assert_eq!(fetched.get_uid(), task.get_uid());
+    assert!(matches!(
+        fetched.unwrap_failure().error_code,
+        meilisearch_sdk::errors::ErrorCode::MissingDocumentId
+    ));
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn index_tasks() {
+    let test_index = TestIndex::seeded("index_tasks").await;
+    let index = &test_index.index;
+
+    let tasks = retry(|| index.get_tasks()).await.unwrap();
+    assert_eq!(tasks.results.len(), 2);
+    assert!(tasks.results.iter().all(|task| task.is_success()));
+    assert!(tasks.results.iter().any(|task| matches!(
+        task,
+        meilisearch_sdk::tasks::Task::Succeeded { content }
+            if matches!(content.update_type, TaskType::DocumentAdditionOrUpdate { .. })
+    )));
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn task_batch() {
+    let test_index = TestIndex::create("task_batch").await;
+    let documents = crate::common::movies();
+    let task = retry(|| test_index.index.add_documents(&documents, None))
+        .await
+        .unwrap();
+    let task = test_index.wait(task).await;
+
+    let batch = retry(|| task.fetch_batch(&test_index.client))
+        .await
+        .unwrap()
+        .expect("a processed task is part of a batch");
+    assert_eq!(Some(batch.uid), task.batch_uid());
+    assert!(batch.stats.total_nb_tasks >= 1);
+    assert!(batch.stats.index_uids.contains_key(&test_index.index.uid));
+    assert!(batch.finished_at.is_some());
+
+    test_index.delete().await;
+}
+
+#[tokio::test]
+async fn purge_tasks_before() {
+    let test_index = TestIndex::seeded("purge_tasks_before").await;
+    let client = &test_index.client;
+
+    // only the canceled tasks are purged, so the tasks of the other tests running on the server are kept
+    let deletion =
+        retry(|| client.purge_tasks_before(OffsetDateTime::now_utc(), &[TaskStatus::Canceled]))
+            .await
+            .unwrap();
+    match test_index.wait(deletion).await {
+        Task::Succeeded {
+            content:
+                SucceededTask {
+                    update_type:
+                        TaskType::TaskDeletion {
+                            details: Some(details),
+                        },
+                    ..
+                },
+        } => assert!(details.matched_tasks.is_some()),
+        task => panic!("the task deletion should have succeeded: {:?}", task),
+    }
+
+    // the succeeded tasks of the index don't match the statuses
+    let tasks = retry(|| test_index.index.get_tasks()).await.unwrap();
+    assert_eq!(tasks.results.len(), 2);
+
+    test_index.delete().await;
+}